@@ -0,0 +1,32 @@
+use soroban_sdk::contracterror;
+
+/// Contract errors for `asset-token`.
+///
+/// Codes follow the workspace allocation in `contracts/ERRORS.md`:
+///
+/// - **1–99** are *shared* across every contract and mean the same thing
+///   everywhere.
+/// - **600–699** belong to `asset-token` alone.
+///
+/// SEP-41 entrypoints return nothing, so these are raised with
+/// `panic_with_error!` and surface to the caller as a contract error code.
+/// Failures inside the registry (locks, restrictions, insufficient balance)
+/// surface with the registry's own `assetsup` codes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TokenError {
+    // ---------------------------------------------------------------
+    // Shared: 1–99. Same meaning in every contract in the workspace.
+    // ---------------------------------------------------------------
+    /// An amount was negative.
+    InvalidInput = 4,
+
+    // ---------------------------------------------------------------
+    // Allowances: 600–609
+    // ---------------------------------------------------------------
+    /// The spender's allowance is lower than the amount, or has expired.
+    InsufficientAllowance = 600,
+    /// A non-zero allowance was given an expiration ledger in the past.
+    InvalidExpirationLedger = 601,
+}
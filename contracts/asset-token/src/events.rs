@@ -0,0 +1,89 @@
+//! SEP-41 token events.
+//!
+//! These deliberately do **not** follow the workspace `<Subject><PastTenseVerb>`
+//! convention in `contracts/EVENTS.md`. Wallets and indexers recognise a token
+//! by the exact topic and data shapes SEP-41 prescribes, so the struct names
+//! are the standard's topic names and the data formats match it.
+
+use soroban_sdk::{contractevent, Address, Env};
+
+/// Topics `["transfer", from, to]`, data `amount`.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Topics `["transfer", from, to]`, data `{ amount, to_muxed_id }`: a
+/// transfer to a muxed address, shaped as the Stellar Asset Contract emits it.
+#[contractevent(topics = ["transfer"], data_format = "map")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferMuxed {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: u64,
+    pub amount: i128,
+}
+
+/// Topics `["approve", from, spender]`, data `[amount, expiration_ledger]`.
+#[contractevent(data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Approve {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Topics `["burn", from]`, data `amount`.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+pub fn transfer(env: &Env, from: &Address, to: &Address, to_muxed_id: Option<u64>, amount: i128) {
+    match to_muxed_id {
+        Some(to_muxed_id) => TransferMuxed {
+            from: from.clone(),
+            to: to.clone(),
+            to_muxed_id,
+            amount,
+        }
+        .publish(env),
+        None => Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            amount,
+        }
+        .publish(env),
+    }
+}
+
+pub fn approve(env: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+    Approve {
+        from: from.clone(),
+        spender: spender.clone(),
+        amount,
+        expiration_ledger,
+    }
+    .publish(env);
+}
+
+pub fn burn(env: &Env, from: &Address, amount: i128) {
+    Burn {
+        from: from.clone(),
+        amount,
+    }
+    .publish(env);
+}
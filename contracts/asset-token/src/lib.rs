@@ -0,0 +1,151 @@
+#![no_std]
+//! # asset-token
+//!
+//! A SEP-41 token over one tokenized `assetsup` asset.
+//!
+//! `assetsup` keeps fractional balances in its own `OwnershipRecord`s, which
+//! wallets, DEXes and other contracts cannot read. One instance of this
+//! contract per tokenized asset exposes those balances through the standard
+//! token interface instead.
+//!
+//! `assetsup` deploys the instance itself, passing its own address and the
+//! asset id to the constructor, and from then on trusts calls from that
+//! address only. See `assetsup/src/token_contract.rs`.
+//!
+//! ## Invariants
+//!
+//! - This contract holds **no balances**. `balance` reads the registry, and
+//!   every transfer or burn is applied by the registry, which enforces the
+//!   asset's pause, transfer restrictions, locks and vesting exactly as its
+//!   own `transfer_tokens` does.
+//! - Allowances are the only state kept here. They live in temporary storage
+//!   and read as zero once past their expiration ledger.
+//! - `decimals`, `name` and `symbol` are read from the registry on every call,
+//!   so they track the asset's metadata rather than a copy taken at
+//!   deployment.
+//!
+//! There is no `mint`; SEP-41 leaves minting to the issuer, which here is the
+//! registry's `mint_tokens`.
+//!
+//! See [`README.md`](https://github.com/DistinctCodes/AssetsUp/blob/main/contracts/asset-token/README.md)
+//! for the entrypoint, event, and error tables.
+
+use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, MuxedAddress, String};
+
+mod errors;
+mod events;
+mod registry;
+mod storage;
+mod test;
+
+pub use errors::TokenError;
+
+#[contract]
+pub struct AssetToken;
+
+#[contractimpl]
+impl AssetToken {
+    /// Binds the token to `asset_id` in the registry at `registry`.
+    pub fn __constructor(env: Env, registry: Address, asset_id: u64) {
+        storage::set_binding(&env, &registry, asset_id);
+    }
+
+    /// The registry contract and asset id this token serves.
+    pub fn asset(env: Env) -> (Address, u64) {
+        storage::get_binding(&env)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        storage::get_allowance(&env, &from, &spender)
+    }
+
+    pub fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        from.require_auth();
+        check_nonnegative(&env, amount);
+
+        storage::set_allowance(&env, &from, &spender, amount, expiration_ledger);
+        events::approve(&env, &from, &spender, amount, expiration_ledger);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        registry::balance(&env, &id)
+    }
+
+    /// The event reports the shares `to` received, which is less than
+    /// `amount` when the asset withholds a transfer fee in shares.
+    pub fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        from.require_auth();
+        if is_noop(&env, amount) {
+            return;
+        }
+
+        let to_muxed_id = to.id();
+        let to = to.address();
+        let received = registry::transfer(&env, &from, &to, amount);
+        events::transfer(&env, &from, &to, to_muxed_id, received);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        if is_noop(&env, amount) {
+            return;
+        }
+
+        storage::spend_allowance(&env, &from, &spender, amount);
+        let received = registry::transfer(&env, &from, &to, amount);
+        events::transfer(&env, &from, &to, None, received);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        if is_noop(&env, amount) {
+            return;
+        }
+
+        registry::burn(&env, &from, amount);
+        events::burn(&env, &from, amount);
+    }
+
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        if is_noop(&env, amount) {
+            return;
+        }
+
+        storage::spend_allowance(&env, &from, &spender, amount);
+        registry::burn(&env, &from, amount);
+        events::burn(&env, &from, amount);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        registry::metadata(&env).0
+    }
+
+    pub fn name(env: Env) -> String {
+        registry::metadata(&env).1
+    }
+
+    pub fn symbol(env: Env) -> String {
+        registry::metadata(&env).2
+    }
+}
+
+fn check_nonnegative(env: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, TokenError::InvalidInput);
+    }
+}
+
+/// Rejects a negative amount, and reports a zero one as nothing to do: the
+/// registry refuses to move or burn zero shares, but SEP-41 callers expect
+/// a zero amount to succeed.
+fn is_noop(env: &Env, amount: i128) -> bool {
+    check_nonnegative(env, amount);
+    amount == 0
+}
@@ -0,0 +1,47 @@
+//! Cross-contract calls into the `assetsup` registry.
+//!
+//! Like `multisig-transfer`, this uses [`Env::invoke_contract`] rather than
+//! importing the registry crate, so the two contracts stay decoupled.
+//!
+//! ## Expected registry interface
+//!
+//! ```text
+//! get_token_balance(asset_id: u64, holder: Address) -> i128
+//! get_sep41_metadata(asset_id: u64) -> (u32, String, String)   // decimals, name, symbol
+//! sep41_transfer(asset_id: u64, from: Address, to: Address, amount: i128) -> i128   // shares received
+//! sep41_burn(asset_id: u64, from: Address, amount: i128)
+//! ```
+//!
+//! The last two authenticate this contract as the asset's bound token
+//! contract. A contract implicitly authorizes calls it makes directly, so no
+//! extra signature is involved. Registry errors are not caught: a transfer the
+//! registry refuses fails with the registry's error code.
+
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::storage;
+
+pub fn balance(env: &Env, id: &Address) -> i128 {
+    let (registry, asset_id) = storage::get_binding(env);
+    let args: Vec<Val> = (asset_id, id.clone()).into_val(env);
+    env.invoke_contract(&registry, &Symbol::new(env, "get_token_balance"), args)
+}
+
+pub fn metadata(env: &Env) -> (u32, String, String) {
+    let (registry, asset_id) = storage::get_binding(env);
+    let args: Vec<Val> = (asset_id,).into_val(env);
+    env.invoke_contract(&registry, &Symbol::new(env, "get_sep41_metadata"), args)
+}
+
+/// Returns the shares `to` received, net of any share fee.
+pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    let (registry, asset_id) = storage::get_binding(env);
+    let args: Vec<Val> = (asset_id, from.clone(), to.clone(), amount).into_val(env);
+    env.invoke_contract(&registry, &Symbol::new(env, "sep41_transfer"), args)
+}
+
+pub fn burn(env: &Env, from: &Address, amount: i128) {
+    let (registry, asset_id) = storage::get_binding(env);
+    let args: Vec<Val> = (asset_id, from.clone(), amount).into_val(env);
+    env.invoke_contract::<()>(&registry, &Symbol::new(env, "sep41_burn"), args);
+}
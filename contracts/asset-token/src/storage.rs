@@ -0,0 +1,97 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::errors::TokenError;
+
+/// Bump instance storage when it has less than 30 days left, back out to 90,
+/// matching the registry's policy in `assetsup::ttl`.
+const LEDGERS_PER_DAY: u32 = 17_280;
+const INSTANCE_THRESHOLD: u32 = 30 * LEDGERS_PER_DAY;
+const INSTANCE_EXTEND_TO: u32 = 90 * LEDGERS_PER_DAY;
+
+#[contracttype]
+pub enum DataKey {
+    Registry,                    // Address, instance
+    AssetId,                     // u64, instance
+    Allowance(Address, Address), // AllowanceValue for (from, spender), temporary
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+pub fn set_binding(env: &Env, registry: &Address, asset_id: u64) {
+    let store = env.storage().instance();
+    store.set(&DataKey::Registry, registry);
+    store.set(&DataKey::AssetId, &asset_id);
+}
+
+/// The registry and asset id this token serves. Set by the constructor, so
+/// always present.
+pub fn get_binding(env: &Env) -> (Address, u64) {
+    let store = env.storage().instance();
+    store.extend_ttl(INSTANCE_THRESHOLD, INSTANCE_EXTEND_TO);
+    (
+        store.get(&DataKey::Registry).unwrap(),
+        store.get(&DataKey::AssetId).unwrap(),
+    )
+}
+
+/// The current allowance; zero once expired.
+pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> i128 {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+        Some(a) if a.expiration_ledger >= env.ledger().sequence() => a.amount,
+        _ => 0,
+    }
+}
+
+/// Stores an allowance in temporary storage, live until its expiration
+/// ledger: an allowance is exactly the short-lived data `temporary` is for.
+pub fn set_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    let current = env.ledger().sequence();
+    if amount > 0 && expiration_ledger < current {
+        panic_with_error!(env, TokenError::InvalidExpirationLedger);
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let store = env.storage().temporary();
+    store.set(
+        &key,
+        &AllowanceValue {
+            amount,
+            expiration_ledger,
+        },
+    );
+    if amount > 0 {
+        // The host rejects an extension past the network's maximum TTL, so a
+        // far-future expiration keeps the entry alive as long as it can.
+        let live_for = (expiration_ledger - current).min(env.storage().max_ttl());
+        store.extend_ttl(&key, live_for, live_for);
+    }
+}
+
+pub fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = get_allowance(env, from, spender);
+    if allowance < amount {
+        panic_with_error!(env, TokenError::InsufficientAllowance);
+    }
+    if amount > 0 {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        let expiration_ledger = env
+            .storage()
+            .temporary()
+            .get::<_, AllowanceValue>(&key)
+            .map(|a| a.expiration_ledger)
+            .unwrap_or(0);
+        set_allowance(env, from, spender, allowance - amount, expiration_ledger);
+    }
+}
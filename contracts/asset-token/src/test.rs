@@ -0,0 +1,364 @@
+//! Integration tests against the real `assetsup` registry.
+//!
+//! The unit-test environment registers contracts natively rather than from
+//! uploaded WASM, so the registry's own `deploy_v2` of this contract cannot
+//! run here. [`Fixture::new`] instead registers the token natively and writes
+//! the registry's binding directly — the same state `tokenize_asset` leaves
+//! behind when a WASM hash is configured. The deployment itself only runs on a
+//! network, once `scripts/deploy.sh` has uploaded the WASM and set the hash.
+#![cfg(test)]
+extern crate std;
+
+use assetsup::asset::Asset;
+use assetsup::{AssetStatus, AssetType, AssetUpContract, AssetUpContractClient, TokenDataKey};
+use soroban_sdk::testutils::{
+    Address as _, BytesN as _, Events as _, Ledger as _, MuxedAddress as _,
+};
+use soroban_sdk::{Address, BytesN, Env, Map, MuxedAddress, String, Symbol, TryIntoVal, Val, Vec};
+
+use crate::{AssetToken, AssetTokenClient, TokenError};
+
+const ASSET_ID: u64 = 1;
+const SUPPLY: i128 = 1_000_000;
+
+struct Fixture<'a> {
+    env: Env,
+    registry: AssetUpContractClient<'a>,
+    token: AssetTokenClient<'a>,
+    tokenizer: Address,
+}
+
+impl<'a> Fixture<'a> {
+    fn new() -> Fixture<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let registry_id = env.register(AssetUpContract, ());
+        let registry = AssetUpContractClient::new(&env, &registry_id);
+        registry.initialize(&Address::generate(&env));
+
+        let tokenizer = Address::generate(&env);
+        let registry_asset_id = BytesN::random(&env);
+        registry.register_asset(
+            &Asset {
+                id: registry_asset_id.clone(),
+                name: String::from_str(&env, "Office Building"),
+                description: String::from_str(&env, "Downtown office building"),
+                category: String::from_str(&env, "Real Estate"),
+                owner: tokenizer.clone(),
+                registration_timestamp: 0,
+                last_transfer_timestamp: 0,
+                status: AssetStatus::Active,
+                metadata_uri: String::from_str(&env, "ipfs://QmBuilding"),
+                purchase_value: 1000,
+                custom_attributes: Vec::new(&env),
+            },
+            &registry.get_admin(),
+        );
+        registry.link_registry_asset(&ASSET_ID, &registry_asset_id, &tokenizer);
+        registry.tokenize_asset(
+            &ASSET_ID,
+            &String::from_str(&env, "BLDG"),
+            &SUPPLY,
+            &2u32,
+            &1i128,
+            &tokenizer,
+            &String::from_str(&env, "Office Building"),
+            &String::from_str(&env, "Fractional shares of an office building"),
+            &AssetType::Physical,
+            &None,
+        );
+
+        let token_id = env.register(AssetToken, (registry_id.clone(), ASSET_ID));
+        env.as_contract(&registry_id, || {
+            env.storage()
+                .persistent()
+                .set(&TokenDataKey::TokenContract(ASSET_ID), &token_id);
+        });
+        let token = AssetTokenClient::new(&env, &token_id);
+
+        Fixture {
+            env,
+            registry,
+            token,
+            tokenizer,
+        }
+    }
+
+    /// The events the token contract itself published in the most recent
+    /// invocation; the registry's own events are left out.
+    fn token_events(&self) -> std::vec::Vec<(Vec<Val>, Val)> {
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, _, _)| *contract == self.token.address)
+            .map(|(_, topics, data)| (topics, data))
+            .collect()
+    }
+
+    /// `(from, to, data)` of the single `transfer` event the token published.
+    fn transfer_event(&self) -> (Address, Address, Val) {
+        let events = self.token_events();
+        assert_eq!(events.len(), 1, "expected exactly one token event");
+        let (topics, data) = events[0].clone();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&self.env).unwrap();
+        assert_eq!(name, Symbol::new(&self.env, "transfer"));
+        (
+            topics.get(1).unwrap().try_into_val(&self.env).unwrap(),
+            topics.get(2).unwrap().try_into_val(&self.env).unwrap(),
+            data,
+        )
+    }
+}
+
+#[test]
+fn metadata_and_balances_come_from_the_registry() {
+    let f = Fixture::new();
+
+    assert_eq!(f.token.decimals(), 2);
+    assert_eq!(f.token.name(), String::from_str(&f.env, "Office Building"));
+    assert_eq!(f.token.symbol(), String::from_str(&f.env, "BLDG"));
+    assert_eq!(f.token.balance(&f.tokenizer), SUPPLY);
+    assert_eq!(f.token.balance(&Address::generate(&f.env)), 0);
+}
+
+#[test]
+fn transfer_moves_registry_balances() {
+    let f = Fixture::new();
+    let buyer = Address::generate(&f.env);
+
+    f.token.transfer(&f.tokenizer, &buyer, &250_000);
+
+    assert_eq!(f.registry.get_token_balance(&ASSET_ID, &buyer), 250_000);
+    assert_eq!(f.token.balance(&f.tokenizer), SUPPLY - 250_000);
+}
+
+#[test]
+fn transfer_event_reports_the_amount() {
+    let f = Fixture::new();
+    let buyer = Address::generate(&f.env);
+
+    f.token.transfer(&f.tokenizer, &buyer, &250_000);
+
+    let (from, to, data) = f.transfer_event();
+    assert_eq!((from, to), (f.tokenizer.clone(), buyer));
+    let amount: i128 = data.try_into_val(&f.env).unwrap();
+    assert_eq!(amount, 250_000);
+}
+
+#[test]
+fn transfer_event_reports_the_shares_received_net_of_a_share_fee() {
+    let f = Fixture::new();
+    let treasury = Address::generate(&f.env);
+    let buyer = Address::generate(&f.env);
+    f.token.transfer(&f.tokenizer, &buyer, &10_000);
+    let other = Address::generate(&f.env);
+    f.registry
+        .set_transfer_fee(&ASSET_ID, &100u32, &0i128, &None, &treasury, &0u32);
+
+    // 1% of 1 000 is withheld from what `other` receives.
+    f.token.transfer(&buyer, &other, &1_000);
+
+    let (_, _, data) = f.transfer_event();
+    let amount: i128 = data.try_into_val(&f.env).unwrap();
+    assert_eq!(amount, 990);
+    assert_eq!(f.token.balance(&other), 990);
+}
+
+#[test]
+fn transfer_to_a_muxed_address_carries_the_mux_id() {
+    let f = Fixture::new();
+    // Only account addresses can be multiplexed.
+    let muxed = MuxedAddress::generate(&f.env);
+    let buyer = muxed.address();
+    let muxed = MuxedAddress::new(buyer.clone(), 42);
+
+    f.token.transfer(&f.tokenizer, &muxed, &500);
+
+    let (_, to, data) = f.transfer_event();
+    assert_eq!(to, buyer);
+    let data: Map<Symbol, Val> = data.try_into_val(&f.env).unwrap();
+    let amount: i128 = data
+        .get(Symbol::new(&f.env, "amount"))
+        .unwrap()
+        .try_into_val(&f.env)
+        .unwrap();
+    let to_muxed_id: u64 = data
+        .get(Symbol::new(&f.env, "to_muxed_id"))
+        .unwrap()
+        .try_into_val(&f.env)
+        .unwrap();
+    assert_eq!((amount, to_muxed_id), (500, 42));
+    assert_eq!(f.token.balance(&buyer), 500);
+}
+
+#[test]
+fn zero_amounts_succeed_without_moving_anything() {
+    let f = Fixture::new();
+    let spender = Address::generate(&f.env);
+    let buyer = Address::generate(&f.env);
+    let expiry = f.env.ledger().sequence() + 100;
+    f.token.approve(&f.tokenizer, &spender, &100, &expiry);
+
+    f.token.transfer(&f.tokenizer, &buyer, &0);
+    assert!(f.token_events().is_empty());
+    f.token.transfer_from(&spender, &f.tokenizer, &buyer, &0);
+    assert!(f.token_events().is_empty());
+    f.token.burn(&f.tokenizer, &0);
+    assert!(f.token_events().is_empty());
+    f.token.burn_from(&spender, &f.tokenizer, &0);
+    assert!(f.token_events().is_empty());
+
+    assert_eq!(f.token.balance(&f.tokenizer), SUPPLY);
+    assert_eq!(f.token.balance(&buyer), 0);
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 100);
+}
+
+#[test]
+fn negative_amounts_are_rejected() {
+    let f = Fixture::new();
+    let buyer = Address::generate(&f.env);
+
+    let res = f.token.try_transfer(&f.tokenizer, &buyer, &-1);
+    assert_eq!(res, Err(Ok(TokenError::InvalidInput.into())));
+}
+
+#[test]
+fn transfer_requires_the_holder() {
+    let f = Fixture::new();
+    let buyer = Address::generate(&f.env);
+
+    f.env.set_auths(&[]);
+    assert!(f.token.try_transfer(&f.tokenizer, &buyer, &1).is_err());
+}
+
+#[test]
+fn transfer_from_spends_the_allowance() {
+    let f = Fixture::new();
+    let spender = Address::generate(&f.env);
+    let buyer = Address::generate(&f.env);
+    let expiry = f.env.ledger().sequence() + 100;
+
+    f.token.approve(&f.tokenizer, &spender, &1_000, &expiry);
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 1_000);
+
+    f.token.transfer_from(&spender, &f.tokenizer, &buyer, &600);
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 400);
+    assert_eq!(f.token.balance(&buyer), 600);
+
+    let res = f
+        .token
+        .try_transfer_from(&spender, &f.tokenizer, &buyer, &401);
+    assert_eq!(res, Err(Ok(TokenError::InsufficientAllowance.into())));
+}
+
+#[test]
+fn an_expired_allowance_reads_as_zero() {
+    let f = Fixture::new();
+    let spender = Address::generate(&f.env);
+    let expiry = f.env.ledger().sequence() + 10;
+
+    f.token.approve(&f.tokenizer, &spender, &1_000, &expiry);
+    f.env.ledger().with_mut(|l| l.sequence_number = expiry + 1);
+
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 0);
+    let res = f
+        .token
+        .try_transfer_from(&spender, &f.tokenizer, &spender, &1);
+    assert_eq!(res, Err(Ok(TokenError::InsufficientAllowance.into())));
+}
+
+#[test]
+fn approving_with_a_far_future_expiration_is_stored() {
+    let f = Fixture::new();
+    let spender = Address::generate(&f.env);
+
+    f.token.approve(&f.tokenizer, &spender, &1_000, &u32::MAX);
+
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 1_000);
+}
+
+#[test]
+fn approving_with_a_past_expiration_is_rejected() {
+    let f = Fixture::new();
+    f.env.ledger().with_mut(|l| l.sequence_number = 100);
+
+    let res = f
+        .token
+        .try_approve(&f.tokenizer, &Address::generate(&f.env), &1, &99);
+    assert_eq!(res, Err(Ok(TokenError::InvalidExpirationLedger.into())));
+}
+
+#[test]
+fn a_registry_lock_blocks_the_token_interface() {
+    let f = Fixture::new();
+    let holder = Address::generate(&f.env);
+    f.token.transfer(&f.tokenizer, &holder, &100);
+    f.registry.lock_tokens(
+        &ASSET_ID,
+        &holder,
+        &(f.env.ledger().timestamp() + 1_000),
+        &f.tokenizer,
+    );
+
+    let buyer = Address::generate(&f.env);
+    assert!(f.token.try_transfer(&holder, &buyer, &1).is_err());
+    assert!(f.token.try_burn(&holder, &1).is_err());
+    assert_eq!(f.token.balance(&holder), 100);
+}
+
+#[test]
+fn registry_transfer_restrictions_apply() {
+    let f = Fixture::new();
+    let approved = Address::generate(&f.env);
+    let outsider = Address::generate(&f.env);
+    f.registry.set_transfer_restriction(&ASSET_ID, &true);
+    f.registry.add_to_whitelist(&ASSET_ID, &f.tokenizer);
+    f.registry.add_to_whitelist(&ASSET_ID, &approved);
+
+    f.token.transfer(&f.tokenizer, &approved, &10);
+    assert!(f.token.try_transfer(&f.tokenizer, &outsider, &10).is_err());
+    assert_eq!(f.token.balance(&outsider), 0);
+}
+
+#[test]
+fn burn_reduces_the_supply() {
+    let f = Fixture::new();
+
+    f.token.burn(&f.tokenizer, &1_000);
+
+    assert_eq!(f.token.balance(&f.tokenizer), SUPPLY - 1_000);
+    assert_eq!(
+        f.registry.get_tokenized_asset(&ASSET_ID).total_supply,
+        SUPPLY - 1_000
+    );
+}
+
+#[test]
+fn burn_from_spends_the_allowance() {
+    let f = Fixture::new();
+    let spender = Address::generate(&f.env);
+    let expiry = f.env.ledger().sequence() + 100;
+    f.token.approve(&f.tokenizer, &spender, &500, &expiry);
+
+    f.token.burn_from(&spender, &f.tokenizer, &500);
+
+    assert_eq!(f.token.allowance(&f.tokenizer, &spender), 0);
+    assert_eq!(f.token.balance(&f.tokenizer), SUPPLY - 500);
+}
+
+#[test]
+fn the_registry_refuses_forwarded_calls_from_anyone_else() {
+    let f = Fixture::new();
+    let thief = Address::generate(&f.env);
+
+    // Only the bound token contract may forward a transfer; with no
+    // authorization mocked, a direct call cannot impersonate it.
+    f.env.set_auths(&[]);
+    assert!(f
+        .registry
+        .try_sep41_transfer(&ASSET_ID, &f.tokenizer, &thief, &1)
+        .is_err());
+    assert_eq!(f.token.balance(&thief), 0);
+}
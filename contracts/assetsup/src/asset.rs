@@ -1,5 +1,7 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
+use crate::error::Error;
+use crate::ttl;
 use crate::types::{AssetStatus, CustomAttribute};
 
 #[contracttype]
@@ -37,3 +39,44 @@ pub struct AssetInfo {
 }
 
 // Note: Contract methods implemented in lib.rs
+
+/// Makes `new_owner` the owner of a registry asset, moving it between the two
+/// owners' registries, and returns the previous owner. Storage only: callers
+/// authorize, audit and emit.
+pub(crate) fn reassign_owner(
+    env: &Env,
+    asset_id: &BytesN<32>,
+    new_owner: &Address,
+) -> Result<Address, Error> {
+    let key = DataKey::Asset(asset_id.clone());
+    let store = env.storage().persistent();
+    let mut asset: Asset = store.get(&key).ok_or(Error::AssetNotFound)?;
+    let old_owner = asset.owner.clone();
+
+    // Remove asset from old owner's registry
+    let old_owner_key = DataKey::OwnerRegistry(old_owner.clone());
+    let mut old_owner_assets: Vec<BytesN<32>> =
+        store.get(&old_owner_key).unwrap_or_else(|| Vec::new(env));
+    if let Some(index) = old_owner_assets.iter().position(|x| x == *asset_id) {
+        old_owner_assets.remove(index as u32);
+    }
+    store.set(&old_owner_key, &old_owner_assets);
+    ttl::extend_persistent(env, &old_owner_key);
+
+    // Add asset to new owner's registry
+    let new_owner_key = DataKey::OwnerRegistry(new_owner.clone());
+    let mut new_owner_assets: Vec<BytesN<32>> =
+        store.get(&new_owner_key).unwrap_or_else(|| Vec::new(env));
+    new_owner_assets.push_back(asset_id.clone());
+    store.set(&new_owner_key, &new_owner_assets);
+    ttl::extend_persistent(env, &new_owner_key);
+
+    // Update asset
+    asset.owner = new_owner.clone();
+    asset.last_transfer_timestamp = env.ledger().timestamp();
+    asset.status = AssetStatus::Transferred;
+    store.set(&key, &asset);
+    ttl::extend_persistent(env, &key);
+
+    Ok(old_owner)
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
+use crate::ttl;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
@@ -40,12 +42,14 @@ pub(crate) fn append_audit_log(
 
     log.push_back(entry);
     env.storage().persistent().set(&key, &log);
+    ttl::extend_persistent(env, &key);
 }
 
 /// Public function to retrieve the audit log for an asset
 /// Returns an empty vector if no history exists
 pub fn get_asset_log(env: &Env, asset_id: &BytesN<32>) -> Vec<AuditEntry> {
     let key = DataKey::AuditLog(asset_id.clone());
+    ttl::extend_persistent(env, &key);
     env.storage()
         .persistent()
         .get(&key)
@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::ttl;
 use crate::types::{
     ActiveProposal, DetokenizationProposal, ExecutedProposal, RejectedProposal, TokenDataKey,
     TokenizedAsset,
@@ -13,9 +14,11 @@ pub fn propose_detokenization(env: &Env, asset_id: u64, proposer: Address) -> Re
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Check if proposal already exists
     let proposal_key = TokenDataKey::DetokenizationProposal(asset_id);
+    ttl::extend_persistent(env, &proposal_key);
     if store.has(&proposal_key) {
         if let Some(DetokenizationProposal::Active(_)) =
             store.get::<_, DetokenizationProposal>(&proposal_key)
@@ -35,6 +38,7 @@ pub fn propose_detokenization(env: &Env, asset_id: u64, proposer: Address) -> Re
     });
 
     store.set(&proposal_key, &proposal);
+    ttl::extend_persistent(env, &proposal_key);
 
     Ok(proposal_id)
 }
@@ -47,9 +51,11 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Check if proposal is active
     let proposal_key = TokenDataKey::DetokenizationProposal(asset_id);
+    ttl::extend_persistent(env, &proposal_key);
     match store.get::<_, DetokenizationProposal>(&proposal_key) {
         Some(DetokenizationProposal::Active(_)) => {
             // Continue
@@ -72,10 +78,7 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
     voting::clear_proposal_votes(env, asset_id, proposal_id)?;
 
     // Get list of all token holders before clearing
-    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
-    let holders = store
-        .get::<_, soroban_sdk::Vec<Address>>(&holders_list_key)
-        .ok_or(Error::AssetNotTokenized)?;
+    let holders = crate::holders::all(env, asset_id);
 
     // Remove all token holder records
     for holder in holders.iter() {
@@ -90,6 +93,15 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
             store.remove(&lock_key);
         }
 
+        // Remove holding-period lots
+        crate::holding::clear(env, asset_id, &holder);
+
+        // Remove vesting grants
+        let grants_key = crate::vesting::DataKey::Grants(asset_id, holder.clone());
+        if store.has(&grants_key) {
+            store.remove(&grants_key);
+        }
+
         // Remove unclaimed dividends
         let dividend_key = TokenDataKey::UnclaimedDividend(asset_id, holder);
         if store.has(&dividend_key) {
@@ -97,9 +109,14 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         }
     }
 
-    // Remove token holders list
-    if store.has(&holders_list_key) {
-        store.remove(&holders_list_key);
+    // Remove the holder set
+    crate::holders::clear(env, asset_id);
+    crate::tokenizer::clear(env, asset_id);
+
+    // Remove the vesting holder index
+    let vesting_holders_key = crate::vesting::DataKey::Holders(asset_id);
+    if store.has(&vesting_holders_key) {
+        store.remove(&vesting_holders_key);
     }
 
     // Remove transfer restrictions
@@ -108,6 +125,10 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         store.remove(&restriction_key);
     }
 
+    store.remove(&TokenDataKey::HolderLimits(asset_id));
+    store.remove(&crate::fees::DataKey::Config(asset_id));
+    store.remove(&crate::holding::DataKey::Period(asset_id));
+
     // Remove whitelist
     let whitelist_key = TokenDataKey::Whitelist(asset_id);
     if store.has(&whitelist_key) {
@@ -120,6 +141,12 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         store.remove(&metadata_key);
     }
 
+    // Remove the supply cap
+    store.remove(&TokenDataKey::MaxSupply(asset_id));
+
+    // The SEP-41 token binding is deliberately kept: the deployed contract
+    // cannot be redeployed at the same address. See `token_contract`.
+
     // Remove the tokenized asset record (this eliminates all tokens from circulation)
     if store.has(&key) {
         store.remove(&key);
@@ -132,6 +159,7 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         executed_at: timestamp,
     });
     store.set(&proposal_key, &executed_proposal);
+    ttl::extend_persistent(env, &proposal_key);
 
     // Emit event: (asset_id, proposal_id, total_supply_removed)
     crate::events::asset_detokenized(env, asset_id, proposal_id, total_supply);
@@ -147,11 +175,13 @@ pub fn reject_detokenization(env: &Env, asset_id: u64) -> Result<(), Error> {
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get proposal
     let proposal_key = TokenDataKey::DetokenizationProposal(asset_id);
     let proposal: DetokenizationProposal =
         store.get(&proposal_key).ok_or(Error::InvalidProposal)?;
+    ttl::extend_persistent(env, &proposal_key);
 
     match proposal {
         DetokenizationProposal::Active(ActiveProposal { proposal_id, .. }) => {
@@ -162,6 +192,7 @@ pub fn reject_detokenization(env: &Env, asset_id: u64) -> Result<(), Error> {
                 rejected_at: timestamp,
             });
             store.set(&proposal_key, &rejected_proposal);
+            ttl::extend_persistent(env, &proposal_key);
 
             // Clear votes
             voting::clear_proposal_votes(env, asset_id, proposal_id)?;
@@ -180,6 +211,7 @@ pub fn get_detokenization_proposal(
     let store = env.storage().persistent();
 
     let key = TokenDataKey::DetokenizationProposal(asset_id);
+    ttl::extend_persistent(env, &key);
     store.get(&key).ok_or(Error::InvalidProposal)
 }
 
@@ -188,6 +220,7 @@ pub fn is_detokenization_active(env: &Env, asset_id: u64) -> Result<bool, Error>
     let store = env.storage().persistent();
 
     let key = TokenDataKey::DetokenizationProposal(asset_id);
+    ttl::extend_persistent(env, &key);
     match store.get::<_, DetokenizationProposal>(&key) {
         Some(DetokenizationProposal::Active(_)) => Ok(true),
         _ => Ok(false),
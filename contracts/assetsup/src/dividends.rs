@@ -1,7 +1,8 @@
 use crate::error::Error;
 use crate::math;
+use crate::ttl;
 use crate::types::{OwnershipRecord, TokenDataKey, TokenizedAsset};
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Env};
 
 /// Distribute dividends proportionally to all token holders
 pub fn distribute_dividends(env: &Env, asset_id: u64, total_amount: i128) -> Result<(), Error> {
@@ -14,14 +15,14 @@ pub fn distribute_dividends(env: &Env, asset_id: u64, total_amount: i128) -> Res
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     if !tokenized_asset.revenue_sharing_enabled {
         return Err(Error::InvalidDividendAmount);
     }
 
     // Get all token holders
-    let holders_key = TokenDataKey::TokenHoldersList(asset_id);
-    let holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
+    let holders = crate::holders::all(env, asset_id);
 
     // Distribute proportionally to each holder
     for holder in holders.iter() {
@@ -43,6 +44,7 @@ pub fn distribute_dividends(env: &Env, asset_id: u64, total_amount: i128) -> Res
         ownership.unclaimed_dividends = math::add(ownership.unclaimed_dividends, proportion)?;
 
         store.set(&holder_key, &ownership);
+        ttl::extend_persistent(env, &holder_key);
     }
 
     // Emit event: (asset_id, total_amount, holder_count)
@@ -58,10 +60,14 @@ pub fn claim_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+
+    crate::compliance::require_not_frozen(env, asset_id, &holder)?;
 
     // Get holder's ownership record
     let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
     let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    ttl::extend_persistent(env, &holder_key);
 
     // Get unclaimed amount
     let unclaimed = ownership.unclaimed_dividends;
@@ -72,7 +78,7 @@ pub fn claim_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128
 
     // Clear unclaimed dividends
     ownership.unclaimed_dividends = 0;
-    store.set(&holder_key, &ownership);
+    crate::tokenization::store_ownership(env, &holder_key, &ownership);
 
     // Emit event: (asset_id, holder, amount)
     crate::events::dividend_claimed(env, asset_id, &holder, unclaimed);
@@ -87,9 +93,11 @@ pub fn get_unclaimed_dividends(env: &Env, asset_id: u64, holder: Address) -> Res
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get holder's ownership record
     let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
+    ttl::extend_persistent(env, &holder_key);
     match store.get::<_, OwnershipRecord>(&holder_key) {
         Some(ownership) => Ok(ownership.unclaimed_dividends),
         None => Ok(0),
@@ -105,6 +113,7 @@ pub fn enable_revenue_sharing(env: &Env, asset_id: u64) -> Result<(), Error> {
 
     tokenized_asset.revenue_sharing_enabled = true;
     store.set(&key, &tokenized_asset);
+    ttl::extend_persistent(env, &key);
 
     Ok(())
 }
@@ -118,6 +127,7 @@ pub fn disable_revenue_sharing(env: &Env, asset_id: u64) -> Result<(), Error> {
 
     tokenized_asset.revenue_sharing_enabled = false;
     store.set(&key, &tokenized_asset);
+    ttl::extend_persistent(env, &key);
 
     Ok(())
 }
@@ -16,6 +16,10 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 /// A published code is permanent. Retiring a variant leaves its number unused;
 /// it is never reassigned, because a backend built against the old meaning
 /// would silently misinterpret the new one.
+///
+/// The contract spec allows at most 50 variants in one error enum. Prefer a
+/// shared code when it describes the failure accurately, and add a variant
+/// only when a caller genuinely needs to tell the case apart.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -50,16 +54,17 @@ pub enum Error {
     AssetAlreadyExists = 101,
     /// No asset is registered under this id.
     AssetNotFound = 102,
-    /// A branch with this id already exists.
-    BranchAlreadyExists = 103,
-    /// No branch is registered under this id.
-    BranchNotFound = 104,
-    /// A subscription already exists for this account.
-    SubscriptionAlreadyExists = 105,
+    // 103-105 retired: BranchAlreadyExists, BranchNotFound,
+    // SubscriptionAlreadyExists. Never returned by any entrypoint.
     /// The payment supplied is missing or does not cover the amount due.
     InvalidPayment = 106,
     /// The contract has not been initialized with its metadata.
     ContractNotInitialized = 107,
+    /// A mutating entrypoint was called while the stored data is behind the
+    /// layout this build expects, or an asset's holders changed before its
+    /// legacy holder list was converted. The admin must finish `migrate` first,
+    /// queueing the asset with `queue_migration` if it was missed.
+    MigrationInProgress = 108,
 
     // ---------------------------------------------------------------
     // Tokenization and balances: 120–139
@@ -80,14 +85,24 @@ pub enum Error {
     TokensAreLocked = 126,
     /// A transfer restriction on this asset rejected the transfer.
     TransferRestrictionFailed = 127,
-    /// The address is not on this asset's transfer whitelist.
-    NotWhitelisted = 128,
+    // 128 retired: NotWhitelisted. Never returned by any entrypoint.
     /// This asset may only be held by accredited investors.
     AccreditedInvestorRequired = 129,
-    /// The address's jurisdiction is not permitted to hold this asset.
-    GeographicRestriction = 130,
+    // 130 retired: GeographicRestriction. Never returned by any entrypoint.
     /// No ownership record exists for this holder.
     HolderNotFound = 131,
+    /// The vesting grant was created as irrevocable.
+    VestingNotRevocable = 132,
+    /// A compliance officer has frozen this holder.
+    HolderFrozen = 133,
+    /// The offering or listing is not in a state that permits this action.
+    InvalidSaleState = 134,
+    /// The transfer would take the asset past its maximum number of holders.
+    MaxHoldersExceeded = 135,
+    /// The transfer would leave the recipient above the per-holder ownership cap.
+    OwnershipCapExceeded = 136,
+    /// The transfer would leave a holder with less than the minimum holding.
+    BelowMinimumHolding = 137,
 
     // ---------------------------------------------------------------
     // Voting: 140–149
@@ -96,12 +111,10 @@ pub enum Error {
     InsufficientVotingPower = 140,
     /// This address has already voted on this proposal.
     AlreadyVoted = 141,
-    /// No proposal exists under this id.
-    ProposalNotFound = 142,
+    // 142 retired: ProposalNotFound. Never returned by any entrypoint.
     /// The proposal is malformed or in a state that does not allow this action.
     InvalidProposal = 143,
-    /// The voting period for this proposal has closed.
-    VotingPeriodEnded = 144,
+    // 144 retired: VotingPeriodEnded. Never returned by any entrypoint.
 
     // ---------------------------------------------------------------
     // Dividends: 150–159
@@ -120,6 +133,8 @@ pub enum Error {
     DetokenizationAlreadyProposed = 161,
     /// The supplied valuation is zero or negative.
     InvalidValuation = 162,
+    /// The submitter is not an approved valuation oracle.
+    OracleNotAuthorized = 163,
 
     // ---------------------------------------------------------------
     // Validation: 170–179
@@ -206,11 +221,9 @@ mod tests {
             Error::AdminNotFound as u32,
             Error::AssetAlreadyExists as u32,
             Error::AssetNotFound as u32,
-            Error::BranchAlreadyExists as u32,
-            Error::BranchNotFound as u32,
-            Error::SubscriptionAlreadyExists as u32,
             Error::InvalidPayment as u32,
             Error::ContractNotInitialized as u32,
+            Error::MigrationInProgress as u32,
             Error::AssetAlreadyTokenized as u32,
             Error::AssetNotTokenized as u32,
             Error::InvalidTokenSupply as u32,
@@ -219,20 +232,23 @@ mod tests {
             Error::InsufficientLockedTokens as u32,
             Error::TokensAreLocked as u32,
             Error::TransferRestrictionFailed as u32,
-            Error::NotWhitelisted as u32,
             Error::AccreditedInvestorRequired as u32,
-            Error::GeographicRestriction as u32,
             Error::HolderNotFound as u32,
+            Error::VestingNotRevocable as u32,
+            Error::HolderFrozen as u32,
+            Error::InvalidSaleState as u32,
+            Error::MaxHoldersExceeded as u32,
+            Error::OwnershipCapExceeded as u32,
+            Error::BelowMinimumHolding as u32,
             Error::InsufficientVotingPower as u32,
             Error::AlreadyVoted as u32,
-            Error::ProposalNotFound as u32,
             Error::InvalidProposal as u32,
-            Error::VotingPeriodEnded as u32,
             Error::NoDividendsToClaim as u32,
             Error::InvalidDividendAmount as u32,
             Error::DetokenizationNotApproved as u32,
             Error::DetokenizationAlreadyProposed as u32,
             Error::InvalidValuation as u32,
+            Error::OracleNotAuthorized as u32,
             Error::InvalidAssetName as u32,
             Error::InvalidPurchaseValue as u32,
             Error::InvalidMetadataUri as u32,
@@ -11,6 +11,8 @@
 
 use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
+use crate::pause::PauseScope;
+
 // ---------------------------------------------------------------------------
 // Registry
 // ---------------------------------------------------------------------------
@@ -132,6 +134,44 @@ pub struct ContractUnpaused {
     pub timestamp: u64,
 }
 
+/// A module, a tokenized asset or the whole contract was paused, until
+/// `expires_at` if set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopePaused {
+    #[topic]
+    pub scope: PauseScope,
+    pub paused_by: Address,
+    pub expires_at: Option<u64>,
+}
+
+/// The admin lifted a scoped pause.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopeUnpaused {
+    #[topic]
+    pub scope: PauseScope,
+    pub admin: Address,
+}
+
+/// An address was allowed to pause.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauserAdded {
+    #[topic]
+    pub pauser: Address,
+    pub timestamp: u64,
+}
+
+/// An address lost the right to pause.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauserRemoved {
+    #[topic]
+    pub pauser: Address,
+    pub timestamp: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Tokenization
 // ---------------------------------------------------------------------------
@@ -154,6 +194,7 @@ pub struct AssetTokenized {
 pub struct TokensMinted {
     #[topic]
     pub asset_id: u64,
+    pub to: Address,
     pub amount: i128,
     pub total_supply: i128,
 }
@@ -176,7 +217,11 @@ pub struct TokensTransferred {
     pub asset_id: u64,
     pub from: Address,
     pub to: Address,
+    /// Shares debited from `from`. A share fee is withheld from this.
     pub amount: i128,
+    pub fee: i128,
+    /// Token the fee was paid in; `None` for shares.
+    pub fee_token: Option<Address>,
 }
 
 /// A holder's tokens were locked until a given timestamp.
@@ -199,6 +244,40 @@ pub struct TokensUnlocked {
     pub timestamp: u64,
 }
 
+/// Tokens were granted to a holder under a vesting schedule.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrantCreated {
+    #[topic]
+    pub asset_id: u64,
+    pub holder: Address,
+    pub grant_id: u32,
+    pub amount: i128,
+    pub cliff: u64,
+    pub end: u64,
+}
+
+/// A vesting grant was revoked and its unvested tokens returned to the
+/// tokenizer.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrantRevoked {
+    #[topic]
+    pub asset_id: u64,
+    pub holder: Address,
+    pub grant_id: u32,
+    pub returned: i128,
+}
+
+/// A SEP-41 token contract was deployed and bound to a tokenized asset.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenContractDeployed {
+    #[topic]
+    pub asset_id: u64,
+    pub token: Address,
+}
+
 /// A tokenized asset's valuation was updated.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -208,6 +287,101 @@ pub struct ValuationUpdated {
     pub new_valuation: i128,
 }
 
+/// An oracle submitted a valuation for a tokenized asset. The stored
+/// valuation only moves once the submissions are aggregated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValuationSubmitted {
+    #[topic]
+    pub asset_id: u64,
+    pub oracle: Address,
+    pub value: i128,
+    pub report_hash: BytesN<32>,
+}
+
+/// A fresh submission was left out of the aggregate for deviating too far
+/// from the median.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValuationOutlierRejected {
+    #[topic]
+    pub asset_id: u64,
+    pub oracle: Address,
+    pub value: i128,
+    pub median: i128,
+}
+
+/// An address was approved as a valuation oracle.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleAdded {
+    #[topic]
+    pub oracle: Address,
+    pub timestamp: u64,
+}
+
+/// An address lost its valuation oracle approval.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleRemoved {
+    #[topic]
+    pub oracle: Address,
+    pub timestamp: u64,
+}
+
+/// A tokenizer changed one field of an asset's token metadata.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadataUpdated {
+    #[topic]
+    pub asset_id: u64,
+    pub revision: u32,
+    pub field: crate::metadata::MetadataField,
+    pub updated_by: Address,
+}
+
+/// A tokenizer nominated a successor for the role.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenizerProposed {
+    #[topic]
+    pub asset_id: u64,
+    pub current_tokenizer: Address,
+    pub proposed_tokenizer: Address,
+}
+
+/// A pending tokenizer nomination was withdrawn.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenizerProposalCancelled {
+    #[topic]
+    pub asset_id: u64,
+    pub current_tokenizer: Address,
+    pub proposed_tokenizer: Address,
+}
+
+/// The tokenizer role moved, either accepted by a nominee or carried along
+/// by a transfer of the linked registry asset (`with_registry`).
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenizerChanged {
+    #[topic]
+    pub asset_id: u64,
+    pub old_tokenizer: Address,
+    pub new_tokenizer: Address,
+    pub with_registry: bool,
+}
+
+/// A tokenized asset was linked to the registry asset it represents.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryAssetLinked {
+    #[topic]
+    pub asset_id: u64,
+    pub registry_asset_id: BytesN<32>,
+    pub owner: Address,
+}
+
 /// A tokenized asset was fully detokenized.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -218,6 +392,159 @@ pub struct AssetDetokenized {
     pub total_supply: i128,
 }
 
+// ---------------------------------------------------------------------------
+// Primary offerings
+// ---------------------------------------------------------------------------
+
+/// A tokenizer opened a primary offering and escrowed its hard cap.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferingCreated {
+    #[topic]
+    pub asset_id: u64,
+    pub payment_token: Address,
+    pub price: i128,
+    pub soft_cap: i128,
+    pub hard_cap: i128,
+    pub start: u64,
+    pub end: u64,
+}
+
+/// An investor bought shares in a primary offering.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferingPurchased {
+    #[topic]
+    pub asset_id: u64,
+    pub investor: Address,
+    pub shares: i128,
+    pub cost: i128,
+}
+
+/// A primary offering closed, successfully or not.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferingFinalized {
+    #[topic]
+    pub asset_id: u64,
+    pub succeeded: bool,
+    pub sold: i128,
+    pub raised: i128,
+}
+
+/// An investor claimed their shares, or their refund, from a closed offering.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferingClaimed {
+    #[topic]
+    pub asset_id: u64,
+    pub investor: Address,
+    pub shares: i128,
+    pub refund: i128,
+}
+
+// ---------------------------------------------------------------------------
+// Secondary market
+// ---------------------------------------------------------------------------
+
+/// A holder listed shares for sale and escrowed them.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingCreated {
+    #[topic]
+    pub asset_id: u64,
+    pub listing_id: u64,
+    pub seller: Address,
+    pub amount: i128,
+    pub payment_token: Address,
+    pub price: i128,
+    pub expires_at: u64,
+}
+
+/// A buyer took all or part of a listing.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingFilled {
+    #[topic]
+    pub asset_id: u64,
+    pub listing_id: u64,
+    pub seller: Address,
+    pub buyer: Address,
+    pub amount: i128,
+    pub cost: i128,
+    pub fee: i128,
+    pub fee_token: Option<Address>,
+}
+
+/// A listing was withdrawn by its seller, or closed after expiry, and its
+/// unsold shares returned.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingCancelled {
+    #[topic]
+    pub asset_id: u64,
+    pub listing_id: u64,
+    pub seller: Address,
+    pub returned: i128,
+    pub expired: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Compliance
+// ---------------------------------------------------------------------------
+
+/// An address was approved as a compliance officer.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceOfficerAdded {
+    #[topic]
+    pub officer: Address,
+    pub timestamp: u64,
+}
+
+/// An address lost its compliance officer approval.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceOfficerRemoved {
+    #[topic]
+    pub officer: Address,
+    pub timestamp: u64,
+}
+
+/// A compliance officer froze a holder of a tokenized asset.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderFrozen {
+    #[topic]
+    pub asset_id: u64,
+    pub holder: Address,
+    pub officer: Address,
+}
+
+/// A compliance officer lifted a holder's freeze.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderUnfrozen {
+    #[topic]
+    pub asset_id: u64,
+    pub holder: Address,
+    pub officer: Address,
+}
+
+/// A compliance officer moved tokens without the holder's consent. Distinct
+/// from `TokensTransferred` so indexers can never mistake one for the other.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensForceTransferred {
+    #[topic]
+    pub asset_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub reason_hash: BytesN<32>,
+    pub officer: Address,
+}
+
 // ---------------------------------------------------------------------------
 // Dividends and voting
 // ---------------------------------------------------------------------------
@@ -257,6 +584,35 @@ pub struct VoteCast {
 // Transfer restrictions
 // ---------------------------------------------------------------------------
 
+/// An asset's transfer fee was set, or removed when `config` is `None`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeSet {
+    #[topic]
+    pub asset_id: u64,
+    pub config: Option<crate::fees::FeeConfig>,
+}
+
+/// An address was made exempt from an asset's transfer fee, or lost its
+/// exemption.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeExemptionSet {
+    #[topic]
+    pub asset_id: u64,
+    pub address: Address,
+    pub exempt: bool,
+}
+
+/// An asset's holding period was set. Zero removes it.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldingPeriodSet {
+    #[topic]
+    pub asset_id: u64,
+    pub period: u64,
+}
+
 /// A transfer restriction was set on an asset.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -266,6 +622,17 @@ pub struct RestrictionSet {
     pub require_accredited: bool,
 }
 
+/// An asset's holder limits were set. Zero disables a limit.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderLimitsSet {
+    #[topic]
+    pub asset_id: u64,
+    pub max_holders: u32,
+    pub max_ownership_bps: u32,
+    pub min_holding: i128,
+}
+
 /// An address was added to an asset's transfer whitelist.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -344,6 +711,32 @@ pub struct ContractUpgraded {
     pub timestamp: u64,
 }
 
+/// An upgrade was scheduled; it can be installed from `executable_at`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeScheduled {
+    #[topic]
+    pub wasm_hash: BytesN<32>,
+    pub executable_at: u64,
+}
+
+/// A scheduled upgrade was cancelled before it was installed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeCancelled {
+    #[topic]
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// The delay applied to newly scheduled upgrades changed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeDelaySet {
+    pub delay: u64,
+    pub timestamp: u64,
+}
+
 /// Storage was migrated from one layout version to another.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -466,6 +859,39 @@ pub fn contract_unpaused(env: &Env, admin: &Address) {
     .publish(env);
 }
 
+pub fn scope_paused(env: &Env, scope: PauseScope, paused_by: &Address, expires_at: Option<u64>) {
+    ScopePaused {
+        scope,
+        paused_by: paused_by.clone(),
+        expires_at,
+    }
+    .publish(env);
+}
+
+pub fn scope_unpaused(env: &Env, scope: PauseScope, admin: &Address) {
+    ScopeUnpaused {
+        scope,
+        admin: admin.clone(),
+    }
+    .publish(env);
+}
+
+pub fn pauser_added(env: &Env, pauser: &Address) {
+    PauserAdded {
+        pauser: pauser.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn pauser_removed(env: &Env, pauser: &Address) {
+    PauserRemoved {
+        pauser: pauser.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
 pub fn asset_tokenized(
     env: &Env,
     asset_id: u64,
@@ -484,9 +910,10 @@ pub fn asset_tokenized(
     .publish(env);
 }
 
-pub fn tokens_minted(env: &Env, asset_id: u64, amount: i128, total_supply: i128) {
+pub fn tokens_minted(env: &Env, asset_id: u64, to: &Address, amount: i128, total_supply: i128) {
     TokensMinted {
         asset_id,
+        to: to.clone(),
         amount,
         total_supply,
     }
@@ -502,12 +929,22 @@ pub fn tokens_burned(env: &Env, asset_id: u64, amount: i128, total_supply: i128)
     .publish(env);
 }
 
-pub fn tokens_transferred(env: &Env, asset_id: u64, from: &Address, to: &Address, amount: i128) {
+pub fn tokens_transferred(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    fee: i128,
+    fee_token: Option<Address>,
+) {
     TokensTransferred {
         asset_id,
         from: from.clone(),
         to: to.clone(),
         amount,
+        fee,
+        fee_token,
     }
     .publish(env);
 }
@@ -530,6 +967,119 @@ pub fn tokens_unlocked(env: &Env, asset_id: u64, holder: &Address) {
     .publish(env);
 }
 
+pub fn vesting_grant_created(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    grant_id: u32,
+    amount: i128,
+    cliff: u64,
+    end: u64,
+) {
+    VestingGrantCreated {
+        asset_id,
+        holder: holder.clone(),
+        grant_id,
+        amount,
+        cliff,
+        end,
+    }
+    .publish(env);
+}
+
+pub fn vesting_grant_revoked(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    grant_id: u32,
+    returned: i128,
+) {
+    VestingGrantRevoked {
+        asset_id,
+        holder: holder.clone(),
+        grant_id,
+        returned,
+    }
+    .publish(env);
+}
+
+pub fn token_contract_deployed(env: &Env, asset_id: u64, token: &Address) {
+    TokenContractDeployed {
+        asset_id,
+        token: token.clone(),
+    }
+    .publish(env);
+}
+
+pub fn token_metadata_updated(
+    env: &Env,
+    asset_id: u64,
+    revision: u32,
+    field: crate::metadata::MetadataField,
+    updated_by: &Address,
+) {
+    TokenMetadataUpdated {
+        asset_id,
+        revision,
+        field,
+        updated_by: updated_by.clone(),
+    }
+    .publish(env);
+}
+
+pub fn tokenizer_proposed(env: &Env, asset_id: u64, current: &Address, proposed: &Address) {
+    TokenizerProposed {
+        asset_id,
+        current_tokenizer: current.clone(),
+        proposed_tokenizer: proposed.clone(),
+    }
+    .publish(env);
+}
+
+pub fn tokenizer_proposal_cancelled(
+    env: &Env,
+    asset_id: u64,
+    current: &Address,
+    proposed: &Address,
+) {
+    TokenizerProposalCancelled {
+        asset_id,
+        current_tokenizer: current.clone(),
+        proposed_tokenizer: proposed.clone(),
+    }
+    .publish(env);
+}
+
+pub fn tokenizer_changed(
+    env: &Env,
+    asset_id: u64,
+    old_tokenizer: &Address,
+    new_tokenizer: &Address,
+    with_registry: bool,
+) {
+    TokenizerChanged {
+        asset_id,
+        old_tokenizer: old_tokenizer.clone(),
+        new_tokenizer: new_tokenizer.clone(),
+        with_registry,
+    }
+    .publish(env);
+}
+
+pub fn registry_asset_linked(
+    env: &Env,
+    asset_id: u64,
+    registry_asset_id: &BytesN<32>,
+    owner: &Address,
+) {
+    RegistryAssetLinked {
+        asset_id,
+        registry_asset_id: registry_asset_id.clone(),
+        owner: owner.clone(),
+    }
+    .publish(env);
+}
+
 pub fn valuation_updated(env: &Env, asset_id: u64, new_valuation: i128) {
     ValuationUpdated {
         asset_id,
@@ -538,6 +1088,54 @@ pub fn valuation_updated(env: &Env, asset_id: u64, new_valuation: i128) {
     .publish(env);
 }
 
+pub fn valuation_submitted(
+    env: &Env,
+    asset_id: u64,
+    oracle: &Address,
+    value: i128,
+    report_hash: &BytesN<32>,
+) {
+    ValuationSubmitted {
+        asset_id,
+        oracle: oracle.clone(),
+        value,
+        report_hash: report_hash.clone(),
+    }
+    .publish(env);
+}
+
+pub fn valuation_outlier_rejected(
+    env: &Env,
+    asset_id: u64,
+    oracle: &Address,
+    value: i128,
+    median: i128,
+) {
+    ValuationOutlierRejected {
+        asset_id,
+        oracle: oracle.clone(),
+        value,
+        median,
+    }
+    .publish(env);
+}
+
+pub fn oracle_added(env: &Env, oracle: &Address) {
+    OracleAdded {
+        oracle: oracle.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn oracle_removed(env: &Env, oracle: &Address) {
+    OracleRemoved {
+        oracle: oracle.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
 pub fn asset_detokenized(env: &Env, asset_id: u64, proposal_id: u64, total_supply: i128) {
     AssetDetokenized {
         asset_id,
@@ -547,6 +1145,159 @@ pub fn asset_detokenized(env: &Env, asset_id: u64, proposal_id: u64, total_suppl
     .publish(env);
 }
 
+pub fn offering_created(env: &Env, asset_id: u64, terms: &crate::offering::OfferingTerms) {
+    OfferingCreated {
+        asset_id,
+        payment_token: terms.payment_token.clone(),
+        price: terms.price,
+        soft_cap: terms.soft_cap,
+        hard_cap: terms.hard_cap,
+        start: terms.start,
+        end: terms.end,
+    }
+    .publish(env);
+}
+
+pub fn offering_purchased(env: &Env, asset_id: u64, investor: &Address, shares: i128, cost: i128) {
+    OfferingPurchased {
+        asset_id,
+        investor: investor.clone(),
+        shares,
+        cost,
+    }
+    .publish(env);
+}
+
+pub fn offering_finalized(env: &Env, asset_id: u64, succeeded: bool, sold: i128, raised: i128) {
+    OfferingFinalized {
+        asset_id,
+        succeeded,
+        sold,
+        raised,
+    }
+    .publish(env);
+}
+
+pub fn offering_claimed(env: &Env, asset_id: u64, investor: &Address, shares: i128, refund: i128) {
+    OfferingClaimed {
+        asset_id,
+        investor: investor.clone(),
+        shares,
+        refund,
+    }
+    .publish(env);
+}
+
+pub fn listing_created(env: &Env, asset_id: u64, listing: &crate::market::Listing) {
+    ListingCreated {
+        asset_id,
+        listing_id: listing.listing_id,
+        seller: listing.seller.clone(),
+        amount: listing.remaining,
+        payment_token: listing.payment_token.clone(),
+        price: listing.price,
+        expires_at: listing.expires_at,
+    }
+    .publish(env);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn listing_filled(
+    env: &Env,
+    asset_id: u64,
+    listing_id: u64,
+    seller: &Address,
+    buyer: &Address,
+    amount: i128,
+    cost: i128,
+    fee: i128,
+    fee_token: Option<Address>,
+) {
+    ListingFilled {
+        asset_id,
+        listing_id,
+        seller: seller.clone(),
+        buyer: buyer.clone(),
+        amount,
+        cost,
+        fee,
+        fee_token,
+    }
+    .publish(env);
+}
+
+pub fn listing_cancelled(
+    env: &Env,
+    asset_id: u64,
+    listing_id: u64,
+    seller: &Address,
+    returned: i128,
+    expired: bool,
+) {
+    ListingCancelled {
+        asset_id,
+        listing_id,
+        seller: seller.clone(),
+        returned,
+        expired,
+    }
+    .publish(env);
+}
+
+pub fn compliance_officer_added(env: &Env, officer: &Address) {
+    ComplianceOfficerAdded {
+        officer: officer.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn compliance_officer_removed(env: &Env, officer: &Address) {
+    ComplianceOfficerRemoved {
+        officer: officer.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn holder_frozen(env: &Env, asset_id: u64, holder: &Address, officer: &Address) {
+    HolderFrozen {
+        asset_id,
+        holder: holder.clone(),
+        officer: officer.clone(),
+    }
+    .publish(env);
+}
+
+pub fn holder_unfrozen(env: &Env, asset_id: u64, holder: &Address, officer: &Address) {
+    HolderUnfrozen {
+        asset_id,
+        holder: holder.clone(),
+        officer: officer.clone(),
+    }
+    .publish(env);
+}
+
+pub fn tokens_force_transferred(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    reason_hash: &BytesN<32>,
+    officer: &Address,
+) {
+    TokensForceTransferred {
+        asset_id,
+        from: from.clone(),
+        to: to.clone(),
+        amount,
+        reason_hash: reason_hash.clone(),
+        officer: officer.clone(),
+    }
+    .publish(env);
+}
+
 pub fn dividend_distributed(env: &Env, asset_id: u64, total_amount: i128, holder_count: u32) {
     DividendDistributed {
         asset_id,
@@ -575,6 +1326,23 @@ pub fn vote_cast(env: &Env, asset_id: u64, proposal_id: u64, voter: &Address, vo
     .publish(env);
 }
 
+pub fn transfer_fee_set(env: &Env, asset_id: u64, config: Option<crate::fees::FeeConfig>) {
+    TransferFeeSet { asset_id, config }.publish(env);
+}
+
+pub fn fee_exemption_set(env: &Env, asset_id: u64, address: &Address, exempt: bool) {
+    FeeExemptionSet {
+        asset_id,
+        address: address.clone(),
+        exempt,
+    }
+    .publish(env);
+}
+
+pub fn holding_period_set(env: &Env, asset_id: u64, period: u64) {
+    HoldingPeriodSet { asset_id, period }.publish(env);
+}
+
 pub fn restriction_set(env: &Env, asset_id: u64, require_accredited: bool) {
     RestrictionSet {
         asset_id,
@@ -583,6 +1351,16 @@ pub fn restriction_set(env: &Env, asset_id: u64, require_accredited: bool) {
     .publish(env);
 }
 
+pub fn holder_limits_set(env: &Env, asset_id: u64, limits: &crate::types::HolderLimits) {
+    HolderLimitsSet {
+        asset_id,
+        max_holders: limits.max_holders,
+        max_ownership_bps: limits.max_ownership_bps,
+        min_holding: limits.min_holding,
+    }
+    .publish(env);
+}
+
 pub fn whitelist_added(env: &Env, asset_id: u64, address: &Address) {
     WhitelistAdded {
         asset_id,
@@ -652,6 +1430,30 @@ pub fn contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>,
     .publish(env);
 }
 
+pub fn upgrade_scheduled(env: &Env, wasm_hash: &BytesN<32>, executable_at: u64) {
+    UpgradeScheduled {
+        wasm_hash: wasm_hash.clone(),
+        executable_at,
+    }
+    .publish(env);
+}
+
+pub fn upgrade_cancelled(env: &Env, wasm_hash: &BytesN<32>) {
+    UpgradeCancelled {
+        wasm_hash: wasm_hash.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn upgrade_delay_set(env: &Env, delay: u64) {
+    UpgradeDelaySet {
+        delay,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
 pub fn contract_migrated(env: &Env, from_version: u32, to_version: u32) {
     ContractMigrated {
         from_version,
@@ -0,0 +1,275 @@
+//! Transfer fees on secondary transfers of shares.
+//!
+//! An asset may carry a fee on `transfer_tokens` (and the SEP-41 transfer
+//! built on it): `bps` of the amount transferred, but never less than
+//! `min_fee`. The fee is paid either
+//!
+//! - **in shares**, withheld from the transfer: the recipient receives the
+//!   amount less the fee, or
+//! - **in a SAC token**, paid by the sender on top of the transfer. `bps` then
+//!   applies to the value of the shares at the asset's current valuation,
+//!   which is taken to be denominated in that token; an asset with no
+//!   valuation pays `min_fee`.
+//!
+//! Each fee is split between the platform treasury and the tokenizer, who
+//! receives `royalty_bps` of it as a royalty. Fees round down. A share fee is
+//! a transfer to each payee like any other: a frozen payee, or one the
+//! asset's whitelist or holder limits would refuse, fails the transfer the
+//! fee is charged on.
+//!
+//! A listing fill is charged as a transfer from the seller to the buyer, with
+//! the seller paying: a share fee is withheld from the shares the buyer
+//! receives, and a token fee comes out of the seller's proceeds, so the
+//! listing must be priced in the fee token and cover the fee.
+//!
+//! Transfers from the tokenizer are primary distribution and are never
+//! charged, nor is a transfer to or from a fee-exempt address. Offering
+//! claims are primary distribution too, and forced transfers are a
+//! compliance override; neither carries a fee.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+use crate::error::Error;
+use crate::math;
+use crate::tokenization::move_tokens;
+use crate::ttl;
+use crate::types::TokenizedAsset;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Fee rate in basis points of the amount transferred
+    pub bps: u32,
+    /// Smallest fee charged, in units of the fee asset
+    pub min_fee: i128,
+    /// SAC token the fee is paid in; `None` for shares of the asset
+    pub fee_token: Option<Address>,
+    pub treasury: Address,
+    /// Part of each fee paid to the tokenizer, in basis points of the fee
+    pub royalty_bps: u32,
+}
+
+/// What a transfer is charged.
+pub struct Charge {
+    pub fee: i128,
+    pub fee_token: Option<Address>,
+    /// Shares the recipient is still owed once a share fee is withheld.
+    pub net: i128,
+    /// (payee, part of the fee): the treasury, then the tokenizer's royalty.
+    payees: Vec<(Address, i128)>,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `FeeConfig` for asset_id. Absent means no fee.
+    Config(u64),
+    /// `bool`: (asset_id, address) neither pays nor causes a fee.
+    Exempt(u64, Address),
+}
+
+// ─── Configuration ────────────────────────────────────────────────────────────
+
+/// Sets or, with `None`, removes the fee on an asset's transfers.
+pub fn set_config(env: &Env, asset_id: u64, config: Option<FeeConfig>) -> Result<(), Error> {
+    let key = DataKey::Config(asset_id);
+    match &config {
+        Some(c) => {
+            if c.bps > 10000 || c.royalty_bps > 10000 || c.min_fee < 0 {
+                return Err(Error::InvalidInput);
+            }
+            env.storage().persistent().set(&key, c);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    crate::events::transfer_fee_set(env, asset_id, config);
+
+    Ok(())
+}
+
+pub fn get_config(env: &Env, asset_id: u64) -> Option<FeeConfig> {
+    let key = DataKey::Config(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_exempt(env: &Env, asset_id: u64, address: &Address, exempt: bool) {
+    let key = DataKey::Exempt(asset_id, address.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    crate::events::fee_exemption_set(env, asset_id, address, exempt);
+}
+
+pub fn is_exempt(env: &Env, asset_id: u64, address: &Address) -> bool {
+    let key = DataKey::Exempt(asset_id, address.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().has(&key)
+}
+
+// ─── Charging ─────────────────────────────────────────────────────────────────
+
+/// Collects the fee, if any, on a transfer of `amount` from `from` to `to`.
+/// A share fee is moved out of `from` here; the caller then delivers
+/// [`Charge::net`] to `to`.
+pub fn charge(
+    env: &Env,
+    tokenized_asset: &TokenizedAsset,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<Charge, Error> {
+    let charge = quote(env, tokenized_asset, from, to, amount)?;
+    check(env, tokenized_asset.asset_id, &charge, from, from)?;
+    collect(env, tokenized_asset, &charge, from, from)?;
+    Ok(charge)
+}
+
+/// The fee, if any, on a transfer of `amount` from `from` to `to`. Moves
+/// nothing.
+///
+/// `from` is whoever the shares belong to, and decides the tokenizer and
+/// exemption rules: for a listing fill, the seller rather than escrow.
+pub fn quote(
+    env: &Env,
+    tokenized_asset: &TokenizedAsset,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<Charge, Error> {
+    let asset_id = tokenized_asset.asset_id;
+    let no_fee = Charge {
+        fee: 0,
+        fee_token: None,
+        net: amount,
+        payees: Vec::new(env),
+    };
+    let Some(config) = get_config(env, asset_id) else {
+        return Ok(no_fee);
+    };
+    if *from == tokenized_asset.tokenizer
+        || is_exempt(env, asset_id, from)
+        || is_exempt(env, asset_id, to)
+    {
+        return Ok(no_fee);
+    }
+
+    let base = match config.fee_token {
+        None => amount,
+        Some(_) if tokenized_asset.total_supply <= 0 => 0,
+        Some(_) => math::mul_div(
+            amount,
+            tokenized_asset.valuation,
+            tokenized_asset.total_supply,
+        )?,
+    };
+    let fee = math::mul_div(base, config.bps as i128, 10000)?.max(config.min_fee);
+    if fee == 0 {
+        return Ok(Charge {
+            fee_token: config.fee_token,
+            ..no_fee
+        });
+    }
+
+    let royalty = math::mul_div(fee, config.royalty_bps as i128, 10000)?;
+    let to_treasury = math::sub(fee, royalty)?;
+    let mut payees = Vec::new(env);
+    for (payee, share) in [
+        (config.treasury, to_treasury),
+        (tokenized_asset.tokenizer.clone(), royalty),
+    ] {
+        if share > 0 {
+            payees.push_back((payee, share));
+        }
+    }
+
+    let net = match config.fee_token {
+        // The recipient must end up with something.
+        None if fee >= amount => return Err(Error::InvalidInput),
+        None => math::sub(amount, fee)?,
+        Some(_) => amount,
+    };
+
+    Ok(Charge {
+        fee,
+        fee_token: config.fee_token,
+        net,
+        payees,
+    })
+}
+
+/// Fails with the error [`collect`] would run into, moving nothing.
+///
+/// A share fee is a transfer to each payee, so each must be unfrozen and
+/// eligible to receive shares under the asset's restrictions and holder
+/// limits. A token fee needs `token_payer` to hold enough of the fee token.
+pub fn check(
+    env: &Env,
+    asset_id: u64,
+    charge: &Charge,
+    share_source: &Address,
+    token_payer: &Address,
+) -> Result<(), Error> {
+    match &charge.fee_token {
+        None => {
+            for (payee, share) in charge.payees.iter() {
+                if payee != *share_source {
+                    crate::compliance::require_not_frozen(env, asset_id, &payee)?;
+                    crate::transfer_restrictions::validate_fee(env, asset_id, &payee, share)?;
+                }
+            }
+        }
+        Some(fee_token) => {
+            let mut due = 0i128;
+            for (payee, share) in charge.payees.iter() {
+                if payee != *token_payer {
+                    due = math::add(due, share)?;
+                }
+            }
+            if token::TokenClient::new(env, fee_token).balance(token_payer) < due {
+                return Err(Error::InsufficientBalance);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Pays `charge` out: a share fee from `share_source`'s balance, a token fee
+/// from `token_payer`'s. A part owed to the source or payer itself stays
+/// where it is.
+pub fn collect(
+    env: &Env,
+    tokenized_asset: &TokenizedAsset,
+    charge: &Charge,
+    share_source: &Address,
+    token_payer: &Address,
+) -> Result<(), Error> {
+    match &charge.fee_token {
+        None => {
+            for (payee, share) in charge.payees.iter() {
+                if payee != *share_source {
+                    move_tokens(env, tokenized_asset, share_source, &payee, share)?;
+                }
+            }
+        }
+        Some(fee_token) => {
+            let client = token::TokenClient::new(env, fee_token);
+            for (payee, share) in charge.payees.iter() {
+                if payee != *token_payer {
+                    client.transfer(token_payer, &payee, &share);
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,276 @@
+//! The holder set of each tokenized asset.
+//!
+//! An address is in the set exactly while its balance is non-zero: it is added
+//! on first receipt and removed when a transfer or burn takes it to zero. The
+//! count reported as `token_holders_count` is the size of this set.
+//!
+//! The set is stored in pages of [`PAGE_SIZE`] addresses rather than one
+//! `Vec`, so no single entry grows with the number of holders and a reader can
+//! walk the set a page at a time with `get_token_holders_page`. Each holder's
+//! position is indexed, which makes membership checks and removal constant
+//! time: a leaving holder's slot is filled with the last holder in the set.
+//! Order is therefore not meaningful.
+//!
+//! Every change to the set also writes the new size into the stored
+//! `TokenizedAsset`, so `token_holders_count` is right in storage and not only
+//! through `get_tokenized_asset`.
+//!
+//! A holder who leaves with unclaimed dividends keeps their `OwnershipRecord`,
+//! at zero balance, until they claim; otherwise the record is removed with
+//! them. See `tokenization::store_ownership`.
+//!
+//! Assets tokenized before paging kept their holders in a single
+//! `TokenHoldersList` entry. That entry is converted to pages, less any
+//! zero-balance addresses, a batch at a time by the storage migration (see
+//! `upgrade`). Converting it all at once could outgrow a transaction, so until
+//! it is converted the asset's holders cannot change: [`sync`] fails with
+//! `MigrationInProgress` and the asset must be queued with `queue_migration`.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::error::Error;
+use crate::tokenization::get_token_balance;
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+/// Addresses per stored page.
+pub const PAGE_SIZE: u32 = 100;
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `Vec<Address>`: holders at positions
+    /// `page * PAGE_SIZE .. (page + 1) * PAGE_SIZE` for (asset_id, page).
+    Page(u64, u32),
+    /// `u32`: a holder's position in the set for (asset_id, holder).
+    Position(u64, Address),
+    /// `u32`: the number of holders of asset_id.
+    Count(u64),
+}
+
+// ─── Updates ──────────────────────────────────────────────────────────────────
+
+/// Brings `holder`'s membership in line with their new `balance`: added if it
+/// is positive, removed if it is zero. Call after every balance change.
+///
+/// Fails with `MigrationInProgress` while the asset still has a legacy list.
+pub fn sync(env: &Env, asset_id: u64, holder: &Address, balance: i128) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&TokenDataKey::TokenHoldersList(asset_id))
+    {
+        return Err(Error::MigrationInProgress);
+    }
+    if balance > 0 {
+        insert(env, asset_id, holder);
+    } else {
+        remove(env, asset_id, holder);
+    }
+    Ok(())
+}
+
+/// Drops the whole set, and any legacy list with it. Used by detokenization.
+pub fn clear(env: &Env, asset_id: u64) {
+    let store = env.storage().persistent();
+    store.remove(&TokenDataKey::TokenHoldersList(asset_id));
+
+    for holder in all(env, asset_id).iter() {
+        store.remove(&DataKey::Position(asset_id, holder));
+    }
+    let pages = page_count(count(env, asset_id));
+    for page in 0..pages {
+        store.remove(&DataKey::Page(asset_id, page));
+    }
+    store.remove(&DataKey::Count(asset_id));
+}
+
+fn insert(env: &Env, asset_id: u64, holder: &Address) {
+    let store = env.storage().persistent();
+    let position_key = DataKey::Position(asset_id, holder.clone());
+    if store.has(&position_key) {
+        return;
+    }
+
+    let position = stored_count(env, asset_id);
+    let page_key = DataKey::Page(asset_id, position / PAGE_SIZE);
+    let mut page: Vec<Address> = store.get(&page_key).unwrap_or_else(|| Vec::new(env));
+    page.push_back(holder.clone());
+
+    store.set(&page_key, &page);
+    store.set(&position_key, &position);
+    set_count(env, asset_id, position + 1);
+    ttl::extend_persistent(env, &page_key);
+    ttl::extend_persistent(env, &position_key);
+}
+
+fn remove(env: &Env, asset_id: u64, holder: &Address) {
+    let store = env.storage().persistent();
+    let position_key = DataKey::Position(asset_id, holder.clone());
+    let Some(position) = store.get::<_, u32>(&position_key) else {
+        return;
+    };
+    store.remove(&position_key);
+
+    // Take the last holder off the end of the set...
+    let last = stored_count(env, asset_id) - 1;
+    let last_page_key = DataKey::Page(asset_id, last / PAGE_SIZE);
+    let mut last_page: Vec<Address> = store.get(&last_page_key).unwrap_or_else(|| Vec::new(env));
+    let moved = last_page.pop_back();
+    if last_page.is_empty() {
+        store.remove(&last_page_key);
+    } else {
+        store.set(&last_page_key, &last_page);
+    }
+
+    // ...and, unless it was the one leaving, put it in the vacated slot.
+    if position != last {
+        if let Some(moved) = moved {
+            let page_key = DataKey::Page(asset_id, position / PAGE_SIZE);
+            let mut page: Vec<Address> = store.get(&page_key).unwrap_or_else(|| Vec::new(env));
+            page.set(position % PAGE_SIZE, moved.clone());
+            store.set(&page_key, &page);
+            ttl::extend_persistent(env, &page_key);
+
+            let moved_key = DataKey::Position(asset_id, moved);
+            store.set(&moved_key, &position);
+            ttl::extend_persistent(env, &moved_key);
+        }
+    }
+
+    set_count(env, asset_id, last);
+}
+
+fn set_count(env: &Env, asset_id: u64, count: u32) {
+    let key = DataKey::Count(asset_id);
+    env.storage().persistent().set(&key, &count);
+    ttl::extend_persistent(env, &key);
+
+    // While a legacy list is being converted the pages hold only part of the
+    // set; the stored count is brought up to date once the list is gone.
+    if !env
+        .storage()
+        .persistent()
+        .has(&TokenDataKey::TokenHoldersList(asset_id))
+    {
+        store_asset_count(env, asset_id, count);
+    }
+}
+
+/// Writes `count` into the stored `TokenizedAsset`, if the asset has one.
+fn store_asset_count(env: &Env, asset_id: u64, count: u32) {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let Some(mut tokenized_asset) = store.get::<_, TokenizedAsset>(&key) else {
+        return;
+    };
+    if tokenized_asset.token_holders_count != count {
+        tokenized_asset.token_holders_count = count;
+        store.set(&key, &tokenized_asset);
+    }
+}
+
+/// Converts up to `max` entries of a pre-paging `TokenHoldersList`, starting
+/// at position `from`, and removes the list once its last entry is converted.
+///
+/// Returns the number of entries processed and whether the list is done. An
+/// asset with no legacy list is done at once. Until the list is removed,
+/// reads keep filtering it, so a half-converted asset reads the same as an
+/// unconverted one.
+pub fn convert_legacy_batch(env: &Env, asset_id: u64, from: u32, max: u32) -> (u32, bool) {
+    let key = TokenDataKey::TokenHoldersList(asset_id);
+    let Some(legacy) = env.storage().persistent().get::<_, Vec<Address>>(&key) else {
+        return (0, true);
+    };
+
+    let start = from.min(legacy.len());
+    let end = start.saturating_add(max).min(legacy.len());
+    for holder in legacy.slice(start..end).iter() {
+        if get_token_balance(env, asset_id, holder.clone()).unwrap_or(0) > 0 {
+            insert(env, asset_id, &holder);
+        }
+    }
+
+    if end < legacy.len() {
+        return (end - start, false);
+    }
+    env.storage().persistent().remove(&key);
+    store_asset_count(env, asset_id, stored_count(env, asset_id));
+    (end - start, true)
+}
+
+// ─── Reads ────────────────────────────────────────────────────────────────────
+//
+// Reads never write, so an unconverted `TokenHoldersList` is filtered on the
+// fly instead.
+
+/// Number of addresses with a non-zero balance.
+pub fn count(env: &Env, asset_id: u64) -> u32 {
+    if let Some(legacy) = legacy_holders(env, asset_id) {
+        return legacy.len();
+    }
+
+    stored_count(env, asset_id)
+}
+
+/// Number of addresses in the pages, ignoring any legacy list.
+fn stored_count(env: &Env, asset_id: u64) -> u32 {
+    let key = DataKey::Count(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// One page of the set. Empty past the last page.
+pub fn page(env: &Env, asset_id: u64, page: u32) -> Vec<Address> {
+    if let Some(legacy) = legacy_holders(env, asset_id) {
+        let start = page.saturating_mul(PAGE_SIZE).min(legacy.len());
+        let end = start.saturating_add(PAGE_SIZE).min(legacy.len());
+        return legacy.slice(start..end);
+    }
+
+    let key = DataKey::Page(asset_id, page);
+    ttl::extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// The whole set, page by page.
+///
+/// For in-contract iteration (dividends, tallies, detokenization), which
+/// already scales with the holder count.
+pub fn all(env: &Env, asset_id: u64) -> Vec<Address> {
+    if let Some(legacy) = legacy_holders(env, asset_id) {
+        return legacy;
+    }
+
+    let mut holders = Vec::new(env);
+    for index in 0..page_count(count(env, asset_id)) {
+        holders.append(&page(env, asset_id, index));
+    }
+    holders
+}
+
+/// The members of an unconverted `TokenHoldersList` that still hold a
+/// balance, if the asset has one.
+fn legacy_holders(env: &Env, asset_id: u64) -> Option<Vec<Address>> {
+    let legacy: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenHoldersList(asset_id))?;
+
+    let mut holders = Vec::new(env);
+    for holder in legacy.iter() {
+        if get_token_balance(env, asset_id, holder.clone()).unwrap_or(0) > 0 {
+            holders.push_back(holder);
+        }
+    }
+    Some(holders)
+}
+
+fn page_count(count: u32) -> u32 {
+    count.div_ceil(PAGE_SIZE)
+}
@@ -0,0 +1,200 @@
+//! Holding periods: newly acquired shares that cannot be resold yet.
+//!
+//! `lock_tokens` freezes a holder's whole balance by hand. A holding period
+//! instead applies to every investor automatically: while one is set, each
+//! acquisition is recorded as a **lot** with its acquisition time, and a lot
+//! cannot move until it is `period` seconds old. Shares held from before the
+//! period was set, or received while none was set, are not in any lot and
+//! stay free.
+//!
+//! Lots are recorded for shares bought or received through `transfer_tokens`
+//! (and the SEP-41 transfer built on it), `mint_to`, offering claims and
+//! listing fills. Vesting grants are restricted by their schedule instead,
+//! and shares coming back to a seller from a cancelled listing are not a new
+//! acquisition. The tokenizer and the contract's own escrow never hold lots.
+//!
+//! A debit spends free shares before restricted ones, so an ordinary transfer
+//! never touches the lots. A debit that overrides the check, such as a
+//! compliance `force_transfer`, consumes the oldest lots first.
+//!
+//! Changing the period applies to lots already recorded. Lots are dropped once
+//! they mature, and at most [`MAX_LOTS`] are kept per holder: beyond that, a
+//! new acquisition is merged into the newest lot, which takes the later date.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::error::Error;
+use crate::math;
+use crate::ttl;
+use crate::types::TokenizedAsset;
+
+/// Most unmatured lots kept per holder.
+pub const MAX_LOTS: u32 = 50;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Shares acquired together, restricted until `acquired_at + period`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lot {
+    pub amount: i128,
+    pub acquired_at: u64,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `u64`: holding period of asset_id in seconds. Absent means none.
+    Period(u64),
+    /// `Vec<Lot>` for (asset_id, holder), oldest first.
+    Lots(u64, Address),
+}
+
+// ─── Configuration ────────────────────────────────────────────────────────────
+
+/// Sets the holding period of an asset. Zero removes it.
+pub fn set_period(env: &Env, asset_id: u64, period: u64) {
+    let key = DataKey::Period(asset_id);
+    if period == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &period);
+        ttl::extend_persistent(env, &key);
+    }
+
+    crate::events::holding_period_set(env, asset_id, period);
+}
+
+pub fn get_period(env: &Env, asset_id: u64) -> u64 {
+    let key = DataKey::Period(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+// ─── Lots ─────────────────────────────────────────────────────────────────────
+
+/// Records `amount` just acquired by `holder` as a new lot, if the asset has
+/// a holding period and `holder` is an investor.
+pub fn record(env: &Env, tokenized_asset: &TokenizedAsset, holder: &Address, amount: i128) {
+    let asset_id = tokenized_asset.asset_id;
+    let period = get_period(env, asset_id);
+    if period == 0
+        || amount <= 0
+        || *holder == tokenized_asset.tokenizer
+        || *holder == env.current_contract_address()
+    {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let mut lots = live_lots(env, asset_id, holder, period);
+    match lots.last() {
+        Some(mut newest) if newest.acquired_at == now || lots.len() >= MAX_LOTS => {
+            newest.amount = newest.amount.saturating_add(amount);
+            newest.acquired_at = now;
+            lots.set(lots.len() - 1, newest);
+        }
+        _ => lots.push_back(Lot {
+            amount,
+            acquired_at: now,
+        }),
+    }
+    store_lots(env, asset_id, holder, &lots);
+}
+
+/// Drops the oldest lots until they fit within `balance`. Called after every
+/// debit, so lots never claim more shares than the holder has.
+pub fn trim(env: &Env, asset_id: u64, holder: &Address, balance: i128) {
+    let key = DataKey::Lots(asset_id, holder.clone());
+    let Some(mut lots) = env.storage().persistent().get::<_, Vec<Lot>>(&key) else {
+        return;
+    };
+
+    let mut excess = lots
+        .iter()
+        .fold(0i128, |sum, lot| sum.saturating_add(lot.amount))
+        .saturating_sub(balance);
+    if excess <= 0 {
+        return;
+    }
+    while excess > 0 {
+        let Some(mut oldest) = lots.first() else {
+            break;
+        };
+        if oldest.amount <= excess {
+            excess -= oldest.amount;
+            lots.pop_front();
+        } else {
+            oldest.amount -= excess;
+            lots.set(0, oldest);
+            excess = 0;
+        }
+    }
+    store_lots(env, asset_id, holder, &lots);
+}
+
+/// Shares of `holder` still in their holding period, and when the oldest of
+/// them is released. `(0, None)` when nothing is restricted.
+pub fn restricted(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+) -> Result<(i128, Option<u64>), Error> {
+    let period = get_period(env, asset_id);
+    if period == 0 {
+        return Ok((0, None));
+    }
+
+    let lots = live_lots(env, asset_id, holder, period);
+    let mut total = 0i128;
+    for lot in lots.iter() {
+        total = math::add(total, lot.amount)?;
+    }
+    let next_release = lots
+        .first()
+        .map(|lot| lot.acquired_at.saturating_add(period));
+    Ok((total, next_release))
+}
+
+/// Drops `holder`'s lots. Used by detokenization.
+pub fn clear(env: &Env, asset_id: u64, holder: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Lots(asset_id, holder.clone()));
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// `holder`'s lots that have not yet matured under `period`.
+fn live_lots(env: &Env, asset_id: u64, holder: &Address, period: u64) -> Vec<Lot> {
+    let key = DataKey::Lots(asset_id, holder.clone());
+    ttl::extend_persistent(env, &key);
+    let lots: Vec<Lot> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    // Lots are in acquisition order, so the matured ones are a prefix.
+    let now = env.ledger().timestamp();
+    let mut live = lots.clone();
+    for lot in lots.iter() {
+        if lot.acquired_at.saturating_add(period) > now {
+            break;
+        }
+        live.pop_front();
+    }
+    live
+}
+
+fn store_lots(env: &Env, asset_id: u64, holder: &Address, lots: &Vec<Lot>) {
+    let key = DataKey::Lots(asset_id, holder.clone());
+    if lots.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, lots);
+        ttl::extend_persistent(env, &key);
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use crate::audit;
+use crate::ttl;
 use crate::Error;
 use soroban_sdk::{contracttype, log, Address, BytesN, Env, String, Vec};
 
@@ -117,14 +118,15 @@ pub fn create_policy(env: Env, policy: InsurancePolicy) -> Result<(), Error> {
 
     // Store the policy
     store.set(&key, &policy);
+    ttl::extend_persistent(&env, &key);
 
     // Maintain asset index: add policy to asset's policy list
-    let mut list: Vec<BytesN<32>> = store
-        .get(&DataKey::AssetPolicies(policy.asset_id.clone()))
-        .unwrap_or_else(|| Vec::new(&env));
+    let list_key = DataKey::AssetPolicies(policy.asset_id.clone());
+    let mut list: Vec<BytesN<32>> = store.get(&list_key).unwrap_or_else(|| Vec::new(&env));
 
     list.push_back(policy.policy_id.clone());
-    store.set(&DataKey::AssetPolicies(policy.asset_id.clone()), &list);
+    store.set(&list_key, &list);
+    ttl::extend_persistent(&env, &list_key);
 
     // Append audit log
     audit::append_audit_log(
@@ -146,6 +148,8 @@ pub fn cancel_policy(env: Env, policy_id: BytesN<32>, caller: Address) -> Result
 
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &key);
+
     // Only holder or insurer can cancel
     if caller != policy.holder && caller != policy.insurer {
         return Err(Error::Unauthorized);
@@ -158,6 +162,7 @@ pub fn cancel_policy(env: Env, policy_id: BytesN<32>, caller: Address) -> Result
 
     policy.status = PolicyStatus::Cancelled;
     store.set(&key, &policy);
+    ttl::extend_persistent(&env, &key);
 
     // Append audit log
     audit::append_audit_log(
@@ -179,6 +184,8 @@ pub fn suspend_policy(env: Env, policy_id: BytesN<32>, insurer: Address) -> Resu
 
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &key);
+
     // Only insurer can suspend
     if insurer != policy.insurer {
         return Err(Error::Unauthorized);
@@ -191,6 +198,7 @@ pub fn suspend_policy(env: Env, policy_id: BytesN<32>, insurer: Address) -> Resu
 
     policy.status = PolicyStatus::Suspended;
     store.set(&key, &policy);
+    ttl::extend_persistent(&env, &key);
 
     log!(&env, "PolicySuspended: {:?}", policy_id);
     Ok(())
@@ -203,6 +211,8 @@ pub fn expire_policy(env: Env, policy_id: BytesN<32>) -> Result<(), Error> {
 
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &key);
+
     let current_time = env.ledger().timestamp();
 
     // Require that end_date has passed
@@ -217,6 +227,7 @@ pub fn expire_policy(env: Env, policy_id: BytesN<32>) -> Result<(), Error> {
 
     policy.status = PolicyStatus::Expired;
     store.set(&key, &policy);
+    ttl::extend_persistent(&env, &key);
 
     log!(&env, "PolicyExpired: {:?}", policy_id);
     Ok(())
@@ -235,6 +246,8 @@ pub fn renew_policy(
 
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &key);
+
     // Only insurer can renew
     if insurer != policy.insurer {
         return Err(Error::Unauthorized);
@@ -265,6 +278,8 @@ pub fn renew_policy(
 
     store.set(&key, &policy);
 
+    ttl::extend_persistent(&env, &key);
+
     // Append audit log
     audit::append_audit_log(
         &env,
@@ -280,9 +295,11 @@ pub fn renew_policy(
 
 /// Get all policies for a specific asset
 pub fn get_asset_policies(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
+    let key = DataKey::AssetPolicies(asset_id);
+    ttl::extend_persistent(&env, &key);
     env.storage()
         .persistent()
-        .get(&DataKey::AssetPolicies(asset_id))
+        .get(&key)
         .unwrap_or_else(|| Vec::new(&env))
 }
 
@@ -296,6 +313,7 @@ pub fn file_insurance_claim(env: Env, claim: InsuranceClaim) -> Result<(), Error
 
     // Verify policy exists and is Active
     let policy: InsurancePolicy = store.get(&policy_key).ok_or(Error::AssetNotFound)?;
+    ttl::extend_persistent(&env, &policy_key);
     if policy.status != PolicyStatus::Active {
         return Err(Error::Unauthorized);
     }
@@ -318,13 +336,15 @@ pub fn file_insurance_claim(env: Env, claim: InsuranceClaim) -> Result<(), Error
 
     // Store the claim
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     // Index claim by asset_id
-    let mut asset_claims: Vec<BytesN<32>> = store
-        .get(&DataKey::AssetClaims(claim.asset_id.clone()))
-        .unwrap_or_else(|| Vec::new(&env));
+    let claims_key = DataKey::AssetClaims(claim.asset_id.clone());
+    let mut asset_claims: Vec<BytesN<32>> =
+        store.get(&claims_key).unwrap_or_else(|| Vec::new(&env));
     asset_claims.push_back(claim.claim_id.clone());
-    store.set(&DataKey::AssetClaims(claim.asset_id.clone()), &asset_claims);
+    store.set(&claims_key, &asset_claims);
+    ttl::extend_persistent(&env, &claims_key);
 
     log!(&env, "ClaimFiled: {:?}", claim.claim_id);
     Ok(())
@@ -343,6 +363,8 @@ pub fn mark_insurance_claim_under_review(
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &claim_key);
+
     // Verify insurer is authorized
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
@@ -358,6 +380,7 @@ pub fn mark_insurance_claim_under_review(
 
     claim.status = ClaimStatus::UnderReview;
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     log!(&env, "ClaimUnderReview: {:?}", claim_id);
     Ok(())
@@ -377,6 +400,8 @@ pub fn approve_insurance_claim(
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &claim_key);
+
     // Verify insurer is authorized
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
@@ -403,6 +428,7 @@ pub fn approve_insurance_claim(
     claim.status = ClaimStatus::Approved;
     claim.approved_amount = approved_amount;
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     log!(&env, "ClaimApproved: {:?}", claim_id);
     Ok(())
@@ -421,6 +447,8 @@ pub fn reject_insurance_claim(
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &claim_key);
+
     // Verify insurer is authorized
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
@@ -436,6 +464,7 @@ pub fn reject_insurance_claim(
 
     claim.status = ClaimStatus::Rejected;
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     log!(&env, "ClaimRejected: {:?}", claim_id);
     Ok(())
@@ -454,6 +483,8 @@ pub fn dispute_insurance_claim(
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &claim_key);
+
     // Verify claimant is authorized
     if claimant != claim.claimant {
         return Err(Error::Unauthorized);
@@ -466,6 +497,7 @@ pub fn dispute_insurance_claim(
 
     claim.status = ClaimStatus::Disputed;
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     log!(&env, "ClaimDisputed: {:?}", claim_id);
     Ok(())
@@ -480,6 +512,8 @@ pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) ->
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
+    ttl::extend_persistent(&env, &claim_key);
+
     // Verify insurer is authorized
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
@@ -495,6 +529,7 @@ pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) ->
 
     claim.status = ClaimStatus::Paid;
     store.set(&claim_key, &claim);
+    ttl::extend_persistent(&env, &claim_key);
 
     log!(&env, "ClaimPaid: {:?}", claim_id);
     Ok(())
@@ -502,17 +537,23 @@ pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) ->
 
 /// Get a specific insurance claim by ID
 pub fn get_insurance_claim(env: Env, claim_id: BytesN<32>) -> Option<InsuranceClaim> {
-    env.storage().persistent().get(&DataKey::Claim(claim_id))
+    let key = DataKey::Claim(claim_id);
+    ttl::extend_persistent(&env, &key);
+    env.storage().persistent().get(&key)
 }
 
 /// Get all claims for a specific asset
 pub fn get_asset_insurance_claims(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
+    let key = DataKey::AssetClaims(asset_id);
+    ttl::extend_persistent(&env, &key);
     env.storage()
         .persistent()
-        .get(&DataKey::AssetClaims(asset_id))
+        .get(&key)
         .unwrap_or_else(|| Vec::new(&env))
 }
 
 pub fn get_policy(env: Env, policy_id: BytesN<32>) -> Option<InsurancePolicy> {
-    env.storage().persistent().get(&DataKey::Policy(policy_id))
+    let key = DataKey::Policy(policy_id);
+    ttl::extend_persistent(&env, &key);
+    env.storage().persistent().get(&key)
 }
@@ -1,6 +1,7 @@
 use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
 use crate::error::Error;
+use crate::ttl;
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
@@ -30,6 +31,7 @@ pub struct Lease {
 // ─── Storage Keys ─────────────────────────────────────────────────────────────
 
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Lease(BytesN<32>),
     AssetActiveLease(BytesN<32>),
@@ -39,22 +41,24 @@ pub enum DataKey {
 // ─── Internal helpers ─────────────────────────────────────────────────────────
 
 fn load_lease(env: &Env, lease_id: &BytesN<32>) -> Result<Lease, Error> {
+    let key = DataKey::Lease(lease_id.clone());
+    ttl::extend_persistent(env, &key);
     env.storage()
         .persistent()
-        .get(&DataKey::Lease(lease_id.clone()))
+        .get(&key)
         .ok_or(Error::LeaseNotFound)
 }
 
 fn save_lease(env: &Env, lease: &Lease) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::Lease(lease.lease_id.clone()), lease);
+    let key = DataKey::Lease(lease.lease_id.clone());
+    env.storage().persistent().set(&key, lease);
+    ttl::extend_persistent(env, &key);
 }
 
 fn set_asset_active_lease(env: &Env, asset_id: &BytesN<32>, lease_id: &BytesN<32>) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::AssetActiveLease(asset_id.clone()), lease_id);
+    let key = DataKey::AssetActiveLease(asset_id.clone());
+    env.storage().persistent().set(&key, lease_id);
+    ttl::extend_persistent(env, &key);
 }
 
 fn clear_asset_active_lease(env: &Env, asset_id: &BytesN<32>) {
@@ -64,9 +68,9 @@ fn clear_asset_active_lease(env: &Env, asset_id: &BytesN<32>) {
 }
 
 fn get_active_lease_id(env: &Env, asset_id: &BytesN<32>) -> Option<BytesN<32>> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::AssetActiveLease(asset_id.clone()))
+    let key = DataKey::AssetActiveLease(asset_id.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
 }
 
 fn append_lessee_lease(env: &Env, lessee: &Address, lease_id: &BytesN<32>) {
@@ -78,6 +82,7 @@ fn append_lessee_lease(env: &Env, lessee: &Address, lease_id: &BytesN<32>) {
         .unwrap_or_else(|| Vec::new(env));
     ids.push_back(lease_id.clone());
     env.storage().persistent().set(&key, &ids);
+    ttl::extend_persistent(env, &key);
 }
 
 // ─── Public functions (called from lib.rs) ────────────────────────────────────
@@ -207,8 +212,10 @@ pub fn get_asset_active_lease(env: &Env, asset_id: BytesN<32>) -> Option<Lease>
 }
 
 pub fn get_lessee_leases(env: &Env, lessee: Address) -> Vec<BytesN<32>> {
+    let key = DataKey::LesseeLeases(lessee);
+    ttl::extend_persistent(env, &key);
     env.storage()
         .persistent()
-        .get(&DataKey::LesseeLeases(lessee))
+        .get(&key)
         .unwrap_or_else(|| Vec::new(env))
 }
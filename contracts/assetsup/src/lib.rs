@@ -34,25 +34,37 @@
 extern crate std;
 
 use crate::error::{handle_error, Error};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Val, Vec};
 
 // `Asset` is part of the contract's public ABI (it is a `register_asset`
 // argument), so the module is public for cross-contract integration tests.
 pub mod asset;
 pub(crate) mod audit;
 pub(crate) mod branch;
+pub(crate) mod compliance;
 pub(crate) mod detokenization;
 pub(crate) mod dividends;
 pub(crate) mod error;
 pub(crate) mod events;
+pub(crate) mod fees;
+pub(crate) mod holders;
+pub(crate) mod holding;
 pub(crate) mod insurance;
 pub(crate) mod lease;
+pub(crate) mod market;
 pub(crate) mod math;
+pub(crate) mod metadata;
+pub(crate) mod offering;
+pub(crate) mod pause;
+pub(crate) mod token_contract;
 pub(crate) mod tokenization;
+pub(crate) mod tokenizer;
 pub(crate) mod transfer_restrictions;
 pub(crate) mod ttl;
 pub(crate) mod types;
 pub mod upgrade;
+pub(crate) mod valuation;
+pub(crate) mod vesting;
 pub(crate) mod voting;
 
 #[cfg(test)]
@@ -75,6 +87,22 @@ pub enum DataKey {
     PendingAdmin,
     ScheduledTransfer(BytesN<32>),
     PendingApproval(BytesN<32>),
+    /// WASM hash of the `asset-token` contract deployed for each tokenized
+    /// asset. Absent until the admin sets it. See `token_contract`.
+    TokenWasmHash,
+    /// `u64` seconds between scheduling and installing an upgrade. Absent
+    /// means `upgrade::DEFAULT_UPGRADE_DELAY`.
+    UpgradeDelay,
+    /// `upgrade::PendingUpgrade` waiting out its delay, if any.
+    PendingUpgrade,
+    /// WASM hash installed by the last upgrade.
+    CurrentWasmHash,
+    /// WASM hash the last upgrade replaced: the rollback target.
+    PreviousWasmHash,
+    /// `Vec<u64>` of keys the migration step in progress has yet to visit.
+    MigrationQueue,
+    /// `u32` position reached within the first queued key.
+    MigrationCursor,
 }
 
 #[contract]
@@ -152,13 +180,16 @@ impl AssetUpContract {
         Ok(admin)
     }
 
+    /// Whether the whole contract is paused, by `pause_contract` or by an
+    /// unexpired contract-wide `pause`.
     pub fn is_paused(env: Env) -> Result<bool, Error> {
         ttl::extend_persistent(&env, &DataKey::Paused);
         Ok(env
             .storage()
             .persistent()
             .get(&DataKey::Paused)
-            .unwrap_or(false))
+            .unwrap_or(false)
+            || pause::get(&env, &pause::PauseScope::Contract).is_some())
     }
 
     pub fn get_total_asset_count(env: Env) -> Result<u64, Error> {
@@ -195,10 +226,7 @@ impl AssetUpContract {
         // pass it.
         caller.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         // Check if caller is authorized registrar
         if !Self::is_authorized_registrar(env.clone(), caller.clone())? {
@@ -226,6 +254,7 @@ impl AssetUpContract {
             store.get(&owner_key).unwrap_or_else(|| Vec::new(&env));
         owner_assets.push_back(asset.id.clone());
         store.set(&owner_key, &owner_assets);
+        ttl::extend_persistent(&env, &owner_key);
 
         // Update total asset count
         let mut total_count = Self::get_total_asset_count(env.clone())?;
@@ -233,6 +262,7 @@ impl AssetUpContract {
         env.storage()
             .persistent()
             .set(&DataKey::TotalAssetCount, &total_count);
+        ttl::extend_persistent(&env, &DataKey::TotalAssetCount);
 
         // Append audit log
         audit::append_audit_log(
@@ -249,16 +279,35 @@ impl AssetUpContract {
         Ok(())
     }
 
-    /// Rejects the call if the contract is paused.
+    /// Rejects the call if the contract, `module`, or the tokenized asset the
+    /// call acts on is paused.
     ///
     /// Every mutating entrypoint calls this except the deliberate exemptions
     /// documented in `contracts/PAUSE.md`: the pause controls themselves, the
     /// admin transfer flow, and `claim_dividends`.
-    fn require_not_paused(env: &Env) -> Result<(), Error> {
+    fn require_not_paused(
+        env: &Env,
+        module: pause::Module,
+        asset_id: Option<u64>,
+    ) -> Result<(), Error> {
+        upgrade::require_current(env)?;
         if Self::is_paused(env.clone())? {
             return Err(Error::ContractPaused);
         }
-        Ok(())
+        pause::require_active(env, module, asset_id)
+    }
+
+    /// Applies one token metadata field change with the tokenizer's
+    /// authorization. Shared by the `update_token_*` entrypoints.
+    fn update_metadata_field(
+        env: &Env,
+        asset_id: u64,
+        update: metadata::MetadataUpdate,
+    ) -> Result<u32, Error> {
+        let tokenized_asset = tokenization::get_tokenized_asset(env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        metadata::apply(env, asset_id, update, tokenized_asset.tokenizer)
     }
 
     fn validate_asset(env: &Env, asset: &asset::Asset) -> Result<(), Error> {
@@ -311,10 +360,7 @@ impl AssetUpContract {
         // otherwise be satisfied by simply naming the owner's address.
         caller.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         let key = asset::DataKey::Asset(asset_id.clone());
         let store = env.storage().persistent();
@@ -377,10 +423,7 @@ impl AssetUpContract {
         // a direct asset-theft path.
         caller.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         // Validate new owner is not zero address
         let zero_address = Address::from_str(
@@ -391,43 +434,18 @@ impl AssetUpContract {
             return Err(Error::InvalidOwnerAddress);
         }
 
-        let key = asset::DataKey::Asset(asset_id.clone());
-        let store = env.storage().persistent();
-
-        let mut asset = match store.get::<_, asset::Asset>(&key) {
-            Some(a) => a,
-            None => return Err(Error::AssetNotFound),
-        };
+        let asset: asset::Asset = env
+            .storage()
+            .persistent()
+            .get(&asset::DataKey::Asset(asset_id.clone()))
+            .ok_or(Error::AssetNotFound)?;
 
         // Only current asset owner can transfer ownership
         if caller != asset.owner {
             return Err(Error::Unauthorized);
         }
 
-        let old_owner = asset.owner.clone();
-
-        // Remove asset from old owner's registry
-        let old_owner_key = asset::DataKey::OwnerRegistry(old_owner.clone());
-        let mut old_owner_assets: Vec<BytesN<32>> =
-            store.get(&old_owner_key).unwrap_or_else(|| Vec::new(&env));
-        if let Some(index) = old_owner_assets.iter().position(|x| x == asset_id) {
-            old_owner_assets.remove(index as u32);
-        }
-        store.set(&old_owner_key, &old_owner_assets);
-
-        // Add asset to new owner's registry
-        let new_owner_key = asset::DataKey::OwnerRegistry(new_owner.clone());
-        let mut new_owner_assets: Vec<BytesN<32>> =
-            store.get(&new_owner_key).unwrap_or_else(|| Vec::new(&env));
-        new_owner_assets.push_back(asset_id.clone());
-        store.set(&new_owner_key, &new_owner_assets);
-
-        // Update asset
-        asset.owner = new_owner.clone();
-        asset.last_transfer_timestamp = env.ledger().timestamp();
-        asset.status = AssetStatus::Transferred;
-        store.set(&key, &asset);
-        ttl::extend_persistent(&env, &key);
+        let old_owner = asset::reassign_owner(&env, &asset_id, &new_owner)?;
 
         // Append audit log
         audit::append_audit_log(
@@ -441,6 +459,9 @@ impl AssetUpContract {
         // Emit event
         events::asset_transferred(&env, &asset_id, &old_owner, &new_owner);
 
+        // The share supply's controller follows the asset it represents.
+        tokenizer::follow_registry_owner(&env, &asset_id, &new_owner);
+
         Ok(())
     }
 
@@ -450,10 +471,7 @@ impl AssetUpContract {
         // Authenticate before the owner/admin comparison below.
         caller.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         let key = asset::DataKey::Asset(asset_id.clone());
         let store = env.storage().persistent();
@@ -507,6 +525,7 @@ impl AssetUpContract {
 
     pub fn get_assets_by_owner(env: Env, owner: Address) -> Result<Vec<BytesN<32>>, Error> {
         let key = asset::DataKey::OwnerRegistry(owner);
+        ttl::extend_persistent(&env, &key);
         let store = env.storage().persistent();
         match store.get(&key) {
             Some(assets) => Ok(assets),
@@ -518,10 +537,36 @@ impl AssetUpContract {
         ttl::extend_instance(&env);
 
         let key = asset::DataKey::Asset(asset_id);
+        ttl::extend_persistent(&env, &key);
         let store = env.storage().persistent();
         Ok(store.has(&key))
     }
 
+    /// Extends every stored entry of registry asset `asset_id` to the full
+    /// TTL window. Permissionless: the caller pays the rent. Returns the
+    /// number of entries extended.
+    pub fn extend_asset_ttl(env: Env, asset_id: BytesN<32>) -> u32 {
+        ttl::renew_all(&env, &ttl::asset_keys(&env, &asset_id))
+    }
+
+    /// Extends every asset-wide entry of tokenized asset `asset_id`, and the
+    /// per-holder entries of the holders on `page`, to the full TTL window.
+    /// Permissionless. Returns the number of entries extended.
+    pub fn extend_token_ttl(env: Env, asset_id: u64, page: u32) -> u32 {
+        ttl::renew_all(&env, &ttl::token_keys(&env, asset_id, page))
+    }
+
+    /// Get the storage keys of registry asset `asset_id`'s entries, for
+    /// tooling to look up their TTLs. See `ttl`.
+    pub fn get_asset_ttl_keys(env: Env, asset_id: BytesN<32>) -> Vec<Val> {
+        ttl::asset_keys(&env, &asset_id)
+    }
+
+    /// Get the storage keys `extend_token_ttl` would extend for `page`.
+    pub fn get_token_ttl_keys(env: Env, asset_id: u64, page: u32) -> Vec<Val> {
+        ttl::token_keys(&env, asset_id, page)
+    }
+
     pub fn get_asset_info(env: Env, asset_id: BytesN<32>) -> Result<asset::AssetInfo, Error> {
         let asset = Self::get_asset(env.clone(), asset_id.clone())?;
         Ok(asset::AssetInfo {
@@ -579,6 +624,7 @@ impl AssetUpContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingAdmin, &new_admin);
+        ttl::extend_persistent(&env, &DataKey::PendingAdmin);
 
         events::admin_proposed(&env, &current_admin, &new_admin);
 
@@ -602,14 +648,15 @@ impl AssetUpContract {
 
         env.storage().persistent().set(&DataKey::Admin, &pending);
         env.storage().persistent().remove(&DataKey::PendingAdmin);
+        ttl::extend_persistent(&env, &DataKey::Admin);
 
         // Move registrar rights along with the role.
         env.storage()
             .persistent()
             .set(&DataKey::AuthorizedRegistrar(old_admin.clone()), &false);
-        env.storage()
-            .persistent()
-            .set(&DataKey::AuthorizedRegistrar(pending.clone()), &true);
+        let registrar_key = DataKey::AuthorizedRegistrar(pending.clone());
+        env.storage().persistent().set(&registrar_key, &true);
+        ttl::extend_persistent(&env, &registrar_key);
 
         events::admin_changed(&env, &old_admin, &pending);
 
@@ -636,22 +683,46 @@ impl AssetUpContract {
 
     /// The address currently nominated to become admin, if any.
     pub fn get_pending_admin(env: Env) -> Option<Address> {
+        ttl::extend_persistent(&env, &DataKey::PendingAdmin);
         env.storage().persistent().get(&DataKey::PendingAdmin)
     }
 
-    /// Replaces this contract's WASM in place, keeping the contract id and all
-    /// storage.
+    /// Schedules a replacement of this contract's WASM, installable with
+    /// [`Self::execute_upgrade`] once the upgrade delay has passed (admin
+    /// only). Returns the pending upgrade.
     ///
-    /// Admin-gated and emits an event. Deliberately **not** blocked by the
-    /// pause: an upgrade is how you fix the incident that caused the pause.
+    /// Deliberately **not** blocked by the pause, nor are the other upgrade
+    /// entrypoints: an upgrade is how you fix the incident that caused the
+    /// pause.
+    pub fn schedule_upgrade(
+        env: Env,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<upgrade::PendingUpgrade, Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        upgrade::schedule(&env, new_wasm_hash)
+    }
+
+    /// Withdraws the pending upgrade (admin only).
+    pub fn cancel_upgrade(env: Env) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        upgrade::cancel(&env)
+    }
+
+    /// Installs the pending upgrade once its delay has passed, keeping the
+    /// contract id and all storage (admin only).
     ///
     /// This does not migrate storage. If the new build changes a stored
     /// layout, call [`Self::migrate`] immediately afterwards — see
     /// `contracts/UPGRADE.md`.
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+    pub fn execute_upgrade(env: Env) -> Result<(), Error> {
         let admin = Self::get_admin(env.clone())?;
         admin.require_auth();
 
+        let new_wasm_hash = upgrade::take_due(&env)?;
         env.deployer()
             .update_current_contract_wasm(new_wasm_hash.clone());
 
@@ -659,7 +730,33 @@ impl AssetUpContract {
         Ok(())
     }
 
-    /// Brings stored data up to the layout this build expects.
+    /// Sets the delay applied to upgrades scheduled from now on; at least
+    /// `upgrade::MIN_UPGRADE_DELAY` (admin only).
+    pub fn set_upgrade_delay(env: Env, delay: u64) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        upgrade::set_upgrade_delay(&env, delay)
+    }
+
+    /// Get the seconds between scheduling and installing an upgrade
+    pub fn get_upgrade_delay(env: Env) -> u64 {
+        upgrade::upgrade_delay(&env)
+    }
+
+    /// Get the upgrade waiting out its delay, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<upgrade::PendingUpgrade> {
+        upgrade::pending_upgrade(&env)
+    }
+
+    /// Get the WASM hash the last upgrade replaced, to schedule a rollback
+    pub fn get_previous_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        upgrade::previous_wasm_hash(&env)
+    }
+
+    /// Brings stored data up to the layout this build expects, one bounded
+    /// batch per call. Returns the version reached; call again until it is
+    /// `upgrade::CURRENT_VERSION`.
     ///
     /// Idempotent: running it when already current is a no-op, so a retried or
     /// duplicated migration transaction cannot corrupt state.
@@ -682,22 +779,38 @@ impl AssetUpContract {
         upgrade::stored_version(&env)
     }
 
+    /// Queues tokenized asset ids for the pending migration step to visit, or
+    /// an asset whose legacy holder list the finished v1 to v2 step missed.
+    /// See `upgrade` for why the contract cannot find them itself.
+    pub fn queue_migration(env: Env, asset_ids: Vec<u64>) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        upgrade::queue(&env, asset_ids);
+        Ok(())
+    }
+
+    /// Get how far the migration to this build's layout has got
+    pub fn get_migration_progress(env: Env) -> upgrade::MigrationProgress {
+        upgrade::progress(&env)
+    }
+
     pub fn add_authorized_registrar(env: Env, registrar: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         let admin = Self::get_admin(env.clone())?;
         admin.require_auth();
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::AuthorizedRegistrar(registrar.clone()), &true);
+        let key = DataKey::AuthorizedRegistrar(registrar.clone());
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(&env, &key);
 
         events::registrar_added(&env, &registrar);
         Ok(())
     }
 
     pub fn remove_authorized_registrar(env: Env, registrar: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Registry, None)?;
 
         let admin = Self::get_admin(env.clone())?;
         admin.require_auth();
@@ -720,6 +833,7 @@ impl AssetUpContract {
         admin.require_auth();
 
         env.storage().persistent().set(&DataKey::Paused, &true);
+        ttl::extend_persistent(&env, &DataKey::Paused);
 
         // Emit event
         events::contract_paused(&env, &admin);
@@ -732,6 +846,9 @@ impl AssetUpContract {
         admin.require_auth();
 
         env.storage().persistent().set(&DataKey::Paused, &false);
+        env.storage()
+            .persistent()
+            .remove(&pause::DataKey::Scope(pause::PauseScope::Contract));
 
         // Emit event
         events::contract_unpaused(&env, &admin);
@@ -739,6 +856,63 @@ impl AssetUpContract {
         Ok(())
     }
 
+    /// Pause the contract, one module or one tokenized asset, optionally
+    /// until `expires_at` (admin or a pauser). A pauser cannot shorten a
+    /// pause already in force.
+    pub fn pause(
+        env: Env,
+        caller: Address,
+        scope: pause::PauseScope,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let by_admin = caller == Self::get_admin(env.clone())?;
+        if !by_admin && !pause::is_pauser(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        pause::pause(&env, scope, &caller, by_admin, expires_at)
+    }
+
+    /// Lift a pause set with `pause` (admin only)
+    pub fn unpause(env: Env, scope: pause::PauseScope) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        pause::unpause(&env, scope, &admin);
+        Ok(())
+    }
+
+    /// Get the pause in force on a scope, if any. An expired pause is not
+    /// returned.
+    pub fn get_pause(env: Env, scope: pause::PauseScope) -> Option<pause::PauseRecord> {
+        pause::get(&env, &scope)
+    }
+
+    /// Allow an address to pause, but not unpause (admin only)
+    pub fn add_pauser(env: Env, pauser: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        pause::add_pauser(&env, &pauser);
+        Ok(())
+    }
+
+    /// Revoke an address's right to pause (admin only)
+    pub fn remove_pauser(env: Env, pauser: Address) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        pause::remove_pauser(&env, &pauser);
+        Ok(())
+    }
+
+    /// Check if an address may pause
+    pub fn is_pauser(env: Env, address: Address) -> bool {
+        pause::is_pauser(&env, &address)
+    }
+
     pub fn get_asset_audit_logs(
         env: Env,
         asset_id: BytesN<32>,
@@ -750,7 +924,9 @@ impl AssetUpContract {
     // Tokenization Functions
     // =====================
 
-    /// Tokenize an asset with full supply to tokenizer
+    /// Tokenize an asset with full supply to tokenizer. `asset_id` must first
+    /// be linked with `link_registry_asset` to a registry asset the tokenizer
+    /// owns
     pub fn tokenize_asset(
         env: Env,
         asset_id: u64,
@@ -762,10 +938,12 @@ impl AssetUpContract {
         name: String,
         description: String,
         asset_type: AssetType,
+        max_supply: Option<i128>,
     ) -> Result<TokenizedAsset, Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         tokenizer.require_auth();
+        tokenizer::require_registry_owner(&env, asset_id, &tokenizer)?;
 
         let metadata = TokenMetadata {
             name,
@@ -778,7 +956,7 @@ impl AssetUpContract {
             geographic_restrictions: Vec::new(&env),
         };
 
-        tokenization::tokenize_asset(
+        let tokenized_asset = tokenization::tokenize_asset(
             &env,
             asset_id,
             symbol,
@@ -787,22 +965,248 @@ impl AssetUpContract {
             min_voting_threshold,
             tokenizer,
             metadata,
-        )
+            max_supply,
+        )?;
+
+        token_contract::deploy_if_configured(&env, asset_id);
+
+        Ok(tokenized_asset)
     }
 
-    /// Mint additional tokens (only tokenizer can call)
+    /// Mint additional tokens to the tokenizer (only tokenizer can call)
     pub fn mint_tokens(
         env: Env,
         asset_id: u64,
         amount: i128,
         minter: Address,
     ) -> Result<TokenizedAsset, Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         minter.require_auth();
         tokenization::mint_tokens(&env, asset_id, amount, minter)
     }
 
+    /// Mint new tokens directly to a recipient, subject to transfer
+    /// restrictions (only the asset tokenizer can call this)
+    pub fn mint_to(
+        env: Env,
+        asset_id: u64,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<TokenizedAsset, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        tokenization::mint_to(&env, asset_id, recipient, amount)
+    }
+
+    /// Get the cap on an asset's total supply, if it has one
+    pub fn get_max_supply(env: Env, asset_id: u64) -> Option<i128> {
+        tokenization::get_max_supply(&env, asset_id)
+    }
+
+    /// Rename the token (only the asset tokenizer can call this). Returns the new metadata revision.
+    pub fn update_token_name(env: Env, asset_id: u64, name: String) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(&env, asset_id, metadata::MetadataUpdate::Name(name))
+    }
+
+    /// Replace the token description (only the asset tokenizer can call this). Returns the new metadata revision.
+    pub fn update_token_description(
+        env: Env,
+        asset_id: u64,
+        description: String,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::Description(description),
+        )
+    }
+
+    /// Correct the asset type (only the asset tokenizer can call this). Returns the new metadata revision.
+    pub fn update_token_asset_type(
+        env: Env,
+        asset_id: u64,
+        asset_type: AssetType,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::AssetType(asset_type),
+        )
+    }
+
+    /// Set or clear the IPFS URI of extended metadata (only the asset tokenizer
+    /// can call this). Returns the new metadata revision.
+    pub fn update_token_ipfs_uri(
+        env: Env,
+        asset_id: u64,
+        ipfs_uri: Option<String>,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(&env, asset_id, metadata::MetadataUpdate::IpfsUri(ipfs_uri))
+    }
+
+    /// Set or clear the hash of the legal documentation (only the asset
+    /// tokenizer can call this). Returns the new metadata revision.
+    pub fn update_legal_docs_hash(
+        env: Env,
+        asset_id: u64,
+        legal_docs_hash: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::LegalDocsHash(legal_docs_hash),
+        )
+    }
+
+    /// Set or clear the hash of the valuation report (only the asset tokenizer
+    /// can call this). Returns the new metadata revision.
+    pub fn update_valuation_report_hash(
+        env: Env,
+        asset_id: u64,
+        valuation_report_hash: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::ValuationReportHash(valuation_report_hash),
+        )
+    }
+
+    /// Set whether investors must be accredited (only the asset tokenizer can
+    /// call this). Returns the new metadata revision.
+    pub fn update_accredited_required(
+        env: Env,
+        asset_id: u64,
+        required: bool,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::AccreditedInvestorRequired(required),
+        )
+    }
+
+    /// Replace the list of restricted ISO country codes (only the asset
+    /// tokenizer can call this). Returns the new metadata revision.
+    pub fn update_geographic_restrictions(
+        env: Env,
+        asset_id: u64,
+        restrictions: Vec<String>,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        Self::update_metadata_field(
+            &env,
+            asset_id,
+            metadata::MetadataUpdate::GeographicRestrictions(restrictions),
+        )
+    }
+
+    /// Get an asset's current token metadata
+    pub fn get_token_metadata(env: Env, asset_id: u64) -> Result<TokenMetadata, Error> {
+        tokenization::get_token_metadata(&env, asset_id)
+    }
+
+    /// Get the token metadata that was in force at a past timestamp
+    pub fn get_token_metadata_at(
+        env: Env,
+        asset_id: u64,
+        timestamp: u64,
+    ) -> Result<TokenMetadata, Error> {
+        metadata::get_metadata_at(&env, asset_id, timestamp)
+    }
+
+    /// Get one recorded revision of an asset's token metadata
+    pub fn get_metadata_revision(
+        env: Env,
+        asset_id: u64,
+        revision: u32,
+    ) -> Option<metadata::MetadataRevision> {
+        metadata::get_revision(&env, asset_id, revision)
+    }
+
+    /// Get the number of recorded token metadata revisions
+    pub fn get_metadata_revision_count(env: Env, asset_id: u64) -> u32 {
+        metadata::revision_count(&env, asset_id)
+    }
+
+    /// Step one of a two-step tokenizer handover: nominate a successor (only
+    /// the asset tokenizer can call this)
+    pub fn propose_tokenizer(env: Env, asset_id: u64, new_tokenizer: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        tokenizer::propose(&env, asset_id, new_tokenizer, tokenized_asset.tokenizer)
+    }
+
+    /// Step two: the nominee accepts the tokenizer role, and with it
+    /// ownership of the linked registry asset, if any
+    pub fn accept_tokenizer(env: Env, asset_id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let pending = tokenizer::get_pending(&env, asset_id).ok_or(Error::NotFound)?;
+        pending.require_auth();
+
+        tokenizer::accept(&env, asset_id, pending)
+    }
+
+    /// Withdraw a pending tokenizer nomination (only the asset tokenizer can
+    /// call this)
+    pub fn cancel_tokenizer_proposal(env: Env, asset_id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        tokenizer::cancel(&env, asset_id, tokenized_asset.tokenizer)
+    }
+
+    /// Bind a tokenized asset id to its registry asset, so that the tokenizer
+    /// role and registry ownership always move together. Required before
+    /// `tokenize_asset`; an asset tokenized before links were required can be
+    /// linked by its tokenizer (caller must own the registry asset)
+    pub fn link_registry_asset(
+        env: Env,
+        asset_id: u64,
+        registry_asset_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        caller.require_auth();
+        tokenizer::link_registry_asset(&env, asset_id, registry_asset_id, caller)
+    }
+
+    /// Get the address nominated as an asset's next tokenizer, if any
+    pub fn get_pending_tokenizer(env: Env, asset_id: u64) -> Option<Address> {
+        tokenizer::get_pending(&env, asset_id)
+    }
+
+    /// Get the registry asset linked to a tokenized asset, if any
+    pub fn get_linked_registry_asset(env: Env, asset_id: u64) -> Option<BytesN<32>> {
+        tokenizer::get_registry_asset(&env, asset_id)
+    }
+
     /// Burn tokens (only tokenizer can call)
     pub fn burn_tokens(
         env: Env,
@@ -810,7 +1214,7 @@ impl AssetUpContract {
         amount: i128,
         burner: Address,
     ) -> Result<TokenizedAsset, Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         burner.require_auth();
         tokenization::burn_tokens(&env, asset_id, amount, burner)
@@ -824,14 +1228,15 @@ impl AssetUpContract {
         to: Address,
         amount: i128,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         from.require_auth();
 
         // Validate transfer restrictions
-        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone())?;
+        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone(), amount)?;
 
-        tokenization::transfer_tokens(&env, asset_id, from, to, amount)
+        tokenization::transfer_tokens(&env, asset_id, from, to, amount)?;
+        Ok(())
     }
 
     /// Get token balance for an address
@@ -844,6 +1249,19 @@ impl AssetUpContract {
         tokenization::get_token_holders(&env, asset_id)
     }
 
+    /// Get one page of an asset's token holders.
+    ///
+    /// Pages hold up to 100 addresses, in no particular order; a page past the
+    /// end is empty. `get_tokenized_asset(..).token_holders_count` gives the
+    /// total.
+    pub fn get_token_holders_page(
+        env: Env,
+        asset_id: u64,
+        page: u32,
+    ) -> Result<Vec<Address>, Error> {
+        tokenization::get_token_holders_page(&env, asset_id, page)
+    }
+
     /// Lock tokens until timestamp (only the asset tokenizer can call this)
     pub fn lock_tokens(
         env: Env,
@@ -852,43 +1270,515 @@ impl AssetUpContract {
         until_timestamp: u64,
         caller: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         caller.require_auth();
         tokenization::lock_tokens(&env, asset_id, holder, until_timestamp, caller)
     }
 
-    /// Unlock tokens
+    /// Unlock tokens (only the asset tokenizer can call this)
     pub fn unlock_tokens(env: Env, asset_id: u64, holder: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
 
         tokenization::unlock_tokens(&env, asset_id, holder)
     }
 
-    /// Check if a holder's tokens are currently locked
-    pub fn is_tokens_locked(env: Env, asset_id: u64, holder: Address) -> bool {
-        tokenization::is_tokens_locked(&env, asset_id, holder)
+    /// Grant tokens from the tokenizer's balance under a vesting schedule
+    /// (only the asset tokenizer can call this). Returns the grant id.
+    pub fn create_vesting_grant(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        amount: i128,
+        start: u64,
+        cliff: u64,
+        end: u64,
+        release: vesting::VestingRelease,
+        revocable: bool,
+        tokenizer: Address,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        tokenizer.require_auth();
+
+        // A grant delivers tokens, so the recipient must be eligible to hold
+        // them like any other transfer recipient.
+        transfer_restrictions::validate_transfer(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder.clone(),
+            amount,
+        )?;
+
+        vesting::create_grant(
+            &env, asset_id, holder, amount, start, cliff, end, release, revocable, tokenizer,
+        )
     }
 
-    /// Get ownership percentage for a holder (in basis points)
-    pub fn get_ownership_percentage(
+    /// Revoke a revocable vesting grant, returning its unvested tokens to the
+    /// tokenizer (only the asset tokenizer can call this)
+    pub fn revoke_vesting_grant(
         env: Env,
         asset_id: u64,
         holder: Address,
+        grant_id: u32,
+        tokenizer: Address,
     ) -> Result<i128, Error> {
-        tokenization::calculate_ownership_percentage(&env, asset_id, holder)
-    }
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
-    /// Get tokenized asset details
-    pub fn get_tokenized_asset(env: Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
-        tokenization::get_tokenized_asset(&env, asset_id)
+        tokenizer.require_auth();
+        vesting::revoke_grant(&env, asset_id, holder, grant_id, tokenizer)
     }
 
-    /// Update asset valuation
-    pub fn update_valuation(env: Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
-
-        tokenization::update_valuation(&env, asset_id, new_valuation)
+    /// Get a holder's vesting grants; the index is the grant id
+    pub fn get_vesting_grants(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+    ) -> Vec<vesting::VestingGrant> {
+        vesting::get_grants(&env, asset_id, holder)
+    }
+
+    /// Get the part of a holder's balance that has not vested yet
+    pub fn get_unvested_balance(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        vesting::unvested_balance(&env, asset_id, &holder)
+    }
+
+    /// Set the WASM hash of the `asset-token` contract deployed for each
+    /// tokenized asset (admin only). See `token_contract`.
+    pub fn set_token_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        token_contract::set_wasm_hash(&env, &wasm_hash);
+        Ok(())
+    }
+
+    /// Deploy the SEP-41 token contract for an asset tokenized before the
+    /// WASM hash was set (only the asset tokenizer can call this)
+    pub fn deploy_token_contract(
+        env: Env,
+        asset_id: u64,
+        tokenizer: Address,
+    ) -> Result<Address, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        tokenizer.require_auth();
+        token_contract::deploy_for_asset(&env, asset_id, tokenizer)
+    }
+
+    /// The asset's SEP-41 token contract, if one has been deployed
+    pub fn get_token_contract(env: Env, asset_id: u64) -> Option<Address> {
+        token_contract::get_contract(&env, asset_id)
+    }
+
+    /// `(decimals, name, symbol)` served by the asset's SEP-41 token contract
+    pub fn get_sep41_metadata(env: Env, asset_id: u64) -> Result<(u32, String, String), Error> {
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        let metadata = tokenization::get_token_metadata(&env, asset_id)?;
+        Ok((
+            tokenized_asset.decimals,
+            metadata.name,
+            tokenized_asset.symbol,
+        ))
+    }
+
+    /// Move tokens on behalf of the asset's SEP-41 token contract.
+    ///
+    /// Callable only by that contract, which has already authenticated `from`
+    /// (or a spender with allowance). Applies the same restriction, lock and
+    /// vesting checks as `transfer_tokens`. Returns the shares `to` received
+    /// once any share fee is withheld.
+    pub fn sep41_transfer(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        token_contract::require_token_contract(&env, asset_id)?;
+        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone(), amount)?;
+
+        tokenization::transfer_tokens(&env, asset_id, from, to, amount)
+    }
+
+    /// Burn a holder's tokens on behalf of the asset's SEP-41 token contract.
+    ///
+    /// Callable only by that contract. Locked and unvested tokens cannot be
+    /// burned.
+    pub fn sep41_burn(env: Env, asset_id: u64, from: Address, amount: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        token_contract::require_token_contract(&env, asset_id)?;
+        tokenization::burn_holder_tokens(&env, asset_id, from, amount)
+    }
+
+    /// Set the asset's holding period in seconds; zero removes it (only the
+    /// asset tokenizer can call this)
+    pub fn set_holding_period(env: Env, asset_id: u64, period: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        holding::set_period(&env, asset_id, period);
+        Ok(())
+    }
+
+    /// Get the asset's holding period in seconds (zero if none)
+    pub fn get_holding_period(env: Env, asset_id: u64) -> u64 {
+        holding::get_period(&env, asset_id)
+    }
+
+    /// Split a holder's balance into what may move now and what is held back
+    /// by a lock, vesting or the holding period
+    pub fn get_transferable_balance(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+    ) -> Result<TransferableBalance, Error> {
+        tokenization::get_transferable_balance(&env, asset_id, &holder)
+    }
+
+    /// Check if a holder's tokens are currently locked
+    pub fn is_tokens_locked(env: Env, asset_id: u64, holder: Address) -> bool {
+        tokenization::is_tokens_locked(&env, asset_id, holder)
+    }
+
+    /// Get ownership percentage for a holder (in basis points)
+    pub fn get_ownership_percentage(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+    ) -> Result<i128, Error> {
+        tokenization::calculate_ownership_percentage(&env, asset_id, holder)
+    }
+
+    /// Get tokenized asset details, with `locked_tokens` as of now
+    pub fn get_tokenized_asset(env: Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+        let mut tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.locked_tokens = vesting::locked_total(&env, asset_id)?;
+        Ok(tokenized_asset)
+    }
+
+    /// Overwrite asset valuation directly (admin override).
+    ///
+    /// Routine valuations come from approved oracles through
+    /// [`Self::submit_valuation`]; this exists for when they are unavailable.
+    pub fn update_valuation(env: Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        tokenization::update_valuation(&env, asset_id, new_valuation, admin)
+    }
+
+    // =====================
+    // Valuation Oracles
+    // =====================
+
+    /// Approve an address to submit valuations (admin only)
+    pub fn add_valuation_oracle(env: Env, oracle: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        valuation::add_oracle(&env, &oracle);
+        Ok(())
+    }
+
+    /// Revoke an oracle's approval (admin only). Its past submissions stop
+    /// counting towards the aggregate immediately.
+    pub fn remove_valuation_oracle(env: Env, oracle: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        valuation::remove_oracle(&env, &oracle);
+        Ok(())
+    }
+
+    /// Set the freshness window, outlier bound and quorum used to aggregate
+    /// oracle submissions (admin only)
+    pub fn set_valuation_config(
+        env: Env,
+        freshness_window: u64,
+        max_deviation_bps: u32,
+        min_submissions: u32,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        valuation::set_config(
+            &env,
+            valuation::ValuationConfig {
+                freshness_window,
+                max_deviation_bps,
+                min_submissions,
+            },
+        )
+    }
+
+    /// Submit a valuation backed by a report hash (approved oracles only).
+    /// Returns the asset's valuation after re-aggregating.
+    pub fn submit_valuation(
+        env: Env,
+        asset_id: u64,
+        oracle: Address,
+        value: i128,
+        report_hash: BytesN<32>,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        oracle.require_auth();
+        valuation::submit_valuation(&env, asset_id, oracle, value, report_hash)
+    }
+
+    /// Check if an address is an approved valuation oracle
+    pub fn is_valuation_oracle(env: Env, oracle: Address) -> bool {
+        valuation::is_oracle(&env, &oracle)
+    }
+
+    /// Get the valuation aggregation parameters
+    pub fn get_valuation_config(env: Env) -> valuation::ValuationConfig {
+        valuation::get_config(&env)
+    }
+
+    /// Get the applied valuations for an asset, oldest first
+    pub fn get_valuation_history(env: Env, asset_id: u64) -> Vec<valuation::ValuationRecord> {
+        valuation::get_history(&env, asset_id)
+    }
+
+    /// Get the net asset value of one whole token
+    pub fn get_nav_per_token(env: Env, asset_id: u64) -> Result<i128, Error> {
+        valuation::nav_per_token(&env, asset_id)
+    }
+
+    // =====================
+    // Primary Offerings
+    // =====================
+
+    /// Open a primary offering of an asset's shares, escrowing the hard cap
+    /// from the tokenizer (only tokenizer can call)
+    pub fn create_offering(
+        env: Env,
+        asset_id: u64,
+        terms: offering::OfferingTerms,
+        tokenizer: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        tokenizer.require_auth();
+        offering::create_offering(&env, asset_id, terms, tokenizer)
+    }
+
+    /// Buy shares in an open offering, paying in its payment token. Returns
+    /// the price paid.
+    pub fn buy_offering_shares(
+        env: Env,
+        asset_id: u64,
+        investor: Address,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        investor.require_auth();
+        offering::buy(&env, asset_id, investor, shares)
+    }
+
+    /// Close an offering once its window has ended or its hard cap has sold
+    /// out. Anyone may call. Returns whether it met its soft cap.
+    pub fn finalize_offering(env: Env, asset_id: u64) -> Result<bool, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        offering::finalize(&env, asset_id)
+    }
+
+    /// Claim shares from a successful offering, or a refund from a failed
+    /// one. Returns the shares or payment released.
+    pub fn claim_offering(env: Env, asset_id: u64, investor: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        investor.require_auth();
+        offering::claim(&env, asset_id, investor)
+    }
+
+    /// Get an asset's current or most recent offering
+    pub fn get_offering(env: Env, asset_id: u64) -> Option<offering::Offering> {
+        offering::get_offering(&env, asset_id)
+    }
+
+    /// Get an investor's unclaimed purchases in an asset's offering
+    pub fn get_offering_subscription(
+        env: Env,
+        asset_id: u64,
+        investor: Address,
+    ) -> Option<offering::Subscription> {
+        offering::get_subscription(&env, asset_id, &investor)
+    }
+
+    // =====================
+    // Secondary Market
+    // =====================
+
+    /// List shares for sale at a fixed price, escrowing them until the
+    /// listing is filled, cancelled or closed. Returns the listing id.
+    pub fn create_listing(
+        env: Env,
+        asset_id: u64,
+        seller: Address,
+        amount: i128,
+        payment_token: Address,
+        price: i128,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        seller.require_auth();
+        market::create_listing(
+            &env,
+            asset_id,
+            seller,
+            amount,
+            payment_token,
+            price,
+            expires_at,
+        )
+    }
+
+    /// Buy all or part of a listing, paying the seller in its payment token.
+    /// Returns the price paid.
+    pub fn buy_listing(
+        env: Env,
+        asset_id: u64,
+        listing_id: u64,
+        buyer: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        buyer.require_auth();
+        market::buy(&env, asset_id, listing_id, buyer, amount)
+    }
+
+    /// Withdraw a listing (seller only), or close it once expired (anyone).
+    /// Returns the shares sent back to the seller.
+    pub fn cancel_listing(
+        env: Env,
+        asset_id: u64,
+        listing_id: u64,
+        caller: Address,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        caller.require_auth();
+        market::cancel(&env, asset_id, listing_id, caller)
+    }
+
+    /// Get a listing by id
+    pub fn get_listing(env: Env, asset_id: u64, listing_id: u64) -> Option<market::Listing> {
+        market::get_listing(&env, asset_id, listing_id)
+    }
+
+    /// Get an asset's open listings, oldest first
+    pub fn get_listings(env: Env, asset_id: u64) -> Vec<market::Listing> {
+        market::get_listings(&env, asset_id)
+    }
+
+    // =====================
+    // Compliance
+    // =====================
+
+    /// Approve an address as a compliance officer (admin only)
+    pub fn add_compliance_officer(env: Env, officer: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        compliance::add_officer(&env, &officer);
+        Ok(())
+    }
+
+    /// Revoke a compliance officer's approval (admin only)
+    pub fn remove_compliance_officer(env: Env, officer: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, None)?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        compliance::remove_officer(&env, &officer);
+        Ok(())
+    }
+
+    /// Check if an address is an approved compliance officer
+    pub fn is_compliance_officer(env: Env, officer: Address) -> bool {
+        compliance::is_officer(&env, &officer)
+    }
+
+    /// Freeze a holder: no transfers to or from them, no votes and no dividend
+    /// claims until unfrozen (compliance officers only)
+    pub fn freeze_holder(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        officer: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        officer.require_auth();
+        compliance::freeze_holder(&env, asset_id, holder, officer)
+    }
+
+    /// Lift a holder's freeze (compliance officers only)
+    pub fn unfreeze_holder(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        officer: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        officer.require_auth();
+        compliance::unfreeze_holder(&env, asset_id, holder, officer)
+    }
+
+    /// Check if a holder is frozen
+    pub fn is_holder_frozen(env: Env, asset_id: u64, holder: Address) -> bool {
+        compliance::is_frozen(&env, asset_id, &holder)
+    }
+
+    /// Move tokens on a compliance officer's authority, e.g. under a court
+    /// order (compliance officers only).
+    ///
+    /// Bypasses freezes, locks, vesting and transfer restrictions. The
+    /// `reason_hash` identifies the order and is recorded in the audit log.
+    pub fn force_transfer(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+        reason_hash: BytesN<32>,
+        officer: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        officer.require_auth();
+        compliance::force_transfer(&env, asset_id, from, to, amount, reason_hash, officer)
     }
 
     // =====================
@@ -897,7 +1787,7 @@ impl AssetUpContract {
 
     /// Distribute dividends proportionally to all holders
     pub fn distribute_dividends(env: Env, asset_id: u64, total_amount: i128) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Dividends, Some(asset_id))?;
 
         dividends::distribute_dividends(&env, asset_id, total_amount)
     }
@@ -919,14 +1809,14 @@ impl AssetUpContract {
 
     /// Enable revenue sharing for an asset
     pub fn enable_revenue_sharing(env: Env, asset_id: u64) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Dividends, Some(asset_id))?;
 
         dividends::enable_revenue_sharing(&env, asset_id)
     }
 
     /// Disable revenue sharing for an asset
     pub fn disable_revenue_sharing(env: Env, asset_id: u64) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Dividends, Some(asset_id))?;
 
         dividends::disable_revenue_sharing(&env, asset_id)
     }
@@ -942,7 +1832,7 @@ impl AssetUpContract {
         proposal_id: u64,
         voter: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Voting, Some(asset_id))?;
 
         voter.require_auth();
         voting::cast_vote(&env, asset_id, proposal_id, voter)
@@ -978,7 +1868,7 @@ impl AssetUpContract {
         asset_id: u64,
         require_accredited: bool,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         transfer_restrictions::set_transfer_restriction(
             &env,
@@ -990,16 +1880,130 @@ impl AssetUpContract {
         )
     }
 
+    /// Set the holder-count, per-holder ownership and minimum-holding limits
+    /// (only the asset tokenizer can call this). Zero disables a limit.
+    pub fn set_holder_limits(
+        env: Env,
+        asset_id: u64,
+        max_holders: u32,
+        max_ownership_bps: u32,
+        min_holding: i128,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let tokenized_asset = tokenization::get_tokenized_asset(&env, asset_id)?;
+        tokenized_asset.tokenizer.require_auth();
+
+        transfer_restrictions::set_holder_limits(
+            &env,
+            asset_id,
+            HolderLimits {
+                max_holders,
+                max_ownership_bps,
+                min_holding,
+            },
+        )
+    }
+
+    /// Get holder limits
+    pub fn get_holder_limits(env: Env, asset_id: u64) -> HolderLimits {
+        transfer_restrictions::get_holder_limits(&env, asset_id)
+    }
+
+    /// Dry run of `transfer_tokens`, transfer fee included: returns the
+    /// shares `to` would receive, and otherwise fails with the error the
+    /// transfer would return. Changes nothing and needs no authorization.
+    pub fn can_transfer(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone(), amount)?;
+        tokenization::check_transfer(&env, asset_id, &from, &to, amount)
+    }
+
+    /// Charge a fee on the asset's secondary transfers (admin only): `bps` of
+    /// the amount, at least `min_fee`, paid in shares or in `fee_token`, split
+    /// between `treasury` and a `royalty_bps` royalty to the tokenizer
+    pub fn set_transfer_fee(
+        env: Env,
+        asset_id: u64,
+        bps: u32,
+        min_fee: i128,
+        fee_token: Option<Address>,
+        treasury: Address,
+        royalty_bps: u32,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+        tokenization::require_tokenized(&env, asset_id)?;
+
+        fees::set_config(
+            &env,
+            asset_id,
+            Some(fees::FeeConfig {
+                bps,
+                min_fee,
+                fee_token,
+                treasury,
+                royalty_bps,
+            }),
+        )
+    }
+
+    /// Remove the fee on the asset's transfers (admin only)
+    pub fn clear_transfer_fee(env: Env, asset_id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        fees::set_config(&env, asset_id, None)
+    }
+
+    /// Get the asset's transfer fee configuration, if it has one
+    pub fn get_transfer_fee(env: Env, asset_id: u64) -> Option<fees::FeeConfig> {
+        fees::get_config(&env, asset_id)
+    }
+
+    /// Exempt an address from the asset's transfer fee, or end its exemption
+    /// (admin only)
+    pub fn set_fee_exempt(
+        env: Env,
+        asset_id: u64,
+        address: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
+
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+
+        fees::set_exempt(&env, asset_id, &address, exempt);
+        Ok(())
+    }
+
+    /// Check if an address is exempt from the asset's transfer fee
+    pub fn is_fee_exempt(env: Env, asset_id: u64, address: Address) -> bool {
+        fees::is_exempt(&env, asset_id, &address)
+    }
+
     /// Add address to whitelist
     pub fn add_to_whitelist(env: Env, asset_id: u64, address: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         transfer_restrictions::add_to_whitelist(&env, asset_id, address)
     }
 
     /// Remove address from whitelist
     pub fn remove_from_whitelist(env: Env, asset_id: u64, address: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         transfer_restrictions::remove_from_whitelist(&env, asset_id, address)
     }
@@ -1024,7 +2028,7 @@ impl AssetUpContract {
         asset_id: u64,
         proposer: Address,
     ) -> Result<u64, Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         proposer.require_auth();
         detokenization::propose_detokenization(&env, asset_id, proposer)
@@ -1032,7 +2036,7 @@ impl AssetUpContract {
 
     /// Execute detokenization (if vote passed)
     pub fn execute_detokenization(env: Env, asset_id: u64, proposal_id: u64) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Tokenization, Some(asset_id))?;
 
         detokenization::execute_detokenization(&env, asset_id, proposal_id)
     }
@@ -1059,7 +2063,7 @@ impl AssetUpContract {
         env: Env,
         policy: insurance::InsurancePolicy,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Insurance, None)?;
 
         policy.insurer.require_auth();
         insurance::create_policy(env, policy)
@@ -1071,7 +2075,7 @@ impl AssetUpContract {
         policy_id: BytesN<32>,
         caller: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Insurance, None)?;
 
         caller.require_auth();
         insurance::cancel_policy(env, policy_id, caller)
@@ -1083,7 +2087,7 @@ impl AssetUpContract {
         policy_id: BytesN<32>,
         insurer: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Insurance, None)?;
 
         insurer.require_auth();
         insurance::suspend_policy(env, policy_id, insurer)
@@ -1091,7 +2095,7 @@ impl AssetUpContract {
 
     /// Expire a policy (permissionless)
     pub fn expire_insurance_policy(env: Env, policy_id: BytesN<32>) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Insurance, None)?;
 
         insurance::expire_policy(env, policy_id)
     }
@@ -1104,7 +2108,7 @@ impl AssetUpContract {
         new_premium: i128,
         insurer: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Insurance, None)?;
 
         insurer.require_auth();
         insurance::renew_policy(env, policy_id, new_end_date, new_premium, insurer)
@@ -1135,7 +2139,7 @@ impl AssetUpContract {
         rent: i128,
         deposit: i128,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Leasing, None)?;
 
         lessor.require_auth();
         lease::create_lease(
@@ -1149,7 +2153,7 @@ impl AssetUpContract {
         lease_id: BytesN<32>,
         caller: Address,
     ) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Leasing, None)?;
 
         caller.require_auth();
         lease::return_leased_asset(&env, lease_id, caller)
@@ -1157,7 +2161,7 @@ impl AssetUpContract {
 
     /// Cancel a lease before it starts. Lessor only.
     pub fn cancel_lease(env: Env, lease_id: BytesN<32>, caller: Address) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Leasing, None)?;
 
         caller.require_auth();
         lease::cancel_lease(&env, lease_id, caller)
@@ -1165,7 +2169,7 @@ impl AssetUpContract {
 
     /// Expire a lease permissionlessly once end_timestamp has passed.
     pub fn expire_lease(env: Env, lease_id: BytesN<32>) -> Result<(), Error> {
-        Self::require_not_paused(&env)?;
+        Self::require_not_paused(&env, pause::Module::Leasing, None)?;
 
         lease::expire_lease(&env, lease_id)
     }
@@ -0,0 +1,325 @@
+//! Secondary market: a fixed-price listing book per tokenized asset.
+//!
+//! `transfer_tokens` moves shares but has no payment leg, so a holder who
+//! wants to exit has to trust a buyer off-chain. A listing closes that gap.
+//!
+//! - **List.** A seller offers `amount` shares at `price` payment-token units
+//!   per whole share, in a Stellar Asset Contract (SAC) token of their choice,
+//!   until `expires_at`. The shares move into escrow under this contract's
+//!   address, so a listing can always be filled. Listing is subject to the
+//!   same freeze, lock and vesting checks as a transfer, and may not leave
+//!   the seller below the asset's minimum holding.
+//! - **Buy.** A buyer takes all or part of a listing. Payment goes straight
+//!   to the seller and the shares straight to the buyer in the same call, so
+//!   either both legs happen or neither does. The asset's transfer
+//!   restrictions and whitelist apply to the buyer as they would to a
+//!   transfer from the seller, and neither side may be frozen. The cost rounds up, as in a primary offering.
+//!   Any transfer fee on the asset is charged to the seller; see `fees`.
+//! - **Cancel.** The seller may withdraw a listing at any time. Once it has
+//!   expired, anyone may close it; either way the unsold shares go back to
+//!   the seller.
+//!
+//! A listing is removed from the book as soon as it is filled, cancelled or
+//! closed.
+
+use soroban_sdk::{contracttype, token, Address, Env, String, Vec};
+
+use crate::audit;
+use crate::error::Error;
+use crate::math;
+use crate::tokenization::{asset_id_to_bytes, move_tokens, require_unlocked};
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub listing_id: u64,
+    pub seller: Address,
+    /// The SAC token the seller is paid in.
+    pub payment_token: Address,
+    /// Payment-token units per whole share (`10^decimals` share units).
+    pub price: i128,
+    /// Share units still for sale, held in escrow.
+    pub remaining: i128,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `Listing` for (asset_id, listing_id).
+    Listing(u64, u64),
+    /// `Vec<u64>`: ids of the asset's open listings, oldest first.
+    Book(u64),
+    /// `u64`: the id the asset's next listing will take.
+    NextId(u64),
+}
+
+// ─── Listings ─────────────────────────────────────────────────────────────────
+
+/// Lists `amount` of the seller's shares and escrows them. Returns the new
+/// listing's id.
+pub fn create_listing(
+    env: &Env,
+    asset_id: u64,
+    seller: Address,
+    amount: i128,
+    payment_token: Address,
+    price: i128,
+    expires_at: u64,
+) -> Result<u64, Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+
+    if amount <= 0 || price <= 0 {
+        return Err(Error::InvalidInput);
+    }
+    let now = env.ledger().timestamp();
+    if expires_at <= now {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    crate::compliance::require_not_frozen(env, asset_id, &seller)?;
+    require_unlocked(env, asset_id, &seller, amount)?;
+    crate::transfer_restrictions::validate_escrow(env, asset_id, &seller, amount)?;
+
+    let escrow = env.current_contract_address();
+    move_tokens(env, &tokenized_asset, &seller, &escrow, amount)?;
+
+    let id_key = DataKey::NextId(asset_id);
+    let listing_id: u64 = env.storage().persistent().get(&id_key).unwrap_or(0);
+    env.storage().persistent().set(&id_key, &(listing_id + 1));
+    ttl::extend_persistent(env, &id_key);
+
+    let listing = Listing {
+        listing_id,
+        seller: seller.clone(),
+        payment_token,
+        price,
+        remaining: amount,
+        created_at: now,
+        expires_at,
+    };
+    store_listing(env, asset_id, &listing);
+
+    let mut book = get_book(env, asset_id);
+    book.push_back(listing_id);
+    store_book(env, asset_id, &book);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "LISTING_CREATED"),
+        seller,
+        String::from_str(env, "Shares listed for sale"),
+    );
+
+    crate::events::listing_created(env, asset_id, &listing);
+
+    Ok(listing_id)
+}
+
+/// Buys `amount` shares from a listing: the buyer pays the seller and
+/// receives the shares from escrow. Returns the price paid.
+pub fn buy(
+    env: &Env,
+    asset_id: u64,
+    listing_id: u64,
+    buyer: Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    let mut listing = get_listing(env, asset_id, listing_id).ok_or(Error::NotFound)?;
+
+    if env.ledger().timestamp() >= listing.expires_at {
+        return Err(Error::InvalidSaleState);
+    }
+    if amount <= 0 || amount > listing.remaining {
+        return Err(Error::InvalidInput);
+    }
+
+    // The buyer is receiving the seller's shares, out of escrow; a seller
+    // frozen since listing may not be paid for them.
+    crate::compliance::require_not_frozen(env, asset_id, &listing.seller)?;
+    crate::compliance::require_not_frozen(env, asset_id, &buyer)?;
+    crate::transfer_restrictions::validate_transfer(
+        env,
+        asset_id,
+        env.current_contract_address(),
+        buyer.clone(),
+        amount,
+    )?;
+
+    let one_share = 10i128
+        .checked_pow(tokenized_asset.decimals)
+        .ok_or(Error::MathOverflow)?;
+    let cost = math::mul_div_ceil(amount, listing.price, one_share)?;
+
+    // The seller pays the transfer fee: in shares out of escrow, or in tokens
+    // out of the price.
+    let escrow = env.current_contract_address();
+    let charge = crate::fees::quote(env, &tokenized_asset, &listing.seller, &buyer, amount)?;
+    let proceeds = match &charge.fee_token {
+        Some(fee_token) if *fee_token != listing.payment_token || cost < charge.fee => {
+            return Err(Error::InvalidPayment);
+        }
+        Some(_) => math::sub(cost, charge.fee)?,
+        None => cost,
+    };
+    crate::fees::check(env, asset_id, &charge, &escrow, &buyer)?;
+    crate::fees::collect(env, &tokenized_asset, &charge, &escrow, &buyer)?;
+
+    token::TokenClient::new(env, &listing.payment_token).transfer(
+        &buyer,
+        &listing.seller,
+        &proceeds,
+    );
+    move_tokens(env, &tokenized_asset, &escrow, &buyer, charge.net)?;
+    crate::holding::record(env, &tokenized_asset, &buyer, charge.net);
+
+    listing.remaining = math::sub(listing.remaining, amount)?;
+    if listing.remaining == 0 {
+        remove_listing(env, asset_id, listing_id);
+    } else {
+        store_listing(env, asset_id, &listing);
+    }
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "LISTING_FILLED"),
+        buyer.clone(),
+        String::from_str(env, "Listed shares bought"),
+    );
+
+    crate::events::listing_filled(
+        env,
+        asset_id,
+        listing_id,
+        &listing.seller,
+        &buyer,
+        amount,
+        cost,
+        charge.fee,
+        charge.fee_token,
+    );
+
+    Ok(cost)
+}
+
+/// Takes a listing off the book and returns its unsold shares to the seller.
+///
+/// Before expiry only the seller may do this; afterwards anyone may, so an
+/// expired listing never strands the seller's shares.
+pub fn cancel(env: &Env, asset_id: u64, listing_id: u64, caller: Address) -> Result<i128, Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    let listing = get_listing(env, asset_id, listing_id).ok_or(Error::NotFound)?;
+
+    let expired = env.ledger().timestamp() >= listing.expires_at;
+    if caller != listing.seller && !expired {
+        return Err(Error::Unauthorized);
+    }
+
+    move_tokens(
+        env,
+        &tokenized_asset,
+        &env.current_contract_address(),
+        &listing.seller,
+        listing.remaining,
+    )?;
+    remove_listing(env, asset_id, listing_id);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "LISTING_CANCELLED"),
+        caller,
+        if expired {
+            String::from_str(env, "Expired listing closed; shares returned")
+        } else {
+            String::from_str(env, "Listing withdrawn by seller")
+        },
+    );
+
+    crate::events::listing_cancelled(
+        env,
+        asset_id,
+        listing_id,
+        &listing.seller,
+        listing.remaining,
+        expired,
+    );
+
+    Ok(listing.remaining)
+}
+
+// ─── Reads ────────────────────────────────────────────────────────────────────
+
+pub fn get_listing(env: &Env, asset_id: u64, listing_id: u64) -> Option<Listing> {
+    let key = DataKey::Listing(asset_id, listing_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+/// The asset's open listings, oldest first. Expired listings stay in the
+/// book until someone closes them.
+pub fn get_listings(env: &Env, asset_id: u64) -> Vec<Listing> {
+    let mut listings = Vec::new(env);
+    for listing_id in get_book(env, asset_id).iter() {
+        if let Some(listing) = get_listing(env, asset_id, listing_id) {
+            listings.push_back(listing);
+        }
+    }
+    listings
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+fn get_book(env: &Env, asset_id: u64) -> Vec<u64> {
+    let key = DataKey::Book(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn store_book(env: &Env, asset_id: u64, book: &Vec<u64>) {
+    let key = DataKey::Book(asset_id);
+    if book.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, book);
+        ttl::extend_persistent(env, &key);
+    }
+}
+
+fn store_listing(env: &Env, asset_id: u64, listing: &Listing) {
+    let key = DataKey::Listing(asset_id, listing.listing_id);
+    env.storage().persistent().set(&key, listing);
+    ttl::extend_persistent(env, &key);
+}
+
+fn remove_listing(env: &Env, asset_id: u64, listing_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Listing(asset_id, listing_id));
+
+    let mut book = get_book(env, asset_id);
+    if let Some(index) = book.first_index_of(listing_id) {
+        book.remove(index);
+    }
+    store_book(env, asset_id, &book);
+}
+
+fn load_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)
+}
@@ -60,13 +60,35 @@ pub fn mul(a: i128, b: i128) -> Result<i128, Error> {
 /// Returns [`Error::MathOverflow`] if `d` is zero, since a zero denominator
 /// here always means a corrupt total supply rather than a caller mistake.
 pub fn mul_div(a: i128, b: i128, d: i128) -> Result<i128, Error> {
+    let (product, d) = reduced_product(a, b, d)?;
+    Ok(product / d)
+}
+
+/// `(a * b) / d`, rounding **up** for non-negative operands.
+///
+/// For amounts owed *to* the contract — the price of a share purchase — where
+/// rounding down would let a buyer take a fraction of a share for free.
+pub fn mul_div_ceil(a: i128, b: i128, d: i128) -> Result<i128, Error> {
+    let (product, d) = reduced_product(a, b, d)?;
+    let quotient = product / d;
+    if product % d == 0 {
+        Ok(quotient)
+    } else {
+        add(quotient, 1)
+    }
+}
+
+/// `a * b` and `d`, with common factors cancelled as far as needed for the
+/// product to fit. The pair has the same quotient as the unreduced fraction,
+/// and divides exactly if and only if it does.
+fn reduced_product(a: i128, b: i128, d: i128) -> Result<(i128, i128), Error> {
     if d == 0 {
         return Err(Error::MathOverflow);
     }
 
     // The common case: the product fits, so compute it directly.
     if let Some(product) = a.checked_mul(b) {
-        return Ok(product / d);
+        return Ok((product, d));
     }
 
     // The product does not fit. Reduce the fraction before multiplying by
@@ -81,7 +103,7 @@ pub fn mul_div(a: i128, b: i128, d: i128) -> Result<i128, Error> {
     let (b, d) = (b / g2 as i128, d / g2 as i128);
 
     match a.checked_mul(b) {
-        Some(product) => Ok(product / d),
+        Some(product) => Ok((product, d)),
         None => Err(Error::MathOverflow),
     }
 }
@@ -172,6 +194,15 @@ mod tests {
         assert_eq!(mul_div(i128::MAX, 3, 1), Err(Error::MathOverflow));
     }
 
+    #[test]
+    fn mul_div_ceil_rounds_up_only_when_inexact() {
+        assert_eq!(mul_div_ceil(1, 10, 3), Ok(4));
+        assert_eq!(mul_div_ceil(3, 10, 3), Ok(10));
+        assert_eq!(mul_div_ceil(0, 10, 3), Ok(0));
+        // Still exact after the overflow-avoiding reduction.
+        assert_eq!(mul_div_ceil(i128::MAX, 4, 4), Ok(i128::MAX));
+    }
+
     #[test]
     fn gcd_is_well_behaved_at_zero() {
         assert_eq!(gcd(0, 0), 1);
@@ -0,0 +1,276 @@
+//! Editable token metadata, with every version kept.
+//!
+//! `tokenize_asset` stores a `TokenMetadata` with no document references. The
+//! tokenizer fills them in, and corrects any other field, one field at a time.
+//! Each update stores a new **revision**: a full copy of the metadata as it
+//! stood after the change, with when it was made and by whom. Revisions are
+//! never rewritten, so an investor can establish which legal documents and
+//! valuation report were in force at any past moment with
+//! [`get_metadata_at`].
+//!
+//! Revision 0 is the metadata set at tokenization. It is recorded on the first
+//! update, dated to the tokenization, so assets that are never edited store no
+//! history beyond their current metadata.
+//!
+//! Revisions outlive detokenization, like the audit log. If the same asset id
+//! is tokenized again, its new initial metadata is appended as a fresh
+//! `Initial` revision.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::audit;
+use crate::error::Error;
+use crate::tokenization::asset_id_to_bytes;
+use crate::ttl;
+use crate::types::{AssetType, TokenDataKey, TokenMetadata, TokenizedAsset};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Which `TokenMetadata` field an update changed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MetadataField {
+    /// The initial metadata, set at tokenization.
+    Initial,
+    Name,
+    Description,
+    AssetType,
+    IpfsUri,
+    LegalDocsHash,
+    ValuationReportHash,
+    AccreditedInvestorRequired,
+    GeographicRestrictions,
+}
+
+/// The metadata as it stood after one update.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataRevision {
+    pub revision: u32,
+    pub field: MetadataField,
+    pub metadata: TokenMetadata,
+    pub updated_at: u64,
+    pub updated_by: Address,
+}
+
+/// A single-field change, as passed from an entrypoint to [`apply`].
+pub enum MetadataUpdate {
+    Name(String),
+    Description(String),
+    AssetType(AssetType),
+    IpfsUri(Option<String>),
+    LegalDocsHash(Option<BytesN<32>>),
+    ValuationReportHash(Option<BytesN<32>>),
+    AccreditedInvestorRequired(bool),
+    GeographicRestrictions(Vec<String>),
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `MetadataRevision` for (asset_id, revision).
+    Revision(u64, u32),
+    /// `u32`: number of revisions recorded for asset_id.
+    RevisionCount(u64),
+}
+
+// ─── Updates ──────────────────────────────────────────────────────────────────
+
+/// Applies one field change on the tokenizer's behalf and records it as a new
+/// revision. Returns the revision number.
+pub fn apply(
+    env: &Env,
+    asset_id: u64,
+    update: MetadataUpdate,
+    caller: Address,
+) -> Result<u32, Error> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    if caller != tokenized_asset.tokenizer {
+        return Err(Error::Unauthorized);
+    }
+
+    let metadata_key = TokenDataKey::TokenMetadata(asset_id);
+    let mut metadata: TokenMetadata = env
+        .storage()
+        .persistent()
+        .get(&metadata_key)
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let mut count = revision_count(env, asset_id);
+    if count == 0 {
+        store_revision(
+            env,
+            asset_id,
+            &MetadataRevision {
+                revision: 0,
+                field: MetadataField::Initial,
+                metadata: metadata.clone(),
+                updated_at: tokenized_asset.tokenization_timestamp,
+                updated_by: tokenized_asset.tokenizer.clone(),
+            },
+        );
+        count = 1;
+    }
+
+    let field = match update {
+        MetadataUpdate::Name(v) => {
+            if v.is_empty() {
+                return Err(Error::InvalidInput);
+            }
+            metadata.name = v;
+            MetadataField::Name
+        }
+        MetadataUpdate::Description(v) => {
+            metadata.description = v;
+            MetadataField::Description
+        }
+        MetadataUpdate::AssetType(v) => {
+            metadata.asset_type = v;
+            MetadataField::AssetType
+        }
+        MetadataUpdate::IpfsUri(v) => {
+            metadata.ipfs_uri = v;
+            MetadataField::IpfsUri
+        }
+        MetadataUpdate::LegalDocsHash(v) => {
+            metadata.legal_docs_hash = v;
+            MetadataField::LegalDocsHash
+        }
+        MetadataUpdate::ValuationReportHash(v) => {
+            metadata.valuation_report_hash = v;
+            MetadataField::ValuationReportHash
+        }
+        MetadataUpdate::AccreditedInvestorRequired(v) => {
+            metadata.accredited_investor_required = v;
+            MetadataField::AccreditedInvestorRequired
+        }
+        MetadataUpdate::GeographicRestrictions(v) => {
+            metadata.geographic_restrictions = v;
+            MetadataField::GeographicRestrictions
+        }
+    };
+
+    env.storage().persistent().set(&metadata_key, &metadata);
+    ttl::extend_persistent(env, &metadata_key);
+
+    let revision = count;
+    store_revision(
+        env,
+        asset_id,
+        &MetadataRevision {
+            revision,
+            field,
+            metadata,
+            updated_at: env.ledger().timestamp(),
+            updated_by: caller.clone(),
+        },
+    );
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "METADATA_UPDATED"),
+        caller.clone(),
+        String::from_str(env, "Token metadata field updated"),
+    );
+
+    crate::events::token_metadata_updated(env, asset_id, revision, field, &caller);
+
+    Ok(revision)
+}
+
+/// Called by `tokenize_asset`. Appends the new initial metadata if the asset
+/// id already has history from an earlier tokenization.
+pub(crate) fn record_tokenization(
+    env: &Env,
+    asset_id: u64,
+    metadata: &TokenMetadata,
+    tokenizer: &Address,
+) {
+    let revision = revision_count(env, asset_id);
+    if revision > 0 {
+        store_revision(
+            env,
+            asset_id,
+            &MetadataRevision {
+                revision,
+                field: MetadataField::Initial,
+                metadata: metadata.clone(),
+                updated_at: env.ledger().timestamp(),
+                updated_by: tokenizer.clone(),
+            },
+        );
+    }
+}
+
+// ─── Reads ────────────────────────────────────────────────────────────────────
+
+/// Number of recorded revisions, including revision 0. Zero until the first
+/// update.
+pub fn revision_count(env: &Env, asset_id: u64) -> u32 {
+    let key = DataKey::RevisionCount(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn get_revision(env: &Env, asset_id: u64, revision: u32) -> Option<MetadataRevision> {
+    let key = DataKey::Revision(asset_id, revision);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+/// The metadata in force at `timestamp`: the latest revision made at or
+/// before it. `NotFound` before the asset was tokenized.
+pub fn get_metadata_at(env: &Env, asset_id: u64, timestamp: u64) -> Result<TokenMetadata, Error> {
+    let count = revision_count(env, asset_id);
+    if count == 0 {
+        // Never edited: the current metadata has applied since tokenization.
+        let tokenized_asset: TokenizedAsset = env
+            .storage()
+            .persistent()
+            .get(&TokenDataKey::TokenizedAsset(asset_id))
+            .ok_or(Error::AssetNotTokenized)?;
+        if timestamp < tokenized_asset.tokenization_timestamp {
+            return Err(Error::NotFound);
+        }
+        return crate::tokenization::get_token_metadata(env, asset_id);
+    }
+
+    // Revisions are in time order; find the last one not after `timestamp`.
+    let (mut low, mut high) = (0u32, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let revision = get_revision(env, asset_id, mid).ok_or(Error::NotFound)?;
+        if revision.updated_at <= timestamp {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == 0 {
+        return Err(Error::NotFound);
+    }
+    get_revision(env, asset_id, low - 1)
+        .map(|r| r.metadata)
+        .ok_or(Error::NotFound)
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+fn store_revision(env: &Env, asset_id: u64, revision: &MetadataRevision) {
+    let key = DataKey::Revision(asset_id, revision.revision);
+    env.storage().persistent().set(&key, revision);
+    ttl::extend_persistent(env, &key);
+
+    let count_key = DataKey::RevisionCount(asset_id);
+    env.storage()
+        .persistent()
+        .set(&count_key, &(revision.revision + 1));
+    ttl::extend_persistent(env, &count_key);
+}
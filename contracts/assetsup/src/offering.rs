@@ -0,0 +1,407 @@
+//! Primary offerings: selling a tokenized asset's shares to investors for a
+//! Stellar Asset Contract (SAC) payment token.
+//!
+//! `tokenize_asset` mints the whole supply to the tokenizer. An offering lets
+//! the tokenizer sell part of it on-chain instead of settling one
+//! `transfer_tokens` per investor off-chain.
+//!
+//! ## Lifecycle
+//!
+//! 1. **Create.** The tokenizer sets the [`OfferingTerms`]. `hard_cap` shares
+//!    move from the tokenizer into escrow under this contract's address, so
+//!    every share sold is guaranteed to exist.
+//! 2. **Buy.** Between `start` and `end`, investors pay `price` per whole
+//!    share and are credited a subscription. Payment is held by this contract.
+//!    The asset's transfer restrictions apply to the investor as they would to
+//!    a transfer from the tokenizer.
+//! 3. **Finalize.** Once `end` passes, or the hard cap sells out, anyone may
+//!    finalize. If at least `soft_cap` shares sold, the offering succeeds: the
+//!    proceeds go to the tokenizer along with any unsold shares. Otherwise it
+//!    fails and every escrowed share goes back to the tokenizer.
+//! 4. **Claim.** Each investor claims their shares from a successful offering
+//!    or their payment back from a failed one.
+//!
+//! Caps and purchase limits are in share units; `max_purchase` bounds an
+//! investor's total across purchases. The cost of a purchase rounds up, so a
+//! fraction of a share is never free.
+//!
+//! A purchase is checked against the asset's holder limits as if its shares
+//! were already delivered, and it keeps that room until it is claimed: an
+//! unclaimed subscription counts toward the buyer's balance under
+//! `max_ownership_bps`, and one from a buyer with no shares yet holds a place
+//! under `max_holders`. No transfer in between can take the room a claim
+//! needs, so a claim from a successful offering is not checked again.
+//!
+//! Escrowed shares are held by this contract's address like any other holding.
+//! They carry no vote, since the contract never casts one, and a dividend
+//! distributed during the offering accrues to the escrow unclaimed.
+
+use soroban_sdk::{contracttype, token, Address, Env, String};
+
+use crate::audit;
+use crate::error::Error;
+use crate::math;
+use crate::tokenization::{asset_id_to_bytes, move_tokens};
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// What the tokenizer sets when opening an offering.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferingTerms {
+    /// The SAC token investors pay in.
+    pub payment_token: Address,
+    /// Payment-token units per whole share (`10^decimals` share units).
+    pub price: i128,
+    pub start: u64,
+    pub end: u64,
+    /// Smallest single purchase, in share units.
+    pub min_purchase: i128,
+    /// Largest total any one investor may buy, in share units.
+    pub max_purchase: i128,
+    /// Shares that must sell for the offering to succeed.
+    pub soft_cap: i128,
+    /// Shares on offer; escrowed from the tokenizer at creation.
+    pub hard_cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OfferingStatus {
+    Open,
+    Succeeded,
+    Failed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offering {
+    pub terms: OfferingTerms,
+    pub status: OfferingStatus,
+    /// Share units sold so far.
+    pub sold: i128,
+    /// Payment-token units received so far.
+    pub raised: i128,
+    /// Subscriptions not yet claimed.
+    pub unsettled: u32,
+    /// Unclaimed subscriptions from investors who held no shares when they
+    /// first bought; each holds a place under `max_holders`.
+    pub new_holders: u32,
+}
+
+/// An investor's purchases in the current offering.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub shares: i128,
+    pub paid: i128,
+    /// The investor held no shares at their first purchase.
+    pub new_holder: bool,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// The asset's current or most recent `Offering`.
+    Offering(u64),
+    /// `Subscription` for (asset_id, investor).
+    Subscription(u64, Address),
+}
+
+// ─── Lifecycle ────────────────────────────────────────────────────────────────
+
+/// Opens an offering and escrows `hard_cap` shares from the tokenizer.
+///
+/// A new offering can only replace a previous one once that one is finalized
+/// and every subscription to it has been claimed.
+pub fn create_offering(
+    env: &Env,
+    asset_id: u64,
+    terms: OfferingTerms,
+    tokenizer: Address,
+) -> Result<(), Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    if tokenized_asset.tokenizer != tokenizer {
+        return Err(Error::Unauthorized);
+    }
+
+    if let Some(previous) = get_offering(env, asset_id) {
+        if previous.status == OfferingStatus::Open || previous.unsettled > 0 {
+            return Err(Error::InvalidSaleState);
+        }
+    }
+
+    if terms.start >= terms.end || terms.end <= env.ledger().timestamp() {
+        return Err(Error::InvalidTimestamps);
+    }
+    if terms.price <= 0
+        || terms.min_purchase <= 0
+        || terms.min_purchase > terms.max_purchase
+        || terms.soft_cap <= 0
+        || terms.soft_cap > terms.hard_cap
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    let escrow = env.current_contract_address();
+    move_tokens(env, &tokenized_asset, &tokenizer, &escrow, terms.hard_cap)?;
+
+    let offering = Offering {
+        terms: terms.clone(),
+        status: OfferingStatus::Open,
+        sold: 0,
+        raised: 0,
+        unsettled: 0,
+        new_holders: 0,
+    };
+    store_offering(env, asset_id, &offering);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "OFFERING_CREATED"),
+        tokenizer,
+        String::from_str(env, "Primary offering opened"),
+    );
+
+    crate::events::offering_created(env, asset_id, &terms);
+
+    Ok(())
+}
+
+/// Buys `shares` for `investor`, taking payment now and crediting a
+/// subscription. Returns the price paid.
+pub fn buy(env: &Env, asset_id: u64, investor: Address, shares: i128) -> Result<i128, Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    let mut offering = get_offering(env, asset_id).ok_or(Error::NotFound)?;
+
+    let now = env.ledger().timestamp();
+    if offering.status != OfferingStatus::Open
+        || now < offering.terms.start
+        || now >= offering.terms.end
+    {
+        return Err(Error::InvalidSaleState);
+    }
+
+    let sub_key = DataKey::Subscription(asset_id, investor.clone());
+    let mut subscription: Subscription =
+        env.storage()
+            .persistent()
+            .get(&sub_key)
+            .unwrap_or(Subscription {
+                shares: 0,
+                paid: 0,
+                new_holder: false,
+            });
+
+    if shares < offering.terms.min_purchase
+        || math::add(subscription.shares, shares)? > offering.terms.max_purchase
+        || math::add(offering.sold, shares)? > offering.terms.hard_cap
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    // The investor will end up holding shares the tokenizer sold them, out
+    // of escrow. What they have already subscribed for counts as held.
+    crate::compliance::require_not_frozen(env, asset_id, &investor)?;
+    crate::transfer_restrictions::validate_transfer(
+        env,
+        asset_id,
+        env.current_contract_address(),
+        investor.clone(),
+        shares,
+    )?;
+
+    let one_share = 10i128
+        .checked_pow(tokenized_asset.decimals)
+        .ok_or(Error::MathOverflow)?;
+    let cost = math::mul_div_ceil(shares, offering.terms.price, one_share)?;
+
+    token::TokenClient::new(env, &offering.terms.payment_token).transfer(
+        &investor,
+        env.current_contract_address(),
+        &cost,
+    );
+
+    if subscription.shares == 0 {
+        offering.unsettled += 1;
+        subscription.new_holder = investor != tokenized_asset.tokenizer
+            && crate::tokenization::get_token_balance(env, asset_id, investor.clone())? == 0;
+        if subscription.new_holder {
+            offering.new_holders += 1;
+        }
+    }
+    subscription.shares = math::add(subscription.shares, shares)?;
+    subscription.paid = math::add(subscription.paid, cost)?;
+    offering.sold = math::add(offering.sold, shares)?;
+    offering.raised = math::add(offering.raised, cost)?;
+
+    env.storage().persistent().set(&sub_key, &subscription);
+    ttl::extend_persistent(env, &sub_key);
+    store_offering(env, asset_id, &offering);
+
+    crate::events::offering_purchased(env, asset_id, &investor, shares, cost);
+
+    Ok(cost)
+}
+
+/// Settles an offering whose window has closed or whose hard cap has sold
+/// out. Returns whether it succeeded.
+pub fn finalize(env: &Env, asset_id: u64) -> Result<bool, Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    let mut offering = get_offering(env, asset_id).ok_or(Error::NotFound)?;
+
+    let sold_out = offering.sold == offering.terms.hard_cap;
+    if offering.status != OfferingStatus::Open
+        || (env.ledger().timestamp() < offering.terms.end && !sold_out)
+    {
+        return Err(Error::InvalidSaleState);
+    }
+
+    let escrow = env.current_contract_address();
+    let tokenizer = tokenized_asset.tokenizer.clone();
+    let succeeded = offering.sold >= offering.terms.soft_cap;
+
+    // Shares that will not be delivered to investors go back to the tokenizer.
+    let returned = if succeeded {
+        math::sub(offering.terms.hard_cap, offering.sold)?
+    } else {
+        offering.terms.hard_cap
+    };
+    if returned > 0 {
+        move_tokens(env, &tokenized_asset, &escrow, &tokenizer, returned)?;
+    }
+
+    if succeeded && offering.raised > 0 {
+        token::TokenClient::new(env, &offering.terms.payment_token).transfer(
+            &escrow,
+            &tokenizer,
+            &offering.raised,
+        );
+    }
+
+    offering.status = if succeeded {
+        OfferingStatus::Succeeded
+    } else {
+        OfferingStatus::Failed
+    };
+    store_offering(env, asset_id, &offering);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "OFFERING_FINALIZED"),
+        escrow,
+        if succeeded {
+            String::from_str(env, "Offering succeeded; proceeds released")
+        } else {
+            String::from_str(env, "Offering missed its soft cap; refunds open")
+        },
+    );
+
+    crate::events::offering_finalized(env, asset_id, succeeded, offering.sold, offering.raised);
+
+    Ok(succeeded)
+}
+
+/// Delivers an investor's shares from a successful offering, or refunds
+/// their payment from a failed one. Returns the shares or payment released.
+///
+/// The holder limits are not checked again: the purchase reserved the room
+/// for these shares. A frozen investor cannot claim until unfrozen.
+///
+/// A refund does not need the asset to still be tokenized, so it survives a
+/// detokenization that lands before every investor has claimed.
+pub fn claim(env: &Env, asset_id: u64, investor: Address) -> Result<i128, Error> {
+    let mut offering = get_offering(env, asset_id).ok_or(Error::NotFound)?;
+    if offering.status == OfferingStatus::Open {
+        return Err(Error::InvalidSaleState);
+    }
+
+    let sub_key = DataKey::Subscription(asset_id, investor.clone());
+    let subscription: Subscription = env
+        .storage()
+        .persistent()
+        .get(&sub_key)
+        .ok_or(Error::NotFound)?;
+
+    let escrow = env.current_contract_address();
+    let (shares, refund) = if offering.status == OfferingStatus::Succeeded {
+        crate::compliance::require_not_frozen(env, asset_id, &investor)?;
+        let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+        move_tokens(
+            env,
+            &tokenized_asset,
+            &escrow,
+            &investor,
+            subscription.shares,
+        )?;
+        crate::holding::record(env, &tokenized_asset, &investor, subscription.shares);
+        (subscription.shares, 0)
+    } else {
+        token::TokenClient::new(env, &offering.terms.payment_token).transfer(
+            &escrow,
+            &investor,
+            &subscription.paid,
+        );
+        (0, subscription.paid)
+    };
+
+    env.storage().persistent().remove(&sub_key);
+    offering.unsettled -= 1;
+    if subscription.new_holder {
+        offering.new_holders -= 1;
+    }
+    store_offering(env, asset_id, &offering);
+
+    crate::events::offering_claimed(env, asset_id, &investor, shares, refund);
+
+    Ok(shares + refund)
+}
+
+// ─── Reads ────────────────────────────────────────────────────────────────────
+
+pub fn get_offering(env: &Env, asset_id: u64) -> Option<Offering> {
+    let key = DataKey::Offering(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_subscription(env: &Env, asset_id: u64, investor: &Address) -> Option<Subscription> {
+    let key = DataKey::Subscription(asset_id, investor.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+/// The shares sold to `investor` and not yet claimed, and the number of
+/// unclaimed subscriptions holding a place under `max_holders`. Both are zero
+/// once an offering fails, since it delivers no shares.
+pub(crate) fn reserved(env: &Env, asset_id: u64, investor: &Address) -> (i128, u32) {
+    let Some(offering) = get_offering(env, asset_id) else {
+        return (0, 0);
+    };
+    if offering.status == OfferingStatus::Failed {
+        return (0, 0);
+    }
+    let shares = get_subscription(env, asset_id, investor).map_or(0, |s| s.shares);
+    (shares, offering.new_holders)
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+fn store_offering(env: &Env, asset_id: u64, offering: &Offering) {
+    let key = DataKey::Offering(asset_id);
+    env.storage().persistent().set(&key, offering);
+    ttl::extend_persistent(env, &key);
+}
+
+fn load_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)
+}
@@ -0,0 +1,178 @@
+//! Scoped pauses: one module, or one tokenized asset, instead of everything.
+//!
+//! `pause_contract` stops every mutating entrypoint at once. That is the right
+//! tool for a contract-wide incident and the wrong one for a single
+//! misbehaving asset, where it halts registration, leasing and insurance for
+//! every other customer too. A pause here has a [`PauseScope`]:
+//!
+//! - `Contract`: everything, like `pause_contract`;
+//! - `Module(m)`: the entrypoints of one [`Module`];
+//! - `Asset(id)`: every entrypoint acting on tokenized asset `id`. Registry,
+//!   leasing and insurance key assets by `BytesN<32>` and are not covered.
+//!
+//! A pause may carry an expiry, after which it lapses on its own with no
+//! further transaction, so a forgotten pause cannot freeze the contract
+//! indefinitely.
+//!
+//! Besides the admin, **pausers** may pause. A pauser cannot unpause, nor cut
+//! short a pause already in force by replacing it with one that ends sooner:
+//! a leaked pauser key can stop the contract but never restart it.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::Error;
+use crate::ttl;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// The areas of the contract that can be paused separately.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Module {
+    /// Registration, ownership, metadata and retirement of registry assets,
+    /// and the registrar allowlist.
+    Registry,
+    /// Shares and everything built on them: transfers, minting, metadata,
+    /// valuations, offerings, listings, compliance, restrictions, fees and
+    /// detokenization.
+    Tokenization,
+    Dividends,
+    Voting,
+    Leasing,
+    Insurance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Contract,
+    Module(Module),
+    /// A tokenized asset, by its `u64` id.
+    Asset(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseRecord {
+    pub paused_by: Address,
+    pub paused_at: u64,
+    /// Ledger timestamp at which the pause lapses; `None` lasts until lifted.
+    pub expires_at: Option<u64>,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `bool`: the address may pause.
+    Pauser(Address),
+    /// `PauseRecord` for a scope. May have expired; see [`get`].
+    Scope(PauseScope),
+}
+
+// ─── Pausers ──────────────────────────────────────────────────────────────────
+
+pub fn add_pauser(env: &Env, pauser: &Address) {
+    let key = DataKey::Pauser(pauser.clone());
+    env.storage().persistent().set(&key, &true);
+    ttl::extend_persistent(env, &key);
+    crate::events::pauser_added(env, pauser);
+}
+
+pub fn remove_pauser(env: &Env, pauser: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Pauser(pauser.clone()));
+    crate::events::pauser_removed(env, pauser);
+}
+
+pub fn is_pauser(env: &Env, address: &Address) -> bool {
+    let key = DataKey::Pauser(address.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+// ─── Pausing ──────────────────────────────────────────────────────────────────
+
+/// Pauses `scope` on behalf of `by`, who has already been authenticated as
+/// the admin or a pauser.
+///
+/// A pauser may only lengthen a pause in force: replacing it with one that
+/// lapses sooner would amount to unpausing, and fails with `Unauthorized`.
+pub fn pause(
+    env: &Env,
+    scope: PauseScope,
+    by: &Address,
+    by_admin: bool,
+    expires_at: Option<u64>,
+) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    if expires_at.is_some_and(|t| t <= now) {
+        return Err(Error::InvalidInput);
+    }
+
+    if !by_admin {
+        if let Some(current) = get(env, &scope) {
+            let shortens = match (current.expires_at, expires_at) {
+                (None, Some(_)) => true,
+                (Some(current), Some(new)) => new < current,
+                (_, None) => false,
+            };
+            if shortens {
+                return Err(Error::Unauthorized);
+            }
+        }
+    }
+
+    let key = DataKey::Scope(scope.clone());
+    env.storage().persistent().set(
+        &key,
+        &PauseRecord {
+            paused_by: by.clone(),
+            paused_at: now,
+            expires_at,
+        },
+    );
+    ttl::extend_persistent(env, &key);
+
+    crate::events::scope_paused(env, scope, by, expires_at);
+
+    Ok(())
+}
+
+/// Lifts the pause on `scope`, if any. Admin only; checked by the caller.
+pub fn unpause(env: &Env, scope: PauseScope, admin: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Scope(scope.clone()));
+    crate::events::scope_unpaused(env, scope, admin);
+}
+
+/// The pause in force on `scope`, or `None` if there is none or it expired.
+pub fn get(env: &Env, scope: &PauseScope) -> Option<PauseRecord> {
+    let key = DataKey::Scope(scope.clone());
+    let record: PauseRecord = env.storage().persistent().get(&key)?;
+    if record
+        .expires_at
+        .is_some_and(|t| t <= env.ledger().timestamp())
+    {
+        return None;
+    }
+    ttl::extend_persistent(env, &key);
+    Some(record)
+}
+
+/// Rejects the call if `module`, or the tokenized asset it acts on, is
+/// paused. The contract-wide pause is checked by the caller.
+pub fn require_active(env: &Env, module: Module, asset_id: Option<u64>) -> Result<(), Error> {
+    if get(env, &PauseScope::Module(module)).is_some() {
+        return Err(Error::ContractPaused);
+    }
+    if let Some(asset_id) = asset_id {
+        if get(env, &PauseScope::Asset(asset_id)).is_some() {
+            return Err(Error::ContractPaused);
+        }
+    }
+    Ok(())
+}
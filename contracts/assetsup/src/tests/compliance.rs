@@ -0,0 +1,206 @@
+//! Compliance freeze and forced transfer tests.
+//!
+//! The properties that matter: **a frozen holder can do nothing with the
+//! asset**, and **a forced transfer always leaves its reason in the audit
+//! log**, whatever locks it had to override.
+
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, TryIntoVal};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::tokenization::asset_id_to_bytes;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+struct Setup<'a> {
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+    holder: Address,
+    officer: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "REG"),
+        &10_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Regulated"),
+        &String::from_str(env, "Compliance test asset"),
+        &AssetType::Digital,
+        &None,
+    );
+
+    let holder = Address::generate(env);
+    client.transfer_tokens(&1u64, &tokenizer, &holder, &1_000i128);
+
+    let officer = Address::generate(env);
+    client.add_compliance_officer(&officer);
+
+    Setup {
+        client,
+        tokenizer,
+        holder,
+        officer,
+    }
+}
+
+#[test]
+fn a_frozen_holder_cannot_send_or_receive() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.freeze_holder(&1u64, &s.holder, &s.officer);
+    assert!(s.client.is_holder_frozen(&1u64, &s.holder));
+
+    let res = s
+        .client
+        .try_transfer_tokens(&1u64, &s.holder, &s.tokenizer, &1i128);
+    assert_eq!(res, Err(Ok(Error::HolderFrozen)));
+    let res = s
+        .client
+        .try_transfer_tokens(&1u64, &s.tokenizer, &s.holder, &1i128);
+    assert_eq!(res, Err(Ok(Error::HolderFrozen)));
+
+    s.client.unfreeze_holder(&1u64, &s.holder, &s.officer);
+    s.client
+        .transfer_tokens(&1u64, &s.holder, &s.tokenizer, &1i128);
+}
+
+#[test]
+fn a_frozen_holder_cannot_vote_or_claim_dividends() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.enable_revenue_sharing(&1u64);
+    s.client.distribute_dividends(&1u64, &10_000i128);
+    s.client.freeze_holder(&1u64, &s.holder, &s.officer);
+
+    let res = s.client.try_cast_vote(&1u64, &1u64, &s.holder);
+    assert_eq!(res, Err(Ok(Error::HolderFrozen)));
+    let res = s.client.try_claim_dividends(&1u64, &s.holder);
+    assert_eq!(res, Err(Ok(Error::HolderFrozen)));
+
+    // The dividends are withheld, not forfeited.
+    s.client.unfreeze_holder(&1u64, &s.holder, &s.officer);
+    assert_eq!(s.client.claim_dividends(&1u64, &s.holder), 1_000);
+}
+
+#[test]
+fn a_freeze_is_per_asset() {
+    let env = create_env();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.try_freeze_holder(&2u64, &s.holder, &s.officer),
+        Err(Ok(Error::AssetNotTokenized))
+    );
+
+    s.client.freeze_holder(&1u64, &s.holder, &s.officer);
+    assert!(!s.client.is_holder_frozen(&2u64, &s.holder));
+}
+
+#[test]
+fn only_officers_can_freeze_or_force_transfer() {
+    let env = create_env();
+    let s = setup(&env);
+    let reason = BytesN::from_array(&env, &[7; 32]);
+
+    // The tokenizer has no compliance powers of its own.
+    let res = s.client.try_freeze_holder(&1u64, &s.holder, &s.tokenizer);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    let res = s.client.try_force_transfer(
+        &1u64,
+        &s.holder,
+        &s.tokenizer,
+        &1i128,
+        &reason,
+        &s.tokenizer,
+    );
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    s.client.remove_compliance_officer(&s.officer);
+    let res = s.client.try_freeze_holder(&1u64, &s.holder, &s.officer);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn force_transfer_overrides_freezes_and_locks() {
+    let env = create_env();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let reason = BytesN::from_array(&env, &[0xab; 32]);
+
+    s.client.freeze_holder(&1u64, &s.holder, &s.officer);
+    s.client.lock_tokens(
+        &1u64,
+        &s.holder,
+        &(env.ledger().timestamp() + 1_000),
+        &s.tokenizer,
+    );
+
+    s.client.force_transfer(
+        &1u64, &s.holder, &recipient, &1_000i128, &reason, &s.officer,
+    );
+    assert_eq!(s.client.get_token_balance(&1u64, &s.holder), 0);
+    assert_eq!(s.client.get_token_balance(&1u64, &recipient), 1_000);
+}
+
+#[test]
+fn force_transfer_records_its_reason() {
+    let env = create_env();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    let reason = BytesN::from_array(&env, &[0xab; 32]);
+
+    s.client
+        .force_transfer(&1u64, &s.holder, &recipient, &400i128, &reason, &s.officer);
+
+    // A distinct event, not a TokensTransferred.
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(name, Symbol::new(&env, "tokens_force_transferred"));
+
+    let log = s.client.get_asset_audit_logs(&asset_id_to_bytes(&env, 1));
+    let entry = log.last().unwrap();
+    assert_eq!(
+        entry.action,
+        String::from_str(&env, "TOKENS_FORCE_TRANSFERRED")
+    );
+    assert_eq!(entry.actor, s.officer);
+    let mut expected = std::string::String::from("reason:");
+    expected.push_str(&"ab".repeat(32));
+    assert_eq!(entry.details, String::from_str(&env, &expected));
+}
+
+#[test]
+fn force_transfer_still_cannot_overdraw() {
+    let env = create_env();
+    let s = setup(&env);
+    let reason = BytesN::from_array(&env, &[1; 32]);
+
+    let res = s.client.try_force_transfer(
+        &1u64,
+        &s.holder,
+        &s.tokenizer,
+        &1_001i128,
+        &reason,
+        &s.officer,
+    );
+    assert_eq!(res, Err(Ok(Error::InsufficientBalance)));
+}
+
+#[test]
+fn unfreezing_a_holder_who_is_not_frozen_fails() {
+    let env = create_env();
+    let s = setup(&env);
+
+    let res = s.client.try_unfreeze_holder(&1u64, &s.holder, &s.officer);
+    assert_eq!(res, Err(Ok(Error::NotFound)));
+}
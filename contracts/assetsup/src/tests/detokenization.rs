@@ -10,6 +10,7 @@ fn test_propose_detokenization_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -20,6 +21,7 @@ fn test_propose_detokenization_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Propose detokenization
@@ -40,6 +42,7 @@ fn test_propose_detokenization_already_proposed() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -50,6 +53,7 @@ fn test_propose_detokenization_already_proposed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.propose_detokenization(&1u64, &user1);
@@ -79,6 +83,7 @@ fn test_execute_detokenization_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -89,6 +94,7 @@ fn test_execute_detokenization_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 60% to user2
@@ -116,6 +122,7 @@ fn test_execute_detokenization_not_approved() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -126,6 +133,7 @@ fn test_execute_detokenization_not_approved() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 30% to user2 (not enough for majority)
@@ -150,6 +158,7 @@ fn test_execute_detokenization_no_proposal() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -160,6 +169,7 @@ fn test_execute_detokenization_no_proposal() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InvalidProposal error
@@ -174,6 +184,7 @@ fn test_get_detokenization_proposal() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -184,6 +195,7 @@ fn test_get_detokenization_proposal() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let proposal_id = client.propose_detokenization(&1u64, &user1);
@@ -207,6 +219,7 @@ fn test_detokenization_clears_all_data() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -217,6 +230,7 @@ fn test_detokenization_clears_all_data() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set up some data
@@ -30,6 +30,7 @@ fn setup_tokenized_asset(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -10,6 +10,7 @@ fn test_enable_revenue_sharing() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -20,6 +21,7 @@ fn test_enable_revenue_sharing() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially disabled
@@ -41,6 +43,7 @@ fn test_disable_revenue_sharing() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -51,6 +54,7 @@ fn test_disable_revenue_sharing() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.enable_revenue_sharing(&1u64);
@@ -68,6 +72,7 @@ fn test_distribute_dividends_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -78,6 +83,7 @@ fn test_distribute_dividends_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Enable revenue sharing
@@ -106,6 +112,7 @@ fn test_distribute_dividends_invalid_amount() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -116,6 +123,7 @@ fn test_distribute_dividends_invalid_amount() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.enable_revenue_sharing(&1u64);
@@ -133,6 +141,7 @@ fn test_distribute_dividends_not_enabled() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -143,6 +152,7 @@ fn test_distribute_dividends_not_enabled() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Revenue sharing not enabled - should panic with InvalidDividendAmount
@@ -157,6 +167,7 @@ fn test_claim_dividends_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -167,6 +178,7 @@ fn test_claim_dividends_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.enable_revenue_sharing(&1u64);
@@ -191,6 +203,7 @@ fn test_claim_dividends_none_to_claim() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -201,6 +214,7 @@ fn test_claim_dividends_none_to_claim() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with NoDividendsToClaim error
@@ -215,6 +229,7 @@ fn test_multiple_dividend_distributions() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -225,6 +240,7 @@ fn test_multiple_dividend_distributions() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.enable_revenue_sharing(&1u64);
@@ -0,0 +1,294 @@
+//! Transfer fee tests.
+//!
+//! The property that matters: **a fee only redistributes value**. Share fees
+//! move shares from the sender to the treasury and tokenizer without changing
+//! the supply, and token fees move tokens without touching the shares.
+
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Map, String, Symbol, TryIntoVal, Val};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+    treasury: Address,
+    /// An investor holding 1,000 of the 10,000 shares.
+    investor: Address,
+}
+
+impl Setup<'_> {
+    fn balance(&self, holder: &Address) -> i128 {
+        self.client.get_token_balance(&1u64, holder)
+    }
+
+    /// 1% with a minimum of 5, a fifth of it to the tokenizer.
+    fn share_fee(&self) {
+        self.client
+            .set_transfer_fee(&1u64, &100u32, &5i128, &None, &self.treasury, &2_000u32);
+    }
+
+    /// `(amount, fee)` of the last `tokens_transferred` event.
+    fn last_transfer_event(&self) -> (i128, i128) {
+        let env = self.env;
+        let name = Symbol::new(env, "tokens_transferred");
+        for (_, topics, data) in env.events().all().iter() {
+            let topic: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
+            if topic == name {
+                let data: Map<Symbol, Val> = data.try_into_val(env).unwrap();
+                let field = |f: &str| -> i128 {
+                    data.get(Symbol::new(env, f))
+                        .unwrap()
+                        .try_into_val(env)
+                        .unwrap()
+                };
+                return (field("amount"), field("fee"));
+            }
+        }
+        panic!("no tokens_transferred event");
+    }
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "FEE"),
+        &10_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Fee bearing"),
+        &String::from_str(env, "Transfer fee test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+    let investor = Address::generate(env);
+    client.transfer_tokens(&1u64, &tokenizer, &investor, &1_000i128);
+
+    Setup {
+        env,
+        client,
+        tokenizer,
+        treasury: Address::generate(env),
+        investor,
+    }
+}
+
+#[test]
+fn a_share_fee_is_withheld_and_split() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    s.share_fee();
+
+    s.client
+        .transfer_tokens(&1u64, &s.investor, &buyer, &1_000i128);
+
+    assert_eq!(s.last_transfer_event(), (1_000, 10));
+    assert_eq!(s.balance(&s.investor), 0);
+    assert_eq!(s.balance(&buyer), 990);
+    assert_eq!(s.balance(&s.treasury), 8);
+    assert_eq!(s.balance(&s.tokenizer), 9_000 + 2);
+    assert_eq!(s.client.get_tokenized_asset(&1u64).total_supply, 10_000);
+}
+
+#[test]
+fn the_minimum_fee_applies_to_small_transfers() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    s.share_fee();
+
+    s.client
+        .transfer_tokens(&1u64, &s.investor, &buyer, &100i128);
+    assert_eq!(s.balance(&buyer), 95);
+
+    // A transfer the fee would swallow whole is refused.
+    assert_eq!(
+        s.client
+            .try_transfer_tokens(&1u64, &s.investor, &buyer, &5i128),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn a_token_fee_is_paid_on_top_at_the_current_valuation() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let usdc = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&s.investor, &1_000);
+
+    // 100 per share, so 100 shares are worth 10,000 and 1% of that is 100.
+    s.client.update_valuation(&1u64, &1_000_000i128);
+    s.client.set_transfer_fee(
+        &1u64,
+        &100u32,
+        &5i128,
+        &Some(sac.address()),
+        &s.treasury,
+        &2_000u32,
+    );
+
+    s.client
+        .transfer_tokens(&1u64, &s.investor, &buyer, &100i128);
+
+    assert_eq!(s.last_transfer_event(), (100, 100));
+    assert_eq!(s.balance(&buyer), 100);
+    assert_eq!(usdc.balance(&s.investor), 900);
+    assert_eq!(usdc.balance(&s.treasury), 80);
+    assert_eq!(usdc.balance(&s.tokenizer), 20);
+}
+
+#[test]
+fn exempt_parties_and_the_tokenizer_pay_nothing() {
+    let env = create_env();
+    let s = setup(&env);
+    let (exempt, other) = (Address::generate(&env), Address::generate(&env));
+    s.share_fee();
+    s.client.set_fee_exempt(&1u64, &exempt, &true);
+    assert!(s.client.is_fee_exempt(&1u64, &exempt));
+
+    s.client
+        .transfer_tokens(&1u64, &s.investor, &exempt, &100i128);
+    assert_eq!(s.balance(&exempt), 100);
+
+    s.client
+        .transfer_tokens(&1u64, &s.tokenizer, &other, &100i128);
+    assert_eq!(s.last_transfer_event(), (100, 0));
+    assert_eq!(s.balance(&other), 100);
+
+    s.client.set_fee_exempt(&1u64, &exempt, &false);
+    s.client.transfer_tokens(&1u64, &exempt, &other, &100i128);
+    assert_eq!(s.balance(&other), 195);
+}
+
+#[test]
+fn clearing_the_fee_stops_charging() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    s.share_fee();
+    assert!(s.client.get_transfer_fee(&1u64).is_some());
+
+    s.client.clear_transfer_fee(&1u64);
+
+    assert_eq!(s.client.get_transfer_fee(&1u64), None);
+    s.client
+        .transfer_tokens(&1u64, &s.investor, &buyer, &100i128);
+    assert_eq!(s.balance(&buyer), 100);
+}
+
+#[test]
+fn only_the_admin_sets_a_valid_fee() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_transfer_fee(&1u64, &10_001u32, &0i128, &None, &s.treasury, &0u32),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        s.client
+            .try_set_transfer_fee(&1u64, &100u32, &-1i128, &None, &s.treasury, &0u32),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    env.set_auths(&[]);
+    assert!(s
+        .client
+        .try_set_transfer_fee(&1u64, &100u32, &0i128, &None, &s.treasury, &0u32)
+        .is_err());
+    assert_eq!(s.client.get_transfer_fee(&1u64), None);
+}
+
+#[test]
+fn share_fee_payees_must_be_able_to_receive_shares() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    s.share_fee();
+
+    // A whitelist without the treasury refuses its part of the fee.
+    for address in [&s.tokenizer, &s.investor, &buyer] {
+        s.client.add_to_whitelist(&1u64, address);
+    }
+    assert_eq!(
+        s.client
+            .try_transfer_tokens(&1u64, &s.investor, &buyer, &100i128),
+        Err(Ok(Error::TransferRestrictionFailed))
+    );
+
+    // So does a frozen treasury.
+    s.client.add_to_whitelist(&1u64, &s.treasury);
+    let officer = Address::generate(&env);
+    s.client.add_compliance_officer(&officer);
+    s.client.freeze_holder(&1u64, &s.treasury, &officer);
+    assert_eq!(
+        s.client
+            .try_transfer_tokens(&1u64, &s.investor, &buyer, &100i128),
+        Err(Ok(Error::HolderFrozen))
+    );
+    assert_eq!(s.balance(&s.investor), 1_000);
+}
+
+#[test]
+fn can_transfer_includes_the_fee() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+
+    s.share_fee();
+    assert_eq!(
+        s.client.can_transfer(&1u64, &s.investor, &buyer, &100i128),
+        95
+    );
+    assert_eq!(
+        s.client
+            .try_can_transfer(&1u64, &s.investor, &buyer, &5i128),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A token fee the sender cannot cover fails the dry run and the transfer
+    // alike.
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &sac.address()).mint(&s.investor, &4);
+    s.client.set_transfer_fee(
+        &1u64,
+        &100u32,
+        &5i128,
+        &Some(sac.address()),
+        &s.treasury,
+        &0u32,
+    );
+    assert_eq!(
+        s.client
+            .try_can_transfer(&1u64, &s.investor, &buyer, &100i128),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client
+            .try_transfer_tokens(&1u64, &s.investor, &buyer, &100i128),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    StellarAssetClient::new(&env, &sac.address()).mint(&s.investor, &1);
+    assert_eq!(
+        s.client.can_transfer(&1u64, &s.investor, &buyer, &100i128),
+        100
+    );
+    assert_eq!(s.balance(&buyer), 0);
+}
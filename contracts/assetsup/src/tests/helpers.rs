@@ -4,7 +4,8 @@ use crate::insurance::{
 };
 use crate::types::{AssetStatus, AssetType, CustomAttribute, TokenMetadata};
 use crate::{AssetUpContract, AssetUpContractClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
+use soroban_sdk::testutils::{Address as _, BytesN as _};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 /// Create a fresh test environment
 pub fn create_env() -> Env {
@@ -155,3 +156,20 @@ pub fn create_test_claim(
         approved_amount: 0,
     }
 }
+
+/// Registers a registry asset owned by `owner` and links it to `asset_id`, as
+/// `tokenize_asset` requires
+pub fn registry_link(
+    env: &Env,
+    client: &AssetUpContractClient,
+    asset_id: u64,
+    owner: &Address,
+) -> BytesN<32> {
+    let registry_id = BytesN::random(env);
+    client.register_asset(
+        &create_test_asset(env, owner, registry_id.clone()),
+        &client.get_admin(),
+    );
+    client.link_registry_asset(&asset_id, &registry_id, owner);
+    registry_id
+}
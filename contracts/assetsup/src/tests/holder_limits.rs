@@ -0,0 +1,198 @@
+//! Holder limit tests.
+//!
+//! The property that matters: **a transfer is judged by the state it would
+//! leave behind**, and only investors count: the tokenizer's unissued shares
+//! and the contract's escrow are neither holders nor concentrated positions.
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::{AssetType, HolderLimits};
+use crate::AssetUpContractClient;
+
+struct Setup<'a> {
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+}
+
+impl Setup<'_> {
+    fn limit(&self, max_holders: u32, max_ownership_bps: u32, min_holding: i128) {
+        self.client
+            .set_holder_limits(&1u64, &max_holders, &max_ownership_bps, &min_holding);
+    }
+
+    fn send(&self, to: &Address, amount: i128) -> Result<(), Error> {
+        match self
+            .client
+            .try_transfer_tokens(&1u64, &self.tokenizer, to, &amount)
+        {
+            Ok(_) => Ok(()),
+            Err(Ok(e)) => Err(e),
+            Err(Err(_)) => panic!("transfer failed outside the contract"),
+        }
+    }
+}
+
+/// 1,000 shares, all held by the tokenizer.
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "CAP"),
+        &1_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Private placement"),
+        &String::from_str(env, "Holder limit test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    Setup { client, tokenizer }
+}
+
+#[test]
+fn limits_default_to_off() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_holder_limits(&1u64), HolderLimits::default());
+    s.send(&Address::generate(&env), 1).unwrap();
+}
+
+#[test]
+fn the_holder_count_cap_admits_a_newcomer_only_when_someone_leaves() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b, c) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    s.limit(2, 0, 0);
+
+    s.send(&a, 100).unwrap();
+    s.send(&b, 100).unwrap();
+    // The tokenizer still holds shares but is not counted.
+    assert_eq!(s.send(&c, 100), Err(Error::MaxHoldersExceeded));
+    // Existing holders can still receive more.
+    s.send(&a, 100).unwrap();
+
+    // A partial sale to a newcomer would make three holders.
+    assert_eq!(
+        s.client.try_transfer_tokens(&1u64, &a, &c, &100i128),
+        Err(Ok(Error::MaxHoldersExceeded))
+    );
+    // Selling out makes room.
+    s.client.transfer_tokens(&1u64, &a, &c, &200i128);
+    assert_eq!(s.client.get_token_balance(&1u64, &c), 200);
+}
+
+#[test]
+fn no_investor_may_pass_the_ownership_cap() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    s.limit(0, 2_000, 0);
+
+    s.send(&a, 200).unwrap();
+    assert_eq!(s.send(&a, 1), Err(Error::OwnershipCapExceeded));
+
+    // Minting grows the supply, so the cap is measured against the new one:
+    // 250 of 1,250 is exactly 20%.
+    s.client.mint_to(&1u64, &b, &250i128);
+    assert_eq!(
+        s.client.try_mint_to(&1u64, &b, &1i128),
+        Err(Ok(Error::OwnershipCapExceeded))
+    );
+}
+
+#[test]
+fn no_investor_may_be_left_below_the_minimum_holding() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    s.limit(0, 0, 50);
+
+    assert_eq!(s.send(&a, 49), Err(Error::BelowMinimumHolding));
+    s.send(&a, 100).unwrap();
+
+    assert_eq!(
+        s.client.try_transfer_tokens(&1u64, &a, &b, &60i128),
+        Err(Ok(Error::BelowMinimumHolding))
+    );
+    // Selling the whole position is always allowed.
+    s.client.transfer_tokens(&1u64, &a, &b, &100i128);
+    assert_eq!(s.client.get_token_balance(&1u64, &a), 0);
+}
+
+#[test]
+fn the_market_escrow_is_not_a_holder() {
+    let env = create_env();
+    let s = setup(&env);
+    let (seller, buyer) = (Address::generate(&env), Address::generate(&env));
+    s.send(&seller, 100).unwrap();
+    s.limit(2, 0, 0);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &sac.address()).mint(&buyer, &1_000);
+    let id = s
+        .client
+        .create_listing(&1u64, &seller, &50i128, &sac.address(), &1i128, &10_000u64);
+
+    // Tokenizer, seller and escrow all hold shares; only the seller counts.
+    s.client.buy_listing(&1u64, &id, &buyer, &10i128);
+    assert_eq!(s.client.get_token_balance(&1u64, &buyer), 10);
+}
+
+#[test]
+fn can_transfer_reports_the_error_a_transfer_would_return() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    s.send(&a, 100).unwrap();
+    s.limit(0, 0, 50);
+
+    s.client.can_transfer(&1u64, &a, &b, &100i128);
+    let check = |to: &Address, amount: i128| s.client.try_can_transfer(&1u64, &a, to, &amount);
+    assert_eq!(check(&b, 60), Err(Ok(Error::BelowMinimumHolding)));
+    assert_eq!(check(&b, 101), Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(check(&a, 100), Err(Ok(Error::InvalidInput)));
+    assert_eq!(check(&b, 0), Err(Ok(Error::InvalidTokenSupply)));
+
+    // Nothing moved.
+    assert_eq!(s.client.get_token_balance(&1u64, &a), 100);
+    assert_eq!(s.client.get_token_balance(&1u64, &b), 0);
+}
+
+#[test]
+fn only_the_tokenizer_sets_valid_limits() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_set_holder_limits(&1u64, &0u32, &10_001u32, &0i128),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        s.client.try_set_holder_limits(&1u64, &0u32, &0u32, &-1i128),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    env.set_auths(&[]);
+    assert!(s
+        .client
+        .try_set_holder_limits(&1u64, &10u32, &0u32, &0i128)
+        .is_err());
+    assert_eq!(s.client.get_holder_limits(&1u64), HolderLimits::default());
+}
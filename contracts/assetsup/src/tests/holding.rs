@@ -0,0 +1,207 @@
+//! Holding period tests.
+//!
+//! The property that matters: **shares cannot move until their own lot is old
+//! enough**, while shares outside any lot stay free.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+const PERIOD: u64 = 1_000;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+}
+
+impl Setup<'_> {
+    fn send(&self, to: &Address, amount: i128) {
+        self.client
+            .transfer_tokens(&1u64, &self.tokenizer, to, &amount);
+    }
+
+    fn at(&self, t: u64) {
+        self.env.ledger().with_mut(|l| l.timestamp = t);
+    }
+
+    fn transferable(&self, holder: &Address) -> i128 {
+        self.client
+            .get_transferable_balance(&1u64, holder)
+            .transferable
+    }
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "HOLD"),
+        &1_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Held"),
+        &String::from_str(env, "Holding period test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    Setup {
+        env,
+        client,
+        tokenizer,
+    }
+}
+
+#[test]
+fn received_shares_wait_out_the_holding_period() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    s.client.set_holding_period(&1u64, &PERIOD);
+    s.at(100);
+
+    s.send(&a, 100);
+
+    let status = s.client.get_transferable_balance(&1u64, &a);
+    assert_eq!(status.balance, 100);
+    assert_eq!(status.in_holding_period, 100);
+    assert_eq!(status.restricted, 100);
+    assert_eq!(status.transferable, 0);
+    assert_eq!(status.next_release, Some(100 + PERIOD));
+    assert_eq!(
+        s.client.try_transfer_tokens(&1u64, &a, &b, &1i128),
+        Err(Ok(Error::TokensAreLocked))
+    );
+
+    s.at(100 + PERIOD);
+    assert_eq!(s.transferable(&a), 100);
+    assert_eq!(
+        s.client.get_transferable_balance(&1u64, &a).next_release,
+        None
+    );
+    s.client.transfer_tokens(&1u64, &a, &b, &100i128);
+}
+
+#[test]
+fn each_lot_is_released_on_its_own_date() {
+    let env = create_env();
+    let s = setup(&env);
+    let a = Address::generate(&env);
+    s.client.set_holding_period(&1u64, &PERIOD);
+
+    s.at(0);
+    s.send(&a, 100);
+    s.at(500);
+    s.send(&a, 60);
+
+    s.at(PERIOD);
+    let status = s.client.get_transferable_balance(&1u64, &a);
+    assert_eq!(status.transferable, 100);
+    assert_eq!(status.in_holding_period, 60);
+    assert_eq!(status.next_release, Some(500 + PERIOD));
+}
+
+#[test]
+fn shares_outside_any_lot_stay_free() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    s.send(&a, 100);
+
+    s.client.set_holding_period(&1u64, &PERIOD);
+    s.send(&a, 50);
+
+    assert_eq!(s.transferable(&a), 100);
+    assert_eq!(
+        s.client.try_transfer_tokens(&1u64, &a, &b, &101i128),
+        Err(Ok(Error::TokensAreLocked))
+    );
+    // Spending the free shares leaves the lot in place.
+    s.client.transfer_tokens(&1u64, &a, &b, &100i128);
+    assert_eq!(
+        s.client
+            .get_transferable_balance(&1u64, &a)
+            .in_holding_period,
+        50
+    );
+}
+
+#[test]
+fn minted_shares_start_a_lot_but_the_tokenizer_never_holds_one() {
+    let env = create_env();
+    let s = setup(&env);
+    let a = Address::generate(&env);
+    s.client.set_holding_period(&1u64, &PERIOD);
+
+    s.client.mint_to(&1u64, &a, &40i128);
+    assert_eq!(s.transferable(&a), 0);
+
+    s.at(PERIOD);
+    s.client.transfer_tokens(&1u64, &a, &s.tokenizer, &40i128);
+    // The tokenizer can pass the shares straight on.
+    s.send(&Address::generate(&env), 1_000);
+}
+
+#[test]
+fn a_forced_transfer_consumes_the_oldest_lots() {
+    let env = create_env();
+    let s = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let officer = Address::generate(&env);
+    s.client.add_compliance_officer(&officer);
+    s.client.set_holding_period(&1u64, &PERIOD);
+
+    s.at(0);
+    s.send(&a, 100);
+    s.at(500);
+    s.send(&a, 50);
+
+    s.client.force_transfer(
+        &1u64,
+        &a,
+        &b,
+        &120i128,
+        &BytesN::from_array(&env, &[0; 32]),
+        &officer,
+    );
+
+    let status = s.client.get_transferable_balance(&1u64, &a);
+    assert_eq!(status.balance, 30);
+    assert_eq!(status.in_holding_period, 30);
+    assert_eq!(status.next_release, Some(500 + PERIOD));
+}
+
+#[test]
+fn removing_the_period_frees_every_lot() {
+    let env = create_env();
+    let s = setup(&env);
+    let a = Address::generate(&env);
+    s.client.set_holding_period(&1u64, &PERIOD);
+    s.send(&a, 100);
+
+    s.client.set_holding_period(&1u64, &0u64);
+
+    assert_eq!(s.client.get_holding_period(&1u64), 0);
+    assert_eq!(s.transferable(&a), 100);
+}
+
+#[test]
+fn only_the_tokenizer_sets_the_period() {
+    let env = create_env();
+    let s = setup(&env);
+
+    env.set_auths(&[]);
+    assert!(s.client.try_set_holding_period(&1u64, &PERIOD).is_err());
+    assert_eq!(s.client.get_holding_period(&1u64), 0);
+}
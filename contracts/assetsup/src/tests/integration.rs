@@ -44,6 +44,7 @@ fn test_full_tokenization_workflow() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -160,6 +161,7 @@ fn test_multiple_dividend_distributions() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -218,6 +220,7 @@ fn test_locked_tokens_with_voting() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -17,6 +17,7 @@ fn test_full_asset_tokenization_workflow() {
 
     // Step 2: Tokenize asset
     let asset_id = 1u64;
+    registry_link(&env, &client, asset_id, &owner);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(&env, "PROP"),
@@ -27,6 +28,7 @@ fn test_full_asset_tokenization_workflow() {
         &String::from_str(&env, "Property Token"),
         &String::from_str(&env, "Tokenized real estate"),
         &AssetType::Physical,
+        &None,
     );
 
     // Step 3: Distribute tokens to investors
@@ -63,6 +65,7 @@ fn test_governance_and_detokenization_workflow() {
 
     // Setup: Tokenize asset
     let asset_id = 1u64;
+    registry_link(&env, &client, asset_id, &owner);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(&env, "GOV"),
@@ -73,6 +76,7 @@ fn test_governance_and_detokenization_workflow() {
         &String::from_str(&env, "Governance Token"),
         &String::from_str(&env, "Token with voting"),
         &AssetType::Physical,
+        &None,
     );
 
     // Distribute tokens
@@ -105,6 +109,7 @@ fn test_transfer_restrictions_workflow() {
 
     // Setup: Tokenize asset
     let asset_id = 1u64;
+    registry_link(&env, &client, asset_id, &owner);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(&env, "REST"),
@@ -115,6 +120,7 @@ fn test_transfer_restrictions_workflow() {
         &String::from_str(&env, "Restricted Token"),
         &String::from_str(&env, "Token with restrictions"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set transfer restrictions
@@ -165,6 +171,7 @@ fn test_token_locking_workflow() {
 
     // Setup: Tokenize and transfer
     let asset_id = 1u64;
+    registry_link(&env, &client, asset_id, &owner);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(&env, "LOCK"),
@@ -175,6 +182,7 @@ fn test_token_locking_workflow() {
         &String::from_str(&env, "Lockable Token"),
         &String::from_str(&env, "Token with locking"),
         &AssetType::Physical,
+        &None,
     );
 
     client.transfer_tokens(&asset_id, &owner, &investor, &500000i128);
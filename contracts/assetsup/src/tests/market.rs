@@ -0,0 +1,390 @@
+//! Secondary market tests.
+//!
+//! The property that matters: **a fill moves the payment and the shares
+//! together or not at all**, and escrowed shares only ever leave to a buyer
+//! or back to their seller.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+const EXPIRY: u64 = 5_000;
+/// Two decimals: 100 share units make one whole share.
+const ONE_SHARE: i128 = 100;
+const PRICE: i128 = 20;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: AssetUpContractClient<'a>,
+    seller: Address,
+    payment: TokenClient<'a>,
+}
+
+impl Setup<'_> {
+    fn buyer(&self, funds: i128) -> Address {
+        let buyer = Address::generate(self.env);
+        StellarAssetClient::new(self.env, &self.payment.address).mint(&buyer, &funds);
+        buyer
+    }
+
+    fn list(&self, amount: i128) -> u64 {
+        self.client.create_listing(
+            &1u64,
+            &self.seller,
+            &amount,
+            &self.payment.address,
+            &PRICE,
+            &EXPIRY,
+        )
+    }
+
+    fn escrowed(&self) -> i128 {
+        self.client.get_token_balance(&1u64, &self.client.address)
+    }
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let seller = Address::generate(env);
+    registry_link(env, &client, 1u64, &seller);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "MKT"),
+        &(1_000 * ONE_SHARE),
+        &2u32,
+        &1i128,
+        &seller,
+        &String::from_str(env, "Traded"),
+        &String::from_str(env, "Secondary market test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let payment = TokenClient::new(env, &sac.address());
+
+    Setup {
+        env,
+        client,
+        seller,
+        payment,
+    }
+}
+
+#[test]
+fn listing_escrows_the_shares() {
+    let env = create_env();
+    let s = setup(&env);
+
+    let id = s.list(100 * ONE_SHARE);
+
+    assert_eq!(s.escrowed(), 100 * ONE_SHARE);
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.seller),
+        900 * ONE_SHARE
+    );
+    let listing = s.client.get_listing(&1u64, &id).unwrap();
+    assert_eq!(listing.remaining, 100 * ONE_SHARE);
+    assert_eq!(s.client.get_listings(&1u64).len(), 1);
+}
+
+#[test]
+fn a_partial_fill_swaps_payment_for_shares() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(100 * ONE_SHARE);
+    let buyer = s.buyer(10_000);
+
+    // 2.5 shares at 20 each.
+    assert_eq!(s.client.buy_listing(&1u64, &id, &buyer, &250i128), 50);
+
+    assert_eq!(s.payment.balance(&buyer), 10_000 - 50);
+    assert_eq!(s.payment.balance(&s.seller), 50);
+    assert_eq!(s.client.get_token_balance(&1u64, &buyer), 250);
+    assert_eq!(s.escrowed(), 100 * ONE_SHARE - 250);
+    assert_eq!(
+        s.client.get_listing(&1u64, &id).unwrap().remaining,
+        100 * ONE_SHARE - 250
+    );
+}
+
+#[test]
+fn a_full_fill_closes_the_listing() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(10 * ONE_SHARE);
+    let buyer = s.buyer(10_000);
+
+    s.client.buy_listing(&1u64, &id, &buyer, &(10 * ONE_SHARE));
+
+    assert!(s.client.get_listing(&1u64, &id).is_none());
+    assert_eq!(s.client.get_listings(&1u64).len(), 0);
+    assert_eq!(s.escrowed(), 0);
+    assert_eq!(
+        s.client.try_buy_listing(&1u64, &id, &buyer, &1i128),
+        Err(Ok(Error::NotFound))
+    );
+}
+
+#[test]
+fn a_buyer_cannot_overfill_or_underpay() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(10 * ONE_SHARE);
+
+    let buyer = s.buyer(10_000);
+    let res = s
+        .client
+        .try_buy_listing(&1u64, &id, &buyer, &(10 * ONE_SHARE + 1));
+    assert_eq!(res, Err(Ok(Error::InvalidInput)));
+
+    // 10 shares cost 200; a buyer holding 199 cannot take them, and the
+    // shares stay in escrow.
+    let poor = s.buyer(199);
+    assert!(s
+        .client
+        .try_buy_listing(&1u64, &id, &poor, &(10 * ONE_SHARE))
+        .is_err());
+    assert_eq!(s.client.get_token_balance(&1u64, &poor), 0);
+    assert_eq!(s.escrowed(), 10 * ONE_SHARE);
+}
+
+#[test]
+fn transfer_restrictions_apply_to_the_buyer() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(10 * ONE_SHARE);
+    let approved = s.buyer(10_000);
+    let outsider = s.buyer(10_000);
+    s.client.add_to_whitelist(&1u64, &approved);
+
+    s.client.buy_listing(&1u64, &id, &approved, &ONE_SHARE);
+    let res = s.client.try_buy_listing(&1u64, &id, &outsider, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::TransferRestrictionFailed)));
+    assert_eq!(s.payment.balance(&outsider), 10_000);
+}
+
+#[test]
+fn a_frozen_seller_cannot_be_paid() {
+    let env = create_env();
+    let s = setup(&env);
+    let (investor, id) = investor_listing(&s, PRICE);
+    let buyer = s.buyer(10_000);
+    let officer = Address::generate(&env);
+    s.client.add_compliance_officer(&officer);
+    s.client.freeze_holder(&1u64, &investor, &officer);
+
+    let res = s.client.try_buy_listing(&1u64, &id, &buyer, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::HolderFrozen)));
+    assert_eq!(s.payment.balance(&buyer), 10_000);
+
+    s.client.unfreeze_holder(&1u64, &investor, &officer);
+    s.client.buy_listing(&1u64, &id, &buyer, &ONE_SHARE);
+    assert_eq!(s.payment.balance(&investor), PRICE);
+}
+
+/// Moves 10 shares from the tokenizer to a new investor, who lists all of
+/// them at `price`.
+fn investor_listing(s: &Setup, price: i128) -> (Address, u64) {
+    let investor = Address::generate(s.env);
+    s.client
+        .transfer_tokens(&1u64, &s.seller, &investor, &(10 * ONE_SHARE));
+    let id = s.client.create_listing(
+        &1u64,
+        &investor,
+        &(10 * ONE_SHARE),
+        &s.payment.address,
+        &price,
+        &EXPIRY,
+    );
+    (investor, id)
+}
+
+#[test]
+fn a_share_fee_on_a_fill_is_withheld_even_at_a_dust_price() {
+    let env = create_env();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    s.client
+        .set_transfer_fee(&1u64, &100u32, &5i128, &None, &treasury, &0u32);
+    let (investor, id) = investor_listing(&s, 1);
+    let buyer = s.buyer(10_000);
+
+    assert_eq!(
+        s.client.buy_listing(&1u64, &id, &buyer, &(10 * ONE_SHARE)),
+        10
+    );
+
+    assert_eq!(s.client.get_token_balance(&1u64, &buyer), 990);
+    assert_eq!(s.client.get_token_balance(&1u64, &treasury), 10);
+    assert_eq!(s.payment.balance(&investor), 10);
+    assert_eq!(s.escrowed(), 0);
+}
+
+#[test]
+fn a_token_fee_on_a_fill_comes_out_of_the_proceeds() {
+    let env = create_env();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    // One payment unit per share unit, so 10 shares are worth 1,000 and 1%
+    // of that is 10.
+    s.client.update_valuation(&1u64, &(1_000 * ONE_SHARE));
+    s.client.set_transfer_fee(
+        &1u64,
+        &100u32,
+        &5i128,
+        &Some(s.payment.address.clone()),
+        &treasury,
+        &0u32,
+    );
+    let (investor, id) = investor_listing(&s, PRICE);
+    let buyer = s.buyer(10_000);
+
+    assert_eq!(
+        s.client.buy_listing(&1u64, &id, &buyer, &(10 * ONE_SHARE)),
+        200
+    );
+
+    assert_eq!(s.client.get_token_balance(&1u64, &buyer), 10 * ONE_SHARE);
+    assert_eq!(s.payment.balance(&buyer), 10_000 - 200);
+    assert_eq!(s.payment.balance(&treasury), 10);
+    assert_eq!(s.payment.balance(&investor), 190);
+
+    // A listing priced below its fee, or in another token, cannot be filled.
+    let (_, dust) = investor_listing(&s, 1);
+    assert_eq!(
+        s.client.try_buy_listing(&1u64, &dust, &buyer, &ONE_SHARE),
+        Err(Ok(Error::InvalidPayment))
+    );
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    s.client.set_transfer_fee(
+        &1u64,
+        &100u32,
+        &5i128,
+        &Some(other.address()),
+        &treasury,
+        &0u32,
+    );
+    assert_eq!(
+        s.client
+            .try_buy_listing(&1u64, &dust, &buyer, &(10 * ONE_SHARE)),
+        Err(Ok(Error::InvalidPayment))
+    );
+}
+
+#[test]
+fn locked_shares_cannot_be_listed() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client
+        .lock_tokens(&1u64, &s.seller, &(EXPIRY + 1), &s.seller);
+
+    let res = s.client.try_create_listing(
+        &1u64,
+        &s.seller,
+        &ONE_SHARE,
+        &s.payment.address,
+        &PRICE,
+        &EXPIRY,
+    );
+    assert_eq!(res, Err(Ok(Error::TokensAreLocked)));
+}
+
+#[test]
+fn a_listing_cannot_leave_the_seller_below_the_minimum_holding() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client
+        .set_holder_limits(&1u64, &0u32, &0u32, &(5 * ONE_SHARE));
+    let investor = Address::generate(&env);
+    s.client
+        .transfer_tokens(&1u64, &s.seller, &investor, &(10 * ONE_SHARE));
+
+    let list = |amount: i128| {
+        s.client.try_create_listing(
+            &1u64,
+            &investor,
+            &amount,
+            &s.payment.address,
+            &PRICE,
+            &EXPIRY,
+        )
+    };
+    assert_eq!(list(6 * ONE_SHARE), Err(Ok(Error::BelowMinimumHolding)));
+    assert_eq!(s.escrowed(), 0);
+
+    // Keeping the minimum, or listing everything, is fine.
+    list(5 * ONE_SHARE).unwrap().unwrap();
+    list(5 * ONE_SHARE).unwrap().unwrap();
+    assert_eq!(s.client.get_token_balance(&1u64, &investor), 0);
+}
+
+#[test]
+fn only_the_seller_cancels_before_expiry() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(10 * ONE_SHARE);
+    let buyer = s.buyer(10_000);
+    s.client.buy_listing(&1u64, &id, &buyer, &ONE_SHARE);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        s.client.try_cancel_listing(&1u64, &id, &stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(
+        s.client.cancel_listing(&1u64, &id, &s.seller),
+        9 * ONE_SHARE
+    );
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.seller),
+        999 * ONE_SHARE
+    );
+    assert_eq!(s.escrowed(), 0);
+    assert!(s.client.get_listing(&1u64, &id).is_none());
+}
+
+#[test]
+fn an_expired_listing_cannot_be_bought_and_anyone_may_close_it() {
+    let env = create_env();
+    let s = setup(&env);
+    let id = s.list(10 * ONE_SHARE);
+    let buyer = s.buyer(10_000);
+
+    env.ledger().with_mut(|l| l.timestamp = EXPIRY);
+    assert_eq!(
+        s.client.try_buy_listing(&1u64, &id, &buyer, &ONE_SHARE),
+        Err(Ok(Error::InvalidSaleState))
+    );
+
+    let anyone = Address::generate(&env);
+    s.client.cancel_listing(&1u64, &id, &anyone);
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.seller),
+        1_000 * ONE_SHARE
+    );
+}
+
+#[test]
+fn listings_get_sequential_ids_per_asset() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(s.list(ONE_SHARE), 0);
+    assert_eq!(s.list(ONE_SHARE), 1);
+    s.client.cancel_listing(&1u64, &0u64, &s.seller);
+
+    let book = s.client.get_listings(&1u64);
+    assert_eq!(book.len(), 1);
+    assert_eq!(book.get(0).unwrap().listing_id, 1);
+    assert_eq!(s.list(ONE_SHARE), 2);
+}
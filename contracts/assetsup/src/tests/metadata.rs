@@ -0,0 +1,195 @@
+//! Token metadata revision tests.
+//!
+//! The property that matters: **every past version of the metadata stays
+//! readable**, so the documents in force at any moment can be established
+//! after the fact.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::metadata::MetadataField;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+const TOKENIZED_AT: u64 = 1_000;
+
+struct Setup<'a> {
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+    set_time(env, TOKENIZED_AT);
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "DOCS"),
+        &1_000i128,
+        &2u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Documented"),
+        &String::from_str(env, "Metadata test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    Setup { client, tokenizer }
+}
+
+fn set_time(env: &Env, t: u64) {
+    env.ledger().with_mut(|l| l.timestamp = t);
+}
+
+#[test]
+fn metadata_is_readable_and_starts_without_documents() {
+    let env = create_env();
+    let s = setup(&env);
+
+    let metadata = s.client.get_token_metadata(&1u64);
+    assert_eq!(metadata.name, String::from_str(&env, "Documented"));
+    assert_eq!(metadata.legal_docs_hash, None);
+    assert_eq!(s.client.get_metadata_revision_count(&1u64), 0);
+}
+
+#[test]
+fn each_update_records_a_revision() {
+    let env = create_env();
+    let s = setup(&env);
+    let legal = BytesN::from_array(&env, &[1; 32]);
+
+    set_time(&env, 2_000);
+    assert_eq!(
+        s.client.update_legal_docs_hash(&1u64, &Some(legal.clone())),
+        1
+    );
+    set_time(&env, 3_000);
+    assert_eq!(
+        s.client
+            .update_token_ipfs_uri(&1u64, &Some(String::from_str(&env, "ipfs://doc"))),
+        2
+    );
+
+    assert_eq!(s.client.get_metadata_revision_count(&1u64), 3);
+    let initial = s.client.get_metadata_revision(&1u64, &0u32).unwrap();
+    assert_eq!(initial.field, MetadataField::Initial);
+    assert_eq!(initial.updated_at, TOKENIZED_AT);
+    assert_eq!(initial.metadata.legal_docs_hash, None);
+
+    let first = s.client.get_metadata_revision(&1u64, &1u32).unwrap();
+    assert_eq!(first.field, MetadataField::LegalDocsHash);
+    assert_eq!(first.updated_at, 2_000);
+    assert_eq!(first.updated_by, s.tokenizer);
+    assert_eq!(first.metadata.legal_docs_hash, Some(legal.clone()));
+    assert_eq!(first.metadata.ipfs_uri, None);
+
+    let current = s.client.get_token_metadata(&1u64);
+    assert_eq!(current.legal_docs_hash, Some(legal));
+    assert_eq!(current.ipfs_uri, Some(String::from_str(&env, "ipfs://doc")));
+}
+
+#[test]
+fn the_documents_in_force_at_any_past_time_can_be_read() {
+    let env = create_env();
+    let s = setup(&env);
+    let v1 = BytesN::from_array(&env, &[1; 32]);
+    let v2 = BytesN::from_array(&env, &[2; 32]);
+
+    set_time(&env, 2_000);
+    s.client.update_legal_docs_hash(&1u64, &Some(v1.clone()));
+    set_time(&env, 3_000);
+    s.client.update_legal_docs_hash(&1u64, &Some(v2.clone()));
+
+    let at = |t: u64| s.client.get_token_metadata_at(&1u64, &t).legal_docs_hash;
+    assert_eq!(at(TOKENIZED_AT), None);
+    assert_eq!(at(1_999), None);
+    assert_eq!(at(2_000), Some(v1.clone()));
+    assert_eq!(at(2_999), Some(v1));
+    assert_eq!(at(3_000), Some(v2.clone()));
+    assert_eq!(at(u64::MAX), Some(v2));
+
+    assert_eq!(
+        s.client
+            .try_get_token_metadata_at(&1u64, &(TOKENIZED_AT - 1)),
+        Err(Ok(Error::NotFound))
+    );
+}
+
+#[test]
+fn an_unedited_asset_reports_its_current_metadata_since_tokenization() {
+    let env = create_env();
+    let s = setup(&env);
+
+    let metadata = s.client.get_token_metadata_at(&1u64, &TOKENIZED_AT);
+    assert_eq!(metadata, s.client.get_token_metadata(&1u64));
+    assert_eq!(
+        s.client
+            .try_get_token_metadata_at(&1u64, &(TOKENIZED_AT - 1)),
+        Err(Ok(Error::NotFound))
+    );
+}
+
+#[test]
+fn every_field_has_an_update() {
+    let env = create_env();
+    let s = setup(&env);
+    let mut countries = Vec::new(&env);
+    countries.push_back(String::from_str(&env, "US"));
+
+    s.client
+        .update_token_name(&1u64, &String::from_str(&env, "Renamed"));
+    s.client
+        .update_token_description(&1u64, &String::from_str(&env, "New"));
+    s.client.update_token_asset_type(&1u64, &AssetType::Digital);
+    s.client
+        .update_valuation_report_hash(&1u64, &Some(BytesN::from_array(&env, &[9; 32])));
+    s.client.update_accredited_required(&1u64, &true);
+    s.client.update_geographic_restrictions(&1u64, &countries);
+
+    let metadata = s.client.get_token_metadata(&1u64);
+    assert_eq!(metadata.name, String::from_str(&env, "Renamed"));
+    assert_eq!(metadata.description, String::from_str(&env, "New"));
+    assert_eq!(metadata.asset_type, AssetType::Digital);
+    assert!(metadata.valuation_report_hash.is_some());
+    assert!(metadata.accredited_investor_required);
+    assert_eq!(metadata.geographic_restrictions, countries);
+    assert_eq!(s.client.get_metadata_revision_count(&1u64), 7);
+
+    // The SEP-41 name follows the metadata.
+    assert_eq!(
+        s.client.get_sep41_metadata(&1u64).1,
+        String::from_str(&env, "Renamed")
+    );
+}
+
+#[test]
+fn only_the_tokenizer_may_update() {
+    let env = create_env();
+    let s = setup(&env);
+
+    env.set_auths(&[]);
+    assert!(s
+        .client
+        .try_update_legal_docs_hash(&1u64, &Some(BytesN::from_array(&env, &[1; 32])))
+        .is_err());
+    assert_eq!(s.client.get_metadata_revision_count(&1u64), 0);
+}
+
+#[test]
+fn an_empty_name_is_rejected() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_update_token_name(&1u64, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidInput))
+    );
+}
@@ -9,12 +9,25 @@ mod audit_trail;
 mod auth;
 mod initialization;
 mod pause;
+mod scoped_pause;
 
 // Tokenization and ownership tests
+mod compliance;
 mod detokenization;
 mod dividends;
+mod fees;
+mod holder_limits;
+mod holders;
+mod holding;
+mod market;
+mod metadata;
+mod offering;
+mod token_contract;
 mod tokenization;
+mod tokenizer;
 mod transfer_restrictions;
+mod valuation;
+mod vesting;
 mod voting;
 
 // Insurance tests
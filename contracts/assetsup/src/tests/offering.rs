@@ -0,0 +1,354 @@
+//! Primary offering tests.
+//!
+//! The properties that matter: **an investor ends up with either their shares
+//! or their money back, never both and never neither**, and the tokenizer
+//! receives the proceeds only from an offering that met its soft cap.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::offering::{OfferingStatus, OfferingTerms};
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+const START: u64 = 1_000;
+const END: u64 = 2_000;
+/// Two decimals: 100 share units make one whole share.
+const ONE_SHARE: i128 = 100;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+    payment: TokenClient<'a>,
+}
+
+impl Setup<'_> {
+    fn investor(&self, funds: i128) -> Address {
+        let investor = Address::generate(self.env);
+        StellarAssetClient::new(self.env, &self.payment.address).mint(&investor, &funds);
+        investor
+    }
+
+    fn terms(&self) -> OfferingTerms {
+        OfferingTerms {
+            payment_token: self.payment.address.clone(),
+            price: 50,
+            start: START,
+            end: END,
+            min_purchase: ONE_SHARE,
+            max_purchase: 50 * ONE_SHARE,
+            soft_cap: 40 * ONE_SHARE,
+            hard_cap: 100 * ONE_SHARE,
+        }
+    }
+
+    fn open(&self) {
+        self.client
+            .create_offering(&1u64, &self.terms(), &self.tokenizer);
+        set_time(self.env, START);
+    }
+}
+
+fn set_time(env: &Env, t: u64) {
+    env.ledger().with_mut(|l| l.timestamp = t);
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "IPO"),
+        &(1_000 * ONE_SHARE),
+        &2u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Offered"),
+        &String::from_str(env, "Offering test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let payment = TokenClient::new(env, &sac.address());
+
+    Setup {
+        env,
+        client,
+        tokenizer,
+        payment,
+    }
+}
+
+#[test]
+fn creating_an_offering_escrows_the_hard_cap() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.tokenizer),
+        900 * ONE_SHARE
+    );
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.client.address),
+        100 * ONE_SHARE
+    );
+}
+
+#[test]
+fn purchases_are_charged_per_whole_share_rounding_up() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+    let investor = s.investor(10_000);
+
+    // 1.5 shares at 50 each.
+    assert_eq!(s.client.buy_offering_shares(&1u64, &investor, &150i128), 75);
+    // 1.01 shares: 50.5 rounds up to 51.
+    assert_eq!(s.client.buy_offering_shares(&1u64, &investor, &101i128), 51);
+    assert_eq!(s.payment.balance(&investor), 10_000 - 126);
+
+    let sub = s
+        .client
+        .get_offering_subscription(&1u64, &investor)
+        .unwrap();
+    assert_eq!((sub.shares, sub.paid), (251, 126));
+}
+
+#[test]
+fn purchases_respect_the_window_and_limits() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.create_offering(&1u64, &s.terms(), &s.tokenizer);
+    let investor = s.investor(1_000_000);
+
+    set_time(&env, START - 1);
+    let res = s
+        .client
+        .try_buy_offering_shares(&1u64, &investor, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::InvalidSaleState)));
+
+    set_time(&env, START);
+    let res = s
+        .client
+        .try_buy_offering_shares(&1u64, &investor, &(ONE_SHARE - 1));
+    assert_eq!(res, Err(Ok(Error::InvalidInput)));
+
+    s.client
+        .buy_offering_shares(&1u64, &investor, &(50 * ONE_SHARE));
+    let res = s
+        .client
+        .try_buy_offering_shares(&1u64, &investor, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::InvalidInput)), "max_purchase is a total");
+
+    set_time(&env, END);
+    let other = s.investor(1_000_000);
+    let res = s.client.try_buy_offering_shares(&1u64, &other, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::InvalidSaleState)));
+}
+
+#[test]
+fn transfer_restrictions_apply_to_investors() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+    let approved = s.investor(10_000);
+    let outsider = s.investor(10_000);
+    s.client.add_to_whitelist(&1u64, &approved);
+
+    s.client.buy_offering_shares(&1u64, &approved, &ONE_SHARE);
+    let res = s
+        .client
+        .try_buy_offering_shares(&1u64, &outsider, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::TransferRestrictionFailed)));
+    assert_eq!(s.payment.balance(&outsider), 10_000);
+}
+
+#[test]
+fn unclaimed_subscriptions_hold_a_place_under_max_holders() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.set_holder_limits(&1u64, &1u32, &0u32, &0i128);
+    s.open();
+    let a = s.investor(1_000_000);
+    let b = s.investor(1_000_000);
+
+    s.client.buy_offering_shares(&1u64, &a, &(40 * ONE_SHARE));
+    // a holds no shares yet, but their subscription takes the only place.
+    let res = s.client.try_buy_offering_shares(&1u64, &b, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::MaxHoldersExceeded)));
+    let res = s
+        .client
+        .try_transfer_tokens(&1u64, &s.tokenizer, &b, &ONE_SHARE);
+    assert_eq!(res, Err(Ok(Error::MaxHoldersExceeded)));
+    // Buying more takes no new place.
+    s.client.buy_offering_shares(&1u64, &a, &ONE_SHARE);
+
+    set_time(&env, END);
+    assert!(s.client.finalize_offering(&1u64));
+    assert_eq!(s.client.claim_offering(&1u64, &a), 41 * ONE_SHARE);
+    assert_eq!(
+        s.client.try_claim_offering(&1u64, &b),
+        Err(Ok(Error::NotFound))
+    );
+    assert_eq!(s.client.get_tokenized_asset(&1u64).token_holders_count, 2);
+}
+
+#[test]
+fn unclaimed_subscriptions_count_toward_the_ownership_cap() {
+    let env = create_env();
+    let s = setup(&env);
+    // 5% of 1,000 shares.
+    s.client.set_holder_limits(&1u64, &0u32, &500u32, &0i128);
+    s.open();
+    let a = s.investor(1_000_000);
+
+    s.client.buy_offering_shares(&1u64, &a, &(40 * ONE_SHARE));
+    let res = s
+        .client
+        .try_transfer_tokens(&1u64, &s.tokenizer, &a, &(20 * ONE_SHARE));
+    assert_eq!(res, Err(Ok(Error::OwnershipCapExceeded)));
+    s.client
+        .transfer_tokens(&1u64, &s.tokenizer, &a, &(10 * ONE_SHARE));
+
+    set_time(&env, END);
+    assert!(s.client.finalize_offering(&1u64));
+    s.client.claim_offering(&1u64, &a);
+    assert_eq!(s.client.get_token_balance(&1u64, &a), 50 * ONE_SHARE);
+}
+
+#[test]
+fn a_successful_offering_pays_the_tokenizer_and_delivers_shares() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+    let a = s.investor(1_000_000);
+    let b = s.investor(1_000_000);
+    s.client.buy_offering_shares(&1u64, &a, &(30 * ONE_SHARE));
+    s.client.buy_offering_shares(&1u64, &b, &(20 * ONE_SHARE));
+
+    // Shares are not delivered before the offering closes.
+    assert_eq!(
+        s.client.try_claim_offering(&1u64, &a),
+        Err(Ok(Error::InvalidSaleState))
+    );
+
+    set_time(&env, END);
+    assert!(s.client.finalize_offering(&1u64));
+    assert_eq!(s.payment.balance(&s.tokenizer), 2_500);
+    // The 50 unsold shares are back with the tokenizer.
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.tokenizer),
+        950 * ONE_SHARE
+    );
+
+    assert_eq!(s.client.claim_offering(&1u64, &a), 30 * ONE_SHARE);
+    assert_eq!(s.client.get_token_balance(&1u64, &a), 30 * ONE_SHARE);
+    assert_eq!(
+        s.client.try_claim_offering(&1u64, &a),
+        Err(Ok(Error::NotFound))
+    );
+    s.client.claim_offering(&1u64, &b);
+
+    assert_eq!(s.client.get_token_balance(&1u64, &s.client.address), 0);
+    assert_eq!(s.payment.balance(&s.client.address), 0);
+}
+
+#[test]
+fn a_failed_offering_refunds_every_investor() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+    let investor = s.investor(1_000_000);
+    s.client
+        .buy_offering_shares(&1u64, &investor, &(10 * ONE_SHARE));
+
+    set_time(&env, END);
+    assert!(!s.client.finalize_offering(&1u64));
+    assert_eq!(
+        s.client.get_offering(&1u64).unwrap().status,
+        OfferingStatus::Failed
+    );
+    assert_eq!(s.payment.balance(&s.tokenizer), 0);
+    assert_eq!(
+        s.client.get_token_balance(&1u64, &s.tokenizer),
+        1_000 * ONE_SHARE
+    );
+
+    assert_eq!(s.client.claim_offering(&1u64, &investor), 500);
+    assert_eq!(s.payment.balance(&investor), 1_000_000);
+    assert_eq!(s.client.get_token_balance(&1u64, &investor), 0);
+}
+
+#[test]
+fn a_sold_out_offering_can_close_early() {
+    let env = create_env();
+    let s = setup(&env);
+    s.open();
+    let a = s.investor(1_000_000);
+    let b = s.investor(1_000_000);
+    s.client.buy_offering_shares(&1u64, &a, &(50 * ONE_SHARE));
+
+    assert_eq!(
+        s.client.try_finalize_offering(&1u64),
+        Err(Ok(Error::InvalidSaleState))
+    );
+
+    s.client.buy_offering_shares(&1u64, &b, &(50 * ONE_SHARE));
+    assert!(s.client.finalize_offering(&1u64));
+}
+
+#[test]
+fn only_the_tokenizer_opens_one_offering_at_a_time() {
+    let env = create_env();
+    let s = setup(&env);
+    let intruder = Address::generate(&env);
+
+    let res = s.client.try_create_offering(&1u64, &s.terms(), &intruder);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    s.open();
+    let res = s
+        .client
+        .try_create_offering(&1u64, &s.terms(), &s.tokenizer);
+    assert_eq!(res, Err(Ok(Error::InvalidSaleState)));
+
+    // A closed offering with an unclaimed subscription still blocks the next.
+    let investor = s.investor(1_000_000);
+    s.client.buy_offering_shares(&1u64, &investor, &ONE_SHARE);
+    set_time(&env, END);
+    s.client.finalize_offering(&1u64);
+    let mut next = s.terms();
+    next.start = END;
+    next.end = END + 1_000;
+    let res = s.client.try_create_offering(&1u64, &next, &s.tokenizer);
+    assert_eq!(res, Err(Ok(Error::InvalidSaleState)));
+
+    s.client.claim_offering(&1u64, &investor);
+    s.client.create_offering(&1u64, &next, &s.tokenizer);
+}
+
+#[test]
+fn inconsistent_terms_are_rejected() {
+    let env = create_env();
+    let s = setup(&env);
+
+    let mut terms = s.terms();
+    terms.soft_cap = terms.hard_cap + 1;
+    let res = s.client.try_create_offering(&1u64, &terms, &s.tokenizer);
+    assert_eq!(res, Err(Ok(Error::InvalidInput)));
+
+    let mut terms = s.terms();
+    terms.end = terms.start;
+    let res = s.client.try_create_offering(&1u64, &terms, &s.tokenizer);
+    assert_eq!(res, Err(Ok(Error::InvalidTimestamps)));
+}
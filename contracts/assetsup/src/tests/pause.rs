@@ -47,6 +47,19 @@ const PAUSE_EXEMPT: &[(&str, &str)] = &[
         "unpause_contract",
         "the control itself; must work while paused",
     ),
+    (
+        "pause",
+        "the scoped control itself; pausers must reach it while paused",
+    ),
+    (
+        "unpause",
+        "the scoped control itself; must work while paused",
+    ),
+    (
+        "add_pauser",
+        "incident response may need another pauser while paused",
+    ),
+    ("remove_pauser", "revoking a compromised pauser key"),
     (
         "propose_admin",
         "incident response may require rotating a compromised admin key",
@@ -58,13 +71,31 @@ const PAUSE_EXEMPT: &[(&str, &str)] = &[
         "user exit path: freezing it would trap funds users have already earned",
     ),
     (
-        "upgrade",
+        "schedule_upgrade",
         "an upgrade is how you fix the incident that caused the pause",
     ),
+    ("cancel_upgrade", "withdraws the upgrade above"),
+    ("execute_upgrade", "installs the upgrade above"),
+    (
+        "set_upgrade_delay",
+        "governs the upgrade path, which must work while paused",
+    ),
     (
         "migrate",
         "must run after upgrade to bring storage up to date",
     ),
+    (
+        "extend_asset_ttl",
+        "keeps storage alive; changes no contract state",
+    ),
+    (
+        "extend_token_ttl",
+        "keeps storage alive; changes no contract state",
+    ),
+    (
+        "queue_migration",
+        "feeds migrate, which runs while every guarded entrypoint refuses",
+    ),
     (
         "storage_version",
         "read-only: returns the current storage layout version",
@@ -162,6 +193,7 @@ fn tokenization_is_blocked_while_paused() {
             &String::from_str(&env, "Token"),
             &String::from_str(&env, "A token"),
             &crate::types::AssetType::Physical,
+            &None,
         )
         .is_err());
     assert!(client.try_mint_tokens(&1u64, &10i128, &holder).is_err());
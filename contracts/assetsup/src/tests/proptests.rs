@@ -9,12 +9,16 @@
 //! a transfer that credits without debiting, or a burn that forgets to reduce
 //! supply, only shows up in a combination nobody wrote down.
 //!
+//! The holder set is checked the same way: its size, reported as
+//! `token_holders_count`, always equals the number of non-zero balances.
+//!
 //! Case counts are kept modest; each case spins up a fresh Soroban `Env`.
 
 use proptest::prelude::*;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env, String, Vec};
 
+use super::helpers::registry_link;
 use crate::types::AssetType;
 use crate::{AssetUpContract, AssetUpContractClient};
 
@@ -28,6 +32,7 @@ fn tokenized(env: &Env, supply: i128) -> (AssetUpContractClient<'_>, Address) {
     client.initialize(&admin);
 
     let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
     client.tokenize_asset(
         &1u64,
         &String::from_str(env, "SHARE"),
@@ -38,6 +43,7 @@ fn tokenized(env: &Env, supply: i128) -> (AssetUpContractClient<'_>, Address) {
         &String::from_str(env, "Share"),
         &String::from_str(env, "Fractional share"),
         &AssetType::Physical,
+        &None,
     );
 
     (client, tokenizer)
@@ -206,4 +212,83 @@ proptest! {
             );
         }
     }
+
+    /// The holder count is exactly the number of non-zero balances, and the
+    /// holder set is exactly those addresses, however shares move.
+    ///
+    /// Amounts are drawn as a share of the sender's balance so that sequences
+    /// regularly empty a balance and refill it, which is where a stale holder
+    /// entry would come from.
+    #[test]
+    fn holder_count_matches_nonzero_balances(
+        supply in 100i128..10_000,
+        ops in prop::collection::vec((0usize..4, 0usize..4, 1u8..=4, any::<bool>()), 1..12),
+    ) {
+        let env = Env::default();
+        let (client, tokenizer) = tokenized(&env, supply);
+        let mut accounts = std::vec![tokenizer.clone()];
+        accounts.extend((0..3).map(|_| Address::generate(&env)));
+
+        for (from, to, quarters, burn) in ops {
+            let from = &accounts[from];
+            let amount = client.get_token_balance(&1u64, from) * i128::from(quarters) / 4;
+            if amount > 0 {
+                if burn && *from == tokenizer {
+                    let _ = client.try_burn_tokens(&1u64, &amount, &tokenizer);
+                } else {
+                    let _ = client.try_transfer_tokens(&1u64, from, &accounts[to], &amount);
+                }
+            }
+
+            let nonzero: std::vec::Vec<&Address> = accounts
+                .iter()
+                .filter(|a| client.get_token_balance(&1u64, a) > 0)
+                .collect();
+            let holders: Vec<Address> = client.get_token_holders(&1u64);
+
+            prop_assert_eq!(
+                client.get_tokenized_asset(&1u64).token_holders_count as usize,
+                nonzero.len(),
+                "the holder count must equal the number of non-zero balances"
+            );
+            prop_assert_eq!(holders.len() as usize, nonzero.len());
+            for account in nonzero {
+                prop_assert!(holders.contains(account), "every non-zero balance must be a holder");
+            }
+        }
+    }
+
+    /// A share fee only moves shares to the treasury and tokenizer: however
+    /// investors trade, the fee plus what the recipient gets is what the
+    /// sender gave, and the holder sum still equals the supply.
+    #[test]
+    fn share_fees_conserve_total_supply(
+        bps in 0u32..=10_000,
+        min_fee in 0i128..20,
+        royalty_bps in 0u32..=10_000,
+        ops in prop::collection::vec((0usize..3, 0usize..3, 1i128..500), 1..8),
+    ) {
+        let env = Env::default();
+        let (client, tokenizer) = tokenized(&env, 10_000);
+        let treasury = Address::generate(&env);
+        client.set_transfer_fee(&1u64, &bps, &min_fee, &None, &treasury, &royalty_bps);
+        let investors: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&env)).collect();
+        for investor in &investors {
+            client.transfer_tokens(&1u64, &tokenizer, investor, &1_000i128);
+        }
+
+        for (from, to, amount) in ops {
+            let (from, to) = (&investors[from], &investors[to]);
+            let sent_before = client.get_token_balance(&1u64, from);
+            if client.try_transfer_tokens(&1u64, from, to, &amount).is_ok() {
+                prop_assert_eq!(client.get_token_balance(&1u64, from), sent_before - amount);
+            }
+
+            prop_assert_eq!(
+                total_held(&client),
+                client.get_tokenized_asset(&1u64).total_supply,
+                "holder balances must always sum to total supply"
+            );
+        }
+    }
 }
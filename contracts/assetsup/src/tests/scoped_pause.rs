@@ -0,0 +1,218 @@
+//! Scoped pause tests.
+//!
+//! The property that matters: **a scoped pause stops exactly its scope**. A
+//! module pause leaves the other modules running, an asset freeze leaves the
+//! other assets trading, and neither outlives its expiry. A pauser can start
+//! or extend a pause but never end one.
+
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, TryIntoVal};
+
+use super::helpers::{create_env, create_test_asset, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::pause::{Module, PauseScope};
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+struct Setup<'a> {
+    env: &'a Env,
+    client: AssetUpContractClient<'a>,
+    admin: Address,
+    tokenizer: Address,
+    pauser: Address,
+}
+
+impl Setup<'_> {
+    fn send(&self, asset_id: u64) -> Result<(), Error> {
+        let to = Address::generate(self.env);
+        match self
+            .client
+            .try_transfer_tokens(&asset_id, &self.tokenizer, &to, &1i128)
+        {
+            Ok(_) => Ok(()),
+            Err(Ok(e)) => Err(e),
+            Err(Err(_)) => panic!("transfer failed outside the contract"),
+        }
+    }
+
+    fn register(&self, seed: u8) {
+        let asset = create_test_asset(
+            self.env,
+            &Address::generate(self.env),
+            BytesN::from_array(self.env, &[seed; 32]),
+        );
+        self.client.register_asset(&asset, &self.admin);
+    }
+
+    fn at(&self, t: u64) {
+        self.env.ledger().with_mut(|l| l.timestamp = t);
+    }
+}
+
+/// Assets 1 and 2, tokenized by the same tokenizer, and one pauser.
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    for asset_id in [1u64, 2] {
+        registry_link(env, &client, asset_id, &tokenizer);
+        client.tokenize_asset(
+            &asset_id,
+            &String::from_str(env, "PSE"),
+            &1_000i128,
+            &0u32,
+            &1i128,
+            &tokenizer,
+            &String::from_str(env, "Pausable"),
+            &String::from_str(env, "Scoped pause test asset"),
+            &AssetType::Physical,
+            &None,
+        );
+    }
+    let pauser = Address::generate(env);
+    client.add_pauser(&pauser);
+
+    Setup {
+        env,
+        client,
+        admin,
+        tokenizer,
+        pauser,
+    }
+}
+
+#[test]
+fn a_module_pause_leaves_the_other_modules_running() {
+    let env = create_env();
+    let s = setup(&env);
+    let scope = PauseScope::Module(Module::Tokenization);
+
+    s.client.pause(&s.pauser, &scope, &None);
+
+    assert!(!s.client.is_paused());
+    assert_eq!(s.send(1), Err(Error::ContractPaused));
+    assert_eq!(s.send(2), Err(Error::ContractPaused));
+    s.register(1);
+
+    s.client.unpause(&scope);
+    s.send(1).unwrap();
+}
+
+#[test]
+fn an_asset_freeze_leaves_the_other_assets_trading() {
+    let env = create_env();
+    let s = setup(&env);
+    s.at(10);
+
+    s.client.pause(&s.pauser, &PauseScope::Asset(1), &None);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    let scope: PauseScope = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(name, Symbol::new(&env, "scope_paused"));
+    assert_eq!(scope, PauseScope::Asset(1));
+
+    let record = s.client.get_pause(&PauseScope::Asset(1)).unwrap();
+    assert_eq!(record.paused_by, s.pauser);
+    assert_eq!(record.paused_at, 10);
+    assert_eq!(record.expires_at, None);
+    assert_eq!(s.send(1), Err(Error::ContractPaused));
+    assert_eq!(
+        s.client.try_distribute_dividends(&1u64, &100i128),
+        Err(Ok(Error::ContractPaused))
+    );
+    s.send(2).unwrap();
+    assert_eq!(s.client.get_pause(&PauseScope::Asset(2)), None);
+}
+
+#[test]
+fn a_pause_lapses_at_its_expiry() {
+    let env = create_env();
+    let s = setup(&env);
+    s.at(100);
+
+    s.client
+        .pause(&s.pauser, &PauseScope::Contract, &Some(200u64));
+    assert!(s.client.is_paused());
+    assert_eq!(s.send(1), Err(Error::ContractPaused));
+
+    s.at(200);
+    assert!(!s.client.is_paused());
+    assert_eq!(s.client.get_pause(&PauseScope::Contract), None);
+    s.send(1).unwrap();
+
+    assert_eq!(
+        s.client
+            .try_pause(&s.pauser, &PauseScope::Contract, &Some(200u64)),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn a_pauser_can_extend_a_pause_but_never_shorten_it() {
+    let env = create_env();
+    let s = setup(&env);
+    let scope = PauseScope::Module(Module::Leasing);
+
+    s.client.pause(&s.pauser, &scope, &Some(500u64));
+    s.client.pause(&s.pauser, &scope, &Some(900u64));
+    assert_eq!(
+        s.client.try_pause(&s.pauser, &scope, &Some(600u64)),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.pause(&s.pauser, &scope, &None);
+    assert_eq!(
+        s.client.try_pause(&s.pauser, &scope, &Some(10_000u64)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The admin may replace the pause with any other.
+    s.client.pause(&s.admin, &scope, &Some(600u64));
+    assert_eq!(s.client.get_pause(&scope).unwrap().expires_at, Some(600));
+}
+
+#[test]
+fn only_the_admin_unpauses() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.pause(&s.pauser, &PauseScope::Asset(1), &None);
+
+    env.set_auths(&[]);
+    assert!(s.client.try_unpause(&PauseScope::Asset(1)).is_err());
+    assert!(s.client.get_pause(&PauseScope::Asset(1)).is_some());
+}
+
+#[test]
+fn only_the_admin_and_pausers_may_pause() {
+    let env = create_env();
+    let s = setup(&env);
+    let stranger = Address::generate(&env);
+    assert!(s.client.is_pauser(&s.pauser));
+    assert!(!s.client.is_pauser(&stranger));
+
+    assert_eq!(
+        s.client.try_pause(&stranger, &PauseScope::Contract, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.client.remove_pauser(&s.pauser);
+    assert_eq!(
+        s.client.try_pause(&s.pauser, &PauseScope::Contract, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!s.client.is_paused());
+}
+
+#[test]
+fn unpause_contract_also_lifts_a_contract_wide_scoped_pause() {
+    let env = create_env();
+    let s = setup(&env);
+
+    s.client.pause(&s.pauser, &PauseScope::Contract, &None);
+    s.client.unpause_contract();
+
+    assert!(!s.client.is_paused());
+    s.send(1).unwrap();
+}
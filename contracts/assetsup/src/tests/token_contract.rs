@@ -0,0 +1,147 @@
+//! Registry-side tests for SEP-41 token contracts.
+//!
+//! The token contract itself lives in the `asset-token` crate, whose tests run
+//! it against this registry. These cover the registry's half: who may
+//! configure and deploy, and that the forwarding entrypoints trust only the
+//! bound token contract and apply the same checks as `transfer_tokens`.
+//!
+//! The unit-test environment registers contracts natively rather than from
+//! uploaded WASM, so `deploy_v2` cannot run here. Tests that need a bound
+//! token write the binding directly, as `tokenize_asset` would.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::{AssetType, TokenDataKey};
+use crate::vesting::VestingRelease;
+use crate::AssetUpContractClient;
+
+fn setup(env: &Env) -> (AssetUpContractClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "TKN"),
+        &10_000i128,
+        &2u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Token"),
+        &String::from_str(env, "SEP-41 test asset"),
+        &AssetType::Digital,
+        &None,
+    );
+
+    (client, tokenizer)
+}
+
+/// Binds a stand-in address as asset 1's token contract.
+fn bind(env: &Env, client: &AssetUpContractClient) -> Address {
+    let token = Address::generate(env);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&TokenDataKey::TokenContract(1), &token);
+    });
+    token
+}
+
+#[test]
+fn no_token_contract_is_deployed_without_a_wasm_hash() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+
+    assert_eq!(client.get_token_contract(&1u64), None);
+    let res = client.try_deploy_token_contract(&1u64, &tokenizer);
+    assert_eq!(res, Err(Ok(Error::NotFound)));
+}
+
+#[test]
+fn only_the_tokenizer_can_deploy() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_token_wasm_hash(&BytesN::from_array(&env, &[9u8; 32]));
+
+    let res = client.try_deploy_token_contract(&1u64, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn a_bound_asset_cannot_be_redeployed() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    client.set_token_wasm_hash(&BytesN::from_array(&env, &[9u8; 32]));
+    let token = bind(&env, &client);
+
+    let res = client.try_deploy_token_contract(&1u64, &tokenizer);
+    assert_eq!(res, Err(Ok(Error::AlreadyInitialized)));
+    assert_eq!(client.get_token_contract(&1u64), Some(token));
+}
+
+#[test]
+fn setting_the_wasm_hash_requires_the_admin() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_set_token_wasm_hash(&BytesN::from_array(&env, &[9u8; 32]))
+        .is_err());
+}
+
+#[test]
+fn forwarding_without_a_bound_token_fails() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+
+    let res = client.try_sep41_transfer(&1u64, &tokenizer, &Address::generate(&env), &1i128);
+    assert_eq!(res, Err(Ok(Error::NotFound)));
+}
+
+#[test]
+fn forwarded_transfers_respect_vesting() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    bind(&env, &client);
+    let holder = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.create_vesting_grant(
+        &1u64,
+        &holder,
+        &1_000i128,
+        &now,
+        &(now + 100),
+        &(now + 1_000),
+        &VestingRelease::Linear,
+        &false,
+        &tokenizer,
+    );
+
+    let res = client.try_sep41_transfer(&1u64, &holder, &tokenizer, &1i128);
+    assert_eq!(res, Err(Ok(Error::TokensAreLocked)));
+    let res = client.try_sep41_burn(&1u64, &holder, &1i128);
+    assert_eq!(res, Err(Ok(Error::TokensAreLocked)));
+
+    env.ledger().with_mut(|l| l.timestamp = now + 1_000);
+    client.sep41_transfer(&1u64, &holder, &tokenizer, &500i128);
+    client.sep41_burn(&1u64, &holder, &500i128);
+    assert_eq!(client.get_token_balance(&1u64, &holder), 0);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 9_500);
+}
+
+#[test]
+fn sep41_metadata_reflects_the_asset() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+
+    let (decimals, name, symbol) = client.get_sep41_metadata(&1u64);
+    assert_eq!(decimals, 2);
+    assert_eq!(name, String::from_str(&env, "Token"));
+    assert_eq!(symbol, String::from_str(&env, "TKN"));
+}
@@ -10,6 +10,7 @@ fn test_tokenize_asset_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     let result = client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -20,6 +21,7 @@ fn test_tokenize_asset_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     assert_eq!(result.asset_id, 1);
@@ -37,6 +39,7 @@ fn test_tokenize_asset_already_tokenized() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -47,9 +50,11 @@ fn test_tokenize_asset_already_tokenized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Try to tokenize again - should panic with AssetAlreadyTokenized
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST2"),
@@ -60,6 +65,7 @@ fn test_tokenize_asset_already_tokenized() {
         &String::from_str(&env, "Test Token 2"),
         &String::from_str(&env, "Another test"),
         &AssetType::Physical,
+        &None,
     );
 }
 
@@ -73,6 +79,7 @@ fn test_tokenize_asset_invalid_supply() {
     env.mock_all_auths();
 
     // Should panic with InvalidTokenSupply error
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -83,6 +90,7 @@ fn test_tokenize_asset_invalid_supply() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 }
 
@@ -94,6 +102,7 @@ fn test_mint_tokens_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -104,6 +113,7 @@ fn test_mint_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let result = client.mint_tokens(&1u64, &500000i128, &user1);
@@ -121,6 +131,7 @@ fn test_mint_tokens_unauthorized() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -131,6 +142,7 @@ fn test_mint_tokens_unauthorized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // user2 is not tokenizer - should panic with Unauthorized
@@ -158,6 +170,7 @@ fn test_burn_tokens_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -168,6 +181,7 @@ fn test_burn_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let result = client.burn_tokens(&1u64, &200000i128, &user1);
@@ -185,6 +199,7 @@ fn test_burn_tokens_insufficient_balance() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -195,6 +210,7 @@ fn test_burn_tokens_insufficient_balance() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InsufficientBalance error
@@ -209,6 +225,7 @@ fn test_transfer_tokens_success() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -219,6 +236,7 @@ fn test_transfer_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
@@ -240,6 +258,7 @@ fn test_transfer_tokens_insufficient_balance() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -250,6 +269,7 @@ fn test_transfer_tokens_insufficient_balance() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InsufficientBalance error
@@ -265,6 +285,7 @@ fn test_transfer_tokens_locked() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -275,6 +296,7 @@ fn test_transfer_tokens_locked() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Lock tokens
@@ -293,6 +315,7 @@ fn test_lock_unlock_tokens() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -303,6 +326,7 @@ fn test_lock_unlock_tokens() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially not locked
@@ -329,6 +353,7 @@ fn test_lock_tokens_unauthorized() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -339,6 +364,7 @@ fn test_lock_tokens_unauthorized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let future_time = env.ledger().timestamp() + 1000;
@@ -355,6 +381,7 @@ fn test_get_ownership_percentage() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -365,6 +392,7 @@ fn test_get_ownership_percentage() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 30% to user2
@@ -386,6 +414,7 @@ fn test_get_token_holders() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -396,6 +425,7 @@ fn test_get_token_holders() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially only user1
@@ -419,6 +449,7 @@ fn test_update_valuation() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -429,6 +460,7 @@ fn test_update_valuation() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.update_valuation(&1u64, &2000000i128);
@@ -446,6 +478,7 @@ fn test_update_valuation_invalid() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -456,8 +489,198 @@ fn test_update_valuation_invalid() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InvalidValuation error
     client.update_valuation(&1u64, &0i128);
 }
+
+fn tokenize_capped(
+    env: &soroban_sdk::Env,
+    client: &crate::AssetUpContractClient<'_>,
+    tokenizer: &soroban_sdk::Address,
+    max_supply: Option<i128>,
+) {
+    registry_link(env, client, 1u64, tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "TST"),
+        &1000000i128,
+        &6u32,
+        &100i128,
+        tokenizer,
+        &String::from_str(env, "Test Token"),
+        &String::from_str(env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &max_supply,
+    );
+}
+
+fn stored_percentage(
+    env: &soroban_sdk::Env,
+    client: &crate::AssetUpContractClient<'_>,
+    holder: &soroban_sdk::Address,
+) -> i128 {
+    env.as_contract(&client.address, || {
+        let record: crate::types::OwnershipRecord = env
+            .storage()
+            .persistent()
+            .get(&crate::types::TokenDataKey::TokenHolder(1, holder.clone()))
+            .unwrap();
+        record.ownership_percentage
+    })
+}
+
+#[test]
+fn test_mint_to_credits_the_recipient() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_capped(&env, &client, &user1, None);
+
+    let result = client.mint_to(&1u64, &user2, &1000000i128);
+
+    assert_eq!(result.total_supply, 2000000);
+    assert_eq!(result.token_holders_count, 2);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 1000000);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 1000000);
+}
+
+#[test]
+fn test_supply_changes_refresh_every_holders_percentage() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_capped(&env, &client, &user1, None);
+    client.transfer_tokens(&1u64, &user1, &user2, &500000i128);
+    assert_eq!(stored_percentage(&env, &client, &user1), 5000);
+    assert_eq!(stored_percentage(&env, &client, &user2), 5000);
+
+    // Doubling the supply halves the share of the holders who received nothing.
+    client.mint_to(&1u64, &user3, &1000000i128);
+    for (holder, expected) in [(&user1, 2500), (&user2, 2500), (&user3, 5000)] {
+        assert_eq!(stored_percentage(&env, &client, holder), expected);
+        assert_eq!(client.get_ownership_percentage(&1u64, holder), expected);
+    }
+
+    client.burn_tokens(&1u64, &500000i128, &user1);
+    for (holder, expected) in [(&user2, 3333), (&user3, 6666)] {
+        assert_eq!(stored_percentage(&env, &client, holder), expected);
+        assert_eq!(client.get_ownership_percentage(&1u64, holder), expected);
+    }
+}
+
+#[test]
+fn test_mint_to_respects_transfer_restrictions() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_capped(&env, &client, &user1, None);
+    client.add_to_whitelist(&1u64, &user2);
+
+    client.mint_to(&1u64, &user2, &100i128);
+    let res = client.try_mint_to(&1u64, &user3, &100i128);
+    assert_eq!(res, Err(Ok(crate::error::Error::TransferRestrictionFailed)));
+}
+
+#[test]
+fn test_mint_to_requires_the_tokenizer() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_capped(&env, &client, &user1, None);
+
+    env.set_auths(&[]);
+    assert!(client.try_mint_to(&1u64, &user2, &100i128).is_err());
+}
+
+#[test]
+fn test_max_supply_caps_every_mint() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_capped(&env, &client, &user1, Some(1500000));
+    assert_eq!(client.get_max_supply(&1u64), Some(1500000));
+
+    client.mint_to(&1u64, &user2, &400000i128);
+    let res = client.try_mint_to(&1u64, &user2, &100001i128);
+    assert_eq!(res, Err(Ok(crate::error::Error::InvalidTokenSupply)));
+    let res = client.try_mint_tokens(&1u64, &100001i128, &user1);
+    assert_eq!(res, Err(Ok(crate::error::Error::InvalidTokenSupply)));
+
+    // Exactly up to the cap is fine; burning makes room again.
+    client.mint_tokens(&1u64, &100000i128, &user1);
+    client.burn_tokens(&1u64, &1i128, &user1);
+    client.mint_tokens(&1u64, &1i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1500000);
+}
+
+#[test]
+fn test_max_supply_below_initial_supply_is_rejected() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    registry_link(&env, &client, 1u64, &user1);
+    let res = client.try_tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TST"),
+        &1000000i128,
+        &6u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &Some(999999i128),
+    );
+    assert_eq!(res, Err(Ok(crate::error::Error::InvalidTokenSupply)));
+}
+
+#[test]
+fn test_decimals_are_capped_at_eighteen() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    registry_link(&env, &client, 1u64, &user1);
+    let res = client.try_tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TST"),
+        &1000000i128,
+        &19u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+    assert_eq!(res, Err(Ok(crate::error::Error::InvalidTokenDecimals)));
+
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TST"),
+        &1000000i128,
+        &18u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+}
@@ -32,6 +32,7 @@ fn setup_tokenized(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -69,6 +70,7 @@ fn test_tokenize_asset() {
             min_voting_threshold,
             tokenizer.clone(),
             metadata,
+            None,
         )
         .unwrap()
     });
@@ -106,6 +108,7 @@ fn test_tokenize_asset_invalid_supply() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
     });
 
@@ -141,6 +144,7 @@ fn test_mint_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -183,6 +187,7 @@ fn test_burn_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -224,6 +229,7 @@ fn test_transfer_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -274,6 +280,7 @@ fn test_lock_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -334,6 +341,7 @@ fn test_ownership_percentage() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
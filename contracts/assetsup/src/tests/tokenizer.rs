@@ -0,0 +1,260 @@
+//! Tokenizer role handover tests.
+//!
+//! The properties that matter: **the role only moves when the successor
+//! accepts**, and **the tokenizer and the owner of the linked registry asset
+//! are always the same address**, whichever way control changes hands.
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use super::helpers::{create_env, create_test_asset, generate_asset_id, initialize_contract};
+use crate::error::Error;
+use crate::tokenization::asset_id_to_bytes;
+use crate::types::AssetType;
+use crate::AssetUpContractClient;
+
+struct Setup<'a> {
+    client: AssetUpContractClient<'a>,
+    tokenizer: Address,
+    registry_id: BytesN<32>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    let registry_id = generate_asset_id(env, 1);
+    client.register_asset(
+        &create_test_asset(env, &tokenizer, registry_id.clone()),
+        &admin,
+    );
+    client.link_registry_asset(&1u64, &registry_id, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "HAND"),
+        &1_000i128,
+        &2u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Handed over"),
+        &String::from_str(env, "Tokenizer handover test asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    Setup {
+        client,
+        tokenizer,
+        registry_id,
+    }
+}
+
+#[test]
+fn the_role_moves_only_on_acceptance() {
+    let env = create_env();
+    let s = setup(&env);
+    let successor = Address::generate(&env);
+
+    s.client.propose_tokenizer(&1u64, &successor);
+    assert_eq!(
+        s.client.get_pending_tokenizer(&1u64),
+        Some(successor.clone())
+    );
+    assert_eq!(s.client.get_tokenized_asset(&1u64).tokenizer, s.tokenizer);
+
+    s.client.accept_tokenizer(&1u64);
+
+    assert_eq!(s.client.get_tokenized_asset(&1u64).tokenizer, successor);
+    assert_eq!(s.client.get_pending_tokenizer(&1u64), None);
+    // The outgoing tokenizer keeps their shares but loses the powers.
+    assert_eq!(s.client.get_token_balance(&1u64, &s.tokenizer), 1_000);
+    assert_eq!(
+        s.client.try_mint_tokens(&1u64, &1i128, &s.tokenizer),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.mint_tokens(&1u64, &1i128, &successor);
+}
+
+#[test]
+fn only_the_nominee_can_accept() {
+    let env = create_env();
+    let s = setup(&env);
+    let successor = Address::generate(&env);
+    s.client.propose_tokenizer(&1u64, &successor);
+
+    env.set_auths(&[]);
+    assert!(s.client.try_accept_tokenizer(&1u64).is_err());
+    assert_eq!(s.client.get_tokenized_asset(&1u64).tokenizer, s.tokenizer);
+}
+
+#[test]
+fn a_proposal_can_be_cancelled() {
+    let env = create_env();
+    let s = setup(&env);
+    s.client.propose_tokenizer(&1u64, &Address::generate(&env));
+
+    s.client.cancel_tokenizer_proposal(&1u64);
+
+    assert_eq!(s.client.get_pending_tokenizer(&1u64), None);
+    assert_eq!(
+        s.client.try_accept_tokenizer(&1u64),
+        Err(Ok(Error::NotFound))
+    );
+}
+
+#[test]
+fn proposing_the_current_tokenizer_is_rejected() {
+    let env = create_env();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_propose_tokenizer(&1u64, &s.tokenizer),
+        Err(Ok(Error::InvalidOwnerAddress))
+    );
+}
+
+fn tokenize(client: &AssetUpContractClient, env: &Env, asset_id: u64, tokenizer: &Address) {
+    client.tokenize_asset(
+        &asset_id,
+        &String::from_str(env, "NEXT"),
+        &1_000i128,
+        &2u32,
+        &1i128,
+        tokenizer,
+        &String::from_str(env, "Next"),
+        &String::from_str(env, "Another tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+}
+
+#[test]
+fn only_the_owner_of_a_linked_registry_asset_can_tokenize() {
+    let env = create_env();
+    let s = setup(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let registry_id = generate_asset_id(&env, 2);
+    s.client.register_asset(
+        &create_test_asset(&env, &seller, registry_id.clone()),
+        &s.client.get_admin(),
+    );
+
+    let tokenize_as = |who: &Address| {
+        s.client.try_tokenize_asset(
+            &2u64,
+            &String::from_str(&env, "NEXT"),
+            &1_000i128,
+            &2u32,
+            &1i128,
+            who,
+            &String::from_str(&env, "Next"),
+            &String::from_str(&env, "Another tokenized asset"),
+            &AssetType::Physical,
+            &None,
+        )
+    };
+    assert_eq!(tokenize_as(&seller), Err(Ok(Error::AssetNotFound)));
+
+    s.client.link_registry_asset(&2u64, &registry_id, &seller);
+    // Sold before it was tokenized: only the new owner may tokenize it.
+    s.client
+        .transfer_asset_ownership(&registry_id, &buyer, &seller);
+    assert_eq!(tokenize_as(&seller), Err(Ok(Error::Unauthorized)));
+    tokenize(&s.client, &env, 2, &buyer);
+    assert_eq!(s.client.get_tokenized_asset(&2u64).tokenizer, buyer);
+}
+
+#[test]
+fn a_linked_tokenizer_role_follows_registry_ownership() {
+    let env = create_env();
+    let s = setup(&env);
+    let buyer = Address::generate(&env);
+    s.client.propose_tokenizer(&1u64, &Address::generate(&env));
+
+    s.client
+        .transfer_asset_ownership(&s.registry_id, &buyer, &s.tokenizer);
+
+    assert_eq!(s.client.get_tokenized_asset(&1u64).tokenizer, buyer);
+    // A nomination made by the seller does not survive the sale.
+    assert_eq!(s.client.get_pending_tokenizer(&1u64), None);
+
+    let entries = s.client.get_asset_audit_logs(&asset_id_to_bytes(&env, 1));
+    let last = entries.get(entries.len() - 1).unwrap();
+    assert_eq!(last.action, String::from_str(&env, "TOKENIZER_CHANGED"));
+}
+
+#[test]
+fn accepting_a_linked_role_takes_the_registry_asset_too() {
+    let env = create_env();
+    let s = setup(&env);
+    let successor = Address::generate(&env);
+
+    s.client.propose_tokenizer(&1u64, &successor);
+    s.client.accept_tokenizer(&1u64);
+
+    assert_eq!(s.client.get_asset(&s.registry_id).owner, successor);
+    assert_eq!(s.client.get_assets_by_owner(&s.tokenizer).len(), 0);
+}
+
+#[test]
+fn each_side_links_once() {
+    let env = create_env();
+    let s = setup(&env);
+    assert_eq!(
+        s.client.get_linked_registry_asset(&1u64),
+        Some(s.registry_id.clone())
+    );
+
+    let other_id = generate_asset_id(&env, 2);
+    s.client.register_asset(
+        &create_test_asset(&env, &s.tokenizer, other_id.clone()),
+        &s.client.get_admin(),
+    );
+    assert_eq!(
+        s.client
+            .try_link_registry_asset(&1u64, &other_id, &s.tokenizer),
+        Err(Ok(Error::AssetAlreadyTokenized))
+    );
+    assert_eq!(
+        s.client
+            .try_link_registry_asset(&2u64, &s.registry_id, &s.tokenizer),
+        Err(Ok(Error::AssetAlreadyTokenized))
+    );
+}
+
+#[test]
+fn an_asset_tokenized_before_links_is_linked_by_its_tokenizer() {
+    let env = create_env();
+    let s = setup(&env);
+    // Rewind to an asset tokenized before links were required.
+    env.as_contract(&s.client.address, || crate::tokenizer::clear(&env, 1));
+
+    // Someone else's registry asset cannot be claimed by the tokenizer, nor
+    // the shares by another registry owner.
+    let stranger = Address::generate(&env);
+    let strangers_id = generate_asset_id(&env, 2);
+    s.client.register_asset(
+        &create_test_asset(&env, &stranger, strangers_id.clone()),
+        &s.client.get_admin(),
+    );
+    assert_eq!(
+        s.client
+            .try_link_registry_asset(&1u64, &strangers_id, &s.tokenizer),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_link_registry_asset(&1u64, &strangers_id, &stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.client
+        .link_registry_asset(&1u64, &s.registry_id, &s.tokenizer);
+    assert_eq!(
+        s.client.get_linked_registry_asset(&1u64),
+        Some(s.registry_id.clone())
+    );
+}
@@ -10,6 +10,7 @@ fn test_add_to_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -20,6 +21,7 @@ fn test_add_to_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially not whitelisted
@@ -39,6 +41,7 @@ fn test_remove_from_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -49,6 +52,7 @@ fn test_remove_from_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add to whitelist
@@ -68,6 +72,7 @@ fn test_get_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -78,6 +83,7 @@ fn test_get_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add multiple addresses to whitelist
@@ -96,6 +102,7 @@ fn test_add_duplicate_to_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -106,6 +113,7 @@ fn test_add_duplicate_to_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add to whitelist twice
@@ -125,6 +133,7 @@ fn test_set_transfer_restriction() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -135,6 +144,7 @@ fn test_set_transfer_restriction() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set transfer restriction
@@ -151,6 +161,7 @@ fn test_transfer_with_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -161,6 +172,7 @@ fn test_transfer_with_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add user2 to whitelist
@@ -181,6 +193,7 @@ fn test_empty_whitelist() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -191,6 +204,7 @@ fn test_empty_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let whitelist = client.get_whitelist(&1u64);
@@ -208,6 +222,7 @@ fn test_transfer_to_non_whitelisted_fails() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 2u64, &user1);
     client.tokenize_asset(
         &2u64,
         &String::from_str(&env, "TST"),
@@ -218,6 +233,7 @@ fn test_transfer_to_non_whitelisted_fails() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Only user2 is whitelisted
@@ -235,6 +251,7 @@ fn test_empty_whitelist_allows_transfer() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 3u64, &user1);
     client.tokenize_asset(
         &3u64,
         &String::from_str(&env, "TST"),
@@ -245,6 +262,7 @@ fn test_empty_whitelist_allows_transfer() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // No whitelist — transfer should succeed
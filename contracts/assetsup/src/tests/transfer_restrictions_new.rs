@@ -29,6 +29,7 @@ fn setup_tokenized_asset(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -131,6 +132,7 @@ fn test_validate_transfer_no_restrictions() {
             asset_id,
             tokenizer.clone(),
             recipient.clone(),
+            100,
         )
         .unwrap()
     });
@@ -188,6 +190,7 @@ fn test_validate_transfer_blocked_when_not_whitelisted() {
             asset_id,
             tokenizer.clone(),
             whitelisted.clone(),
+            100,
         );
 
         // Transfer to non-whitelisted address should be blocked
@@ -196,6 +199,7 @@ fn test_validate_transfer_blocked_when_not_whitelisted() {
             asset_id,
             tokenizer.clone(),
             not_whitelisted.clone(),
+            100,
         );
 
         (allowed, blocked)
@@ -222,6 +226,7 @@ fn test_validate_transfer_empty_whitelist_allows_all() {
             asset_id,
             tokenizer.clone(),
             recipient.clone(),
+            100,
         )
     });
 
@@ -254,12 +259,14 @@ fn test_validate_transfer_accredited_required_uses_whitelist() {
             asset_id,
             tokenizer.clone(),
             accredited.clone(),
+            100,
         );
         let err = transfer_restrictions::validate_transfer(
             &env,
             asset_id,
             tokenizer.clone(),
             non_accredited.clone(),
+            100,
         );
         (ok, err)
     });
@@ -5,11 +5,13 @@
 //! `lib.rs` these fail: Soroban archives the entry and the asset simply
 //! disappears from the contract's view.
 
+use soroban_sdk::testutils::storage::Persistent as _;
 use soroban_sdk::testutils::{Address as _, Ledger as _, LedgerInfo};
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{Address, BytesN, Env, String, Val, Vec};
 
-use super::helpers::{create_env, create_test_asset, initialize_contract};
+use super::helpers::{create_env, create_test_asset, initialize_contract, registry_link};
 use crate::ttl::{LEDGERS_PER_DAY, PERSISTENT_EXTEND_TO};
+use crate::types::{AssetType, TokenDataKey};
 use crate::AssetUpContractClient;
 
 fn asset_id(env: &Env, seed: u8) -> BytesN<32> {
@@ -114,3 +116,127 @@ fn contract_configuration_survives_past_the_default_lifetime() {
     assert!(!client.is_paused());
     assert!(client.is_authorized_registrar(&admin));
 }
+
+/// Registers asset `seed` and leases it out, so it has a record, an owner
+/// index, an audit log, an active lease and the lease itself.
+fn leased_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    admin: &Address,
+    seed: u8,
+) -> BytesN<32> {
+    env.mock_all_auths();
+    let owner = Address::generate(env);
+    let id = asset_id(env, seed);
+    client.register_asset(&create_test_asset(env, &owner, id.clone()), admin);
+    client.create_lease(
+        &id,
+        &asset_id(env, seed + 100),
+        &owner,
+        &Address::generate(env),
+        &1u64,
+        &u64::MAX,
+        &10i128,
+        &100i128,
+    );
+    id
+}
+
+/// Remaining TTL of each key, read from inside the contract.
+fn ttls(env: &Env, client: &AssetUpContractClient, keys: &Vec<Val>) -> Vec<u32> {
+    env.as_contract(&client.address, || {
+        let mut ttls = Vec::new(env);
+        for key in keys.iter() {
+            ttls.push_back(env.storage().persistent().get_ttl(&key));
+        }
+        ttls
+    })
+}
+
+#[test]
+fn reading_leases_and_audit_logs_keeps_them_alive() {
+    let env = create_env();
+    let (client, admin) = setup(&env);
+    let id = leased_asset(&env, &client, &admin, 4);
+    let owner = client.get_asset(&id).owner;
+
+    for _ in 0..3 {
+        advance_ledgers(&env, 45 * LEDGERS_PER_DAY);
+        assert!(client.get_asset_active_lease(&id).is_some());
+        assert_eq!(client.get_asset_audit_logs(&id).len(), 1);
+        assert!(client.get_assets_by_owner(&owner).contains(&id));
+        assert_eq!(client.get_asset(&id).owner, owner);
+    }
+}
+
+#[test]
+fn the_keep_alive_extends_every_entry_of_a_registry_asset() {
+    let env = create_env();
+    let (client, admin) = setup(&env);
+    let id = leased_asset(&env, &client, &admin, 5);
+
+    let keys = client.get_asset_ttl_keys(&id);
+    assert_eq!(keys.len(), 5);
+
+    // Untouched for most of the window, the entries are close to expiry...
+    advance_ledgers(&env, 80 * LEDGERS_PER_DAY);
+    for ttl in ttls(&env, &client, &keys).iter() {
+        assert!(ttl <= 10 * LEDGERS_PER_DAY);
+    }
+
+    // ...until anyone renews them all to the full window.
+    env.set_auths(&[]);
+    assert_eq!(client.extend_asset_ttl(&id), 5);
+    for ttl in ttls(&env, &client, &keys).iter() {
+        assert_eq!(ttl, PERSISTENT_EXTEND_TO);
+    }
+
+    // Past the point the original lifetime would have run out.
+    advance_ledgers(&env, 80 * LEDGERS_PER_DAY);
+    assert!(client.get_asset_active_lease(&id).is_some());
+    assert_eq!(client.get_asset_audit_logs(&id).len(), 1);
+}
+
+#[test]
+fn the_token_keep_alive_covers_the_holders_on_a_page() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    env.mock_all_auths();
+    let tokenizer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    registry_link(&env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TTL"),
+        &1_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(&env, "Kept alive"),
+        &String::from_str(&env, "TTL test asset"),
+        &AssetType::Digital,
+        &None,
+    );
+    client.transfer_tokens(&1u64, &tokenizer, &holder, &100i128);
+
+    let keys = client.get_token_ttl_keys(&1u64, &0u32);
+    // Past the holder set, a page has no per-holder entries.
+    assert!(client.get_token_ttl_keys(&1u64, &1u32).len() < keys.len());
+
+    advance_ledgers(&env, 80 * LEDGERS_PER_DAY);
+    assert_eq!(client.extend_token_ttl(&1u64, &0u32), keys.len());
+    for ttl in ttls(&env, &client, &keys).iter() {
+        assert_eq!(ttl, PERSISTENT_EXTEND_TO);
+    }
+    env.as_contract(&client.address, || {
+        let record = TokenDataKey::TokenHolder(1, holder.clone());
+        assert_eq!(
+            env.storage().persistent().get_ttl(&record),
+            PERSISTENT_EXTEND_TO
+        );
+    });
+
+    advance_ledgers(&env, 80 * LEDGERS_PER_DAY);
+    assert_eq!(client.get_token_balance(&1u64, &holder), 100);
+    assert_eq!(client.get_token_balance(&1u64, &tokenizer), 900);
+}
@@ -7,15 +7,21 @@
 //! uploaded WASM, so `update_current_contract_wasm` itself cannot execute
 //! here. That step is verified by the deployment runbook in
 //! `contracts/UPGRADE.md`. What these tests cover is everything around it that
-//! could silently lose data: the admin gate on both entrypoints, the storage
-//! version stamp, migration idempotency, and that a full registry — including
-//! transferred and retired assets — reads back intact after migrating.
+//! could silently lose data: the admin gate and timelock on upgrades, the
+//! storage version stamp, migration idempotency, that a full registry —
+//! including transferred and retired assets — reads back intact after
+//! migrating, and that a batched migration resumes where it stopped.
 
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, BytesN, Env, String};
 
-use super::helpers::{create_env, create_test_asset, initialize_contract};
-use crate::upgrade::CURRENT_VERSION;
+use super::helpers::{create_env, create_test_asset, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::holders;
+use crate::types::{AssetType, TokenDataKey};
+use crate::upgrade::{
+    MigrationProgress, CURRENT_VERSION, DEFAULT_UPGRADE_DELAY, MIGRATION_BATCH, MIN_UPGRADE_DELAY,
+};
 use crate::{AssetUpContract, AssetUpContractClient};
 
 fn asset_id(env: &Env, seed: u8) -> BytesN<32> {
@@ -35,10 +41,9 @@ fn placeholder_wasm_hash(env: &Env) -> BytesN<32> {
 }
 
 #[test]
-fn upgrade_requires_the_admins_authorization() {
-    // The auth check runs before the WASM swap, so this is testable natively
-    // and is the gate that matters: an unauthenticated caller must never reach
-    // update_current_contract_wasm.
+fn scheduling_an_upgrade_requires_the_admins_authorization() {
+    // The auth check runs before anything is recorded, so an unauthenticated
+    // caller can never start the clock towards update_current_contract_wasm.
     let env = create_env();
     let admin = Address::generate(&env);
     let client = initialize_contract(&env, &admin);
@@ -46,12 +51,120 @@ fn upgrade_requires_the_admins_authorization() {
     let hash = placeholder_wasm_hash(&env);
 
     env.set_auths(&[]);
-    let res = client.try_upgrade(&hash);
+    let res = client.try_schedule_upgrade(&hash);
 
     assert!(
         res.is_err(),
         "replacing the contract WASM must require the admin"
     );
+    assert_eq!(client.get_pending_upgrade(), None);
+}
+
+// ---------------------------------------------------------------------------
+// Timelock
+// ---------------------------------------------------------------------------
+
+#[test]
+fn an_upgrade_cannot_be_installed_before_its_delay() {
+    let env = create_env();
+    let admin = Address::generate(&env);
+    let client = initialize_contract(&env, &admin);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let pending = client.schedule_upgrade(&placeholder_wasm_hash(&env));
+
+    assert_eq!(pending.scheduled_at, 1_000);
+    assert_eq!(pending.executable_at, 1_000 + DEFAULT_UPGRADE_DELAY);
+    assert_eq!(client.get_pending_upgrade(), Some(pending.clone()));
+    assert_eq!(
+        client.try_execute_upgrade(),
+        Err(Ok(Error::InvalidProposal))
+    );
+
+    // Once due, execution gets past every check and reaches the WASM swap,
+    // which cannot run natively (see the module docs).
+    env.ledger()
+        .with_mut(|l| l.timestamp = pending.executable_at);
+    assert!(matches!(client.try_execute_upgrade(), Err(Err(_))));
+}
+
+#[test]
+fn a_scheduled_upgrade_can_be_cancelled() {
+    let env = create_env();
+    let admin = Address::generate(&env);
+    let client = initialize_contract(&env, &admin);
+    let hash = placeholder_wasm_hash(&env);
+
+    client.schedule_upgrade(&hash);
+    // Only one upgrade may be pending.
+    assert_eq!(
+        client.try_schedule_upgrade(&hash),
+        Err(Ok(Error::InvalidProposal))
+    );
+
+    client.cancel_upgrade();
+
+    assert_eq!(client.get_pending_upgrade(), None);
+    assert_eq!(client.try_execute_upgrade(), Err(Ok(Error::NotFound)));
+    assert_eq!(client.try_cancel_upgrade(), Err(Ok(Error::NotFound)));
+    client.schedule_upgrade(&hash);
+}
+
+#[test]
+fn the_delay_has_a_floor_and_applies_to_later_schedules() {
+    let env = create_env();
+    let admin = Address::generate(&env);
+    let client = initialize_contract(&env, &admin);
+    assert_eq!(client.get_upgrade_delay(), DEFAULT_UPGRADE_DELAY);
+
+    assert_eq!(
+        client.try_set_upgrade_delay(&(MIN_UPGRADE_DELAY - 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let pending = client.schedule_upgrade(&placeholder_wasm_hash(&env));
+    client.set_upgrade_delay(&MIN_UPGRADE_DELAY);
+
+    assert_eq!(client.get_upgrade_delay(), MIN_UPGRADE_DELAY);
+    assert_eq!(client.get_pending_upgrade(), Some(pending));
+
+    env.set_auths(&[]);
+    assert!(client.try_set_upgrade_delay(&(7 * 86_400)).is_err());
+    assert!(client.try_cancel_upgrade().is_err());
+    assert!(client.try_execute_upgrade().is_err());
+}
+
+#[test]
+fn each_installed_hash_becomes_the_next_rollback_target() {
+    // The swap itself cannot run natively, so drive the bookkeeping directly.
+    let env = create_env();
+    let admin = Address::generate(&env);
+    let client = initialize_contract(&env, &admin);
+    let (first, second) = (
+        BytesN::from_array(&env, &[1u8; 32]),
+        BytesN::from_array(&env, &[2u8; 32]),
+    );
+    assert_eq!(client.get_previous_wasm_hash(), None);
+
+    let install = |hash: &BytesN<32>| {
+        let pending = client.schedule_upgrade(hash);
+        env.ledger()
+            .with_mut(|l| l.timestamp = pending.executable_at);
+        env.as_contract(&client.address, || {
+            assert_eq!(crate::upgrade::take_due(&env), Ok(hash.clone()));
+        });
+    };
+
+    install(&first);
+    // The originally deployed hash is unknown.
+    assert_eq!(client.get_previous_wasm_hash(), None);
+
+    install(&second);
+    assert_eq!(client.get_previous_wasm_hash(), Some(first.clone()));
+
+    // Rolling back is scheduling the previous hash.
+    install(&first);
+    assert_eq!(client.get_previous_wasm_hash(), Some(second));
 }
 
 #[test]
@@ -141,7 +254,7 @@ fn migrate_requires_the_admins_authorization() {
 }
 
 #[test]
-fn a_freshly_registered_contract_reports_the_current_version() {
+fn an_unversioned_contract_reports_version_one() {
     // Contracts initialized before versioning existed have no stored value and
     // are treated as the layout they were written with, rather than as
     // version 0 needing an imaginary migration.
@@ -149,7 +262,7 @@ fn a_freshly_registered_contract_reports_the_current_version() {
     let contract_id = env.register(AssetUpContract, ());
     let client = AssetUpContractClient::new(&env, &contract_id);
 
-    assert_eq!(client.storage_version(), CURRENT_VERSION);
+    assert_eq!(client.storage_version(), 1);
 }
 
 #[test]
@@ -159,3 +272,151 @@ fn migrating_from_a_future_version_is_refused() {
     let env = create_env();
     assert!(crate::upgrade::migrate_from(&env, CURRENT_VERSION + 1).is_err());
 }
+
+// ---------------------------------------------------------------------------
+// Batched migration
+// ---------------------------------------------------------------------------
+
+/// Tokenizes asset 1, spreads it over `holders` addresses besides the
+/// tokenizer, then rewinds the contract to the v1 layout: one legacy holder
+/// list, which also names an address that has since sold out.
+fn v1_contract_with_holders(env: &Env, holders: u32) -> (AssetUpContractClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "MIG"),
+        &1_000_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Migrated"),
+        &String::from_str(env, "Migration test asset"),
+        &AssetType::Digital,
+        &None,
+    );
+
+    let mut legacy = vec![env, tokenizer.clone()];
+    for _ in 0..holders {
+        let holder = Address::generate(env);
+        client.transfer_tokens(&1u64, &tokenizer, &holder, &10i128);
+        legacy.push_back(holder);
+    }
+    legacy.push_back(Address::generate(env));
+
+    env.as_contract(&client.address, || {
+        holders::clear(env, 1);
+        env.storage()
+            .persistent()
+            .set(&TokenDataKey::TokenHoldersList(1), &legacy);
+        crate::upgrade::set_version(env, 1);
+    });
+
+    (client, tokenizer)
+}
+
+#[test]
+fn a_large_migration_resumes_from_its_cursor() {
+    let env = create_env();
+    let (client, _) = v1_contract_with_holders(&env, 120);
+    // Asset 2 was never tokenized: queued in error, it costs one unit.
+    client.queue_migration(&vec![&env, 1u64, 2u64]);
+
+    assert_eq!(client.migrate(), 1);
+    assert_eq!(
+        client.get_migration_progress(),
+        MigrationProgress {
+            stored_version: 1,
+            target_version: CURRENT_VERSION,
+            queued: 2,
+            cursor: MIGRATION_BATCH,
+        }
+    );
+    // Half converted, the asset still reads as before.
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 121);
+
+    assert_eq!(client.migrate(), 1);
+    assert_eq!(client.get_migration_progress().cursor, 2 * MIGRATION_BATCH);
+
+    // 122 legacy entries: the third call finishes them and the queue.
+    assert_eq!(client.migrate(), CURRENT_VERSION);
+    assert_eq!(
+        client.get_migration_progress(),
+        MigrationProgress {
+            stored_version: CURRENT_VERSION,
+            target_version: CURRENT_VERSION,
+            queued: 0,
+            cursor: 0,
+        }
+    );
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&TokenDataKey::TokenHoldersList(1)));
+    });
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 121);
+    assert_eq!(client.get_token_holders(&1u64).len(), 121);
+}
+
+#[test]
+fn writes_are_refused_until_the_migration_finishes() {
+    let env = create_env();
+    let (client, tokenizer) = v1_contract_with_holders(&env, 60);
+    let buyer = Address::generate(&env);
+    client.queue_migration(&vec![&env, 1u64]);
+
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &tokenizer, &buyer, &1i128),
+        Err(Ok(Error::MigrationInProgress))
+    );
+    client.migrate();
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &tokenizer, &buyer, &1i128),
+        Err(Ok(Error::MigrationInProgress))
+    );
+    // Reads are served throughout.
+    assert_eq!(client.get_token_balance(&1u64, &tokenizer), 1_000_000 - 600);
+
+    assert_eq!(client.migrate(), CURRENT_VERSION);
+    client.transfer_tokens(&1u64, &tokenizer, &buyer, &1i128);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 62);
+}
+
+#[test]
+fn an_asset_left_off_the_queue_stays_frozen_until_queued() {
+    let env = create_env();
+    let (client, tokenizer) = v1_contract_with_holders(&env, 60);
+    let buyer = Address::generate(&env);
+
+    env.set_auths(&[]);
+    assert!(client.try_queue_migration(&vec![&env, 1u64]).is_err());
+    env.mock_all_auths();
+
+    // Nothing queued: the step finishes at once, but the unqueued legacy list
+    // is not converted behind a transfer's back.
+    assert_eq!(client.migrate(), CURRENT_VERSION);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &tokenizer, &buyer, &1i128),
+        Err(Ok(Error::MigrationInProgress))
+    );
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 61);
+
+    // Queued late, it is converted in batches like any other.
+    client.queue_migration(&vec![&env, 1u64]);
+    assert_eq!(client.migrate(), CURRENT_VERSION);
+    assert_eq!(client.get_migration_progress().cursor, MIGRATION_BATCH);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &tokenizer, &buyer, &1i128),
+        Err(Ok(Error::MigrationInProgress))
+    );
+    client.migrate();
+    assert_eq!(client.get_migration_progress().queued, 0);
+
+    client.transfer_tokens(&1u64, &tokenizer, &buyer, &1i128);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 62);
+}
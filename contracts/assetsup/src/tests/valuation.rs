@@ -0,0 +1,217 @@
+//! Oracle-fed valuation tests.
+//!
+//! The property that matters: **no single address can move a valuation on its
+//! own.** Only approved oracles submit, stale and outlying submissions do not
+//! count, and the admin override is authenticated and leaves a history entry.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::types::AssetType;
+use crate::valuation::{DEFAULT_FRESHNESS_WINDOW, MAX_VALUATION_HISTORY};
+use crate::AssetUpContractClient;
+
+fn report(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Tokenizes asset 1 with 1,000 whole tokens at 2 decimals.
+fn setup(env: &Env) -> (AssetUpContractClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "VAL"),
+        &100_000i128,
+        &2u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Valued"),
+        &String::from_str(env, "An oracle-valued asset"),
+        &AssetType::Physical,
+        &None,
+    );
+
+    (client, admin)
+}
+
+fn oracles(env: &Env, client: &AssetUpContractClient, n: usize) -> std::vec::Vec<Address> {
+    (0..n)
+        .map(|_| {
+            let oracle = Address::generate(env);
+            client.add_valuation_oracle(&oracle);
+            oracle
+        })
+        .collect()
+}
+
+#[test]
+fn an_unapproved_address_cannot_submit() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_submit_valuation(&1u64, &stranger, &5_000i128, &report(&env, 1));
+    assert_eq!(res, Err(Ok(crate::error::Error::OracleNotAuthorized)));
+}
+
+#[test]
+fn a_single_oracle_cannot_set_the_valuation_under_the_default_config() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    let o = oracles(&env, &client, 2);
+
+    client.submit_valuation(&1u64, &o[0], &5_000i128, &report(&env, 1));
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 100_000);
+    assert!(client.get_valuation_history(&1u64).is_empty());
+
+    // The median of two rounds down.
+    assert_eq!(
+        client.submit_valuation(&1u64, &o[1], &5_101i128, &report(&env, 2)),
+        5_050
+    );
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 5_050);
+
+    let history = client.get_valuation_history(&1u64);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().submissions, 2);
+}
+
+#[test]
+fn the_median_of_fresh_submissions_is_applied() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&DEFAULT_FRESHNESS_WINDOW, &1_000u32, &3u32);
+    let o = oracles(&env, &client, 3);
+
+    client.submit_valuation(&1u64, &o[0], &1_000i128, &report(&env, 1));
+    client.submit_valuation(&1u64, &o[1], &1_060i128, &report(&env, 2));
+    // Quorum not met yet: the tokenization-time valuation is untouched.
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 100_000);
+    assert!(client.get_valuation_history(&1u64).is_empty());
+
+    client.submit_valuation(&1u64, &o[2], &1_020i128, &report(&env, 3));
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 1_020);
+}
+
+#[test]
+fn an_outlier_is_excluded_from_the_aggregate() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&DEFAULT_FRESHNESS_WINDOW, &500u32, &2u32);
+    let o = oracles(&env, &client, 3);
+
+    client.submit_valuation(&1u64, &o[0], &1_000i128, &report(&env, 1));
+    client.submit_valuation(&1u64, &o[1], &1_010i128, &report(&env, 2));
+    // A wildly high report from one oracle must not move the figure.
+    client.submit_valuation(&1u64, &o[2], &9_000i128, &report(&env, 3));
+
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 1_005);
+    let last = client.get_valuation_history(&1u64).last().unwrap();
+    assert_eq!(last.submissions, 2);
+    assert_eq!(last.rejected, 1);
+}
+
+#[test]
+fn stale_submissions_stop_counting() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&3_600u64, &1_000u32, &2u32);
+    let o = oracles(&env, &client, 2);
+
+    client.submit_valuation(&1u64, &o[0], &1_000i128, &report(&env, 1));
+    env.ledger().with_mut(|l| l.timestamp += 3_601);
+    client.submit_valuation(&1u64, &o[1], &1_050i128, &report(&env, 2));
+
+    // The first report has aged out, so quorum is not met.
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 100_000);
+}
+
+#[test]
+fn a_removed_oracles_submission_stops_counting() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&DEFAULT_FRESHNESS_WINDOW, &1_000u32, &2u32);
+    let o = oracles(&env, &client, 3);
+
+    client.submit_valuation(&1u64, &o[0], &1_000i128, &report(&env, 1));
+    client.remove_valuation_oracle(&o[0]);
+    client.submit_valuation(&1u64, &o[1], &1_050i128, &report(&env, 2));
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 100_000);
+
+    client.submit_valuation(&1u64, &o[2], &1_070i128, &report(&env, 3));
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 1_060);
+}
+
+#[test]
+fn history_is_capped() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&DEFAULT_FRESHNESS_WINDOW, &1_000u32, &1u32);
+    let o = oracles(&env, &client, 1);
+
+    for i in 0..(MAX_VALUATION_HISTORY + 5) {
+        client.submit_valuation(&1u64, &o[0], &(1_000 + i as i128), &report(&env, 1));
+    }
+
+    let history = client.get_valuation_history(&1u64);
+    assert_eq!(history.len(), MAX_VALUATION_HISTORY);
+    assert_eq!(history.get(0).unwrap().value, 1_005);
+}
+
+#[test]
+fn nav_per_token_scales_by_decimals() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    client.set_valuation_config(&DEFAULT_FRESHNESS_WINDOW, &1_000u32, &1u32);
+    let o = oracles(&env, &client, 1);
+
+    // 100,000 base units at 2 decimals is 1,000 whole tokens.
+    client.submit_valuation(&1u64, &o[0], &2_000_000i128, &report(&env, 1));
+    assert_eq!(client.get_nav_per_token(&1u64), 2_000);
+}
+
+#[test]
+fn the_admin_override_requires_the_admin_and_is_recorded() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+
+    client.update_valuation(&1u64, &7_777i128);
+    let last = client.get_valuation_history(&1u64).last().unwrap();
+    assert_eq!(last.value, 7_777);
+    assert_eq!(last.submissions, 0);
+
+    env.set_auths(&[]);
+    assert!(
+        client.try_update_valuation(&1u64, &8_888i128).is_err(),
+        "overwriting a valuation must require the admin"
+    );
+}
+
+#[test]
+fn oracle_management_requires_the_admin() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+    env.set_auths(&[]);
+
+    assert!(client
+        .try_add_valuation_oracle(&Address::generate(&env))
+        .is_err());
+    assert!(client
+        .try_set_valuation_config(&60u64, &100u32, &1u32)
+        .is_err());
+}
+
+#[test]
+fn a_zero_quorum_is_rejected() {
+    let env = create_env();
+    let (client, _) = setup(&env);
+
+    let res = client.try_set_valuation_config(&60u64, &100u32, &0u32);
+    assert_eq!(res, Err(Ok(crate::error::Error::InvalidInput)));
+}
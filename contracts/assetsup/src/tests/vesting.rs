@@ -0,0 +1,313 @@
+//! Vesting grant tests.
+//!
+//! The property that matters: **a grant locks only its unvested tokens.** The
+//! rest of the holder's balance moves freely, the vested part becomes
+//! transferable on schedule, and the asset's `locked_tokens` reports the
+//! unvested total.
+
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, String, Vec};
+
+use super::helpers::{create_env, initialize_contract, registry_link};
+use crate::error::Error;
+use crate::types::{AssetType, TokenDataKey, TokenizedAsset};
+use crate::vesting::{DataKey, VestingRelease};
+use crate::AssetUpContractClient;
+
+const START: u64 = 1_000;
+const CLIFF: u64 = 2_000;
+const END: u64 = 5_000;
+
+fn set_time(env: &Env, t: u64) {
+    env.ledger().with_mut(|l| l.timestamp = t);
+}
+
+fn setup(env: &Env) -> (AssetUpContractClient<'_>, Address) {
+    let admin = Address::generate(env);
+    let client = initialize_contract(env, &admin);
+    env.mock_all_auths();
+    set_time(env, START);
+
+    let tokenizer = Address::generate(env);
+    registry_link(env, &client, 1u64, &tokenizer);
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(env, "VEST"),
+        &10_000i128,
+        &0u32,
+        &1i128,
+        &tokenizer,
+        &String::from_str(env, "Vested"),
+        &String::from_str(env, "Vesting test asset"),
+        &AssetType::Digital,
+        &None,
+    );
+
+    (client, tokenizer)
+}
+
+fn grant(
+    client: &AssetUpContractClient,
+    tokenizer: &Address,
+    holder: &Address,
+    release: &VestingRelease,
+    revocable: bool,
+) -> u32 {
+    client.create_vesting_grant(
+        &1u64, holder, &4_000i128, &START, &CLIFF, &END, release, &revocable, tokenizer,
+    )
+}
+
+#[test]
+fn nothing_vests_before_the_cliff() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    grant(&client, &tokenizer, &holder, &VestingRelease::Linear, false);
+
+    set_time(&env, CLIFF - 1);
+    assert_eq!(client.get_unvested_balance(&1u64, &holder), 4_000);
+    let res = client.try_transfer_tokens(&1u64, &holder, &tokenizer, &1i128);
+    assert_eq!(res, Err(Ok(Error::TokensAreLocked)));
+}
+
+#[test]
+fn linear_release_unlocks_proportionally() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    grant(&client, &tokenizer, &holder, &VestingRelease::Linear, false);
+
+    // Halfway between start and end: half of 4,000 has vested.
+    set_time(&env, 3_000);
+    assert_eq!(client.get_unvested_balance(&1u64, &holder), 2_000);
+
+    client.transfer_tokens(&1u64, &holder, &buyer, &2_000i128);
+    let res = client.try_transfer_tokens(&1u64, &holder, &buyer, &1i128);
+    assert_eq!(res, Err(Ok(Error::TokensAreLocked)));
+
+    set_time(&env, END);
+    client.transfer_tokens(&1u64, &holder, &buyer, &2_000i128);
+    assert_eq!(client.get_token_balance(&1u64, &buyer), 4_000);
+}
+
+#[test]
+fn stepped_release_unlocks_in_tranches() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    // Four 1,000-second steps over the 4,000-second schedule.
+    grant(
+        &client,
+        &tokenizer,
+        &holder,
+        &VestingRelease::Stepped(1_000),
+        false,
+    );
+
+    set_time(&env, 2_999);
+    assert_eq!(client.get_unvested_balance(&1u64, &holder), 3_000);
+    set_time(&env, 3_000);
+    assert_eq!(client.get_unvested_balance(&1u64, &holder), 2_000);
+}
+
+#[test]
+fn only_the_unvested_part_of_a_balance_is_blocked() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    // Tokens received outside the grant stay freely transferable.
+    client.transfer_tokens(&1u64, &tokenizer, &holder, &500i128);
+    grant(&client, &tokenizer, &holder, &VestingRelease::Linear, false);
+
+    client.transfer_tokens(&1u64, &holder, &buyer, &500i128);
+    assert_eq!(client.get_token_balance(&1u64, &holder), 4_000);
+}
+
+#[test]
+fn locked_tokens_tracks_the_unvested_total() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    grant(&client, &tokenizer, &a, &VestingRelease::Linear, false);
+    grant(&client, &tokenizer, &b, &VestingRelease::Linear, false);
+
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 8_000);
+    set_time(&env, 3_000);
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 4_000);
+    set_time(&env, END);
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 0);
+}
+
+#[test]
+fn the_stored_total_is_checkpointed_and_fully_vested_grantees_are_dropped() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    grant(&client, &tokenizer, &a, &VestingRelease::Linear, false);
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 4_000);
+
+    // Reading the asset gives the total as of now, while the stored
+    // checkpoint still holds the total as of the grant...
+    set_time(&env, END);
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 0);
+    env.as_contract(&client.address, || {
+        let stored: TokenizedAsset = env
+            .storage()
+            .persistent()
+            .get(&TokenDataKey::TokenizedAsset(1))
+            .unwrap();
+        assert_eq!(stored.locked_tokens, 4_000);
+    });
+
+    // ...until the next grant, which also drops `a`, whose grant has fully
+    // vested.
+    client.create_vesting_grant(
+        &1u64,
+        &b,
+        &1_000i128,
+        &END,
+        &END,
+        &(END + 1_000),
+        &VestingRelease::Linear,
+        &false,
+        &tokenizer,
+    );
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 1_000);
+    env.as_contract(&client.address, || {
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Holders(1))
+            .unwrap();
+        assert_eq!(grantees, vec![&env, b.clone()]);
+    });
+
+    // A dropped grantee rejoins with a new grant.
+    client.create_vesting_grant(
+        &1u64,
+        &a,
+        &1_000i128,
+        &END,
+        &END,
+        &(END + 1_000),
+        &VestingRelease::Linear,
+        &false,
+        &tokenizer,
+    );
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 2_000);
+}
+
+#[test]
+fn revoking_returns_unvested_tokens_to_the_tokenizer() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    let id = grant(&client, &tokenizer, &holder, &VestingRelease::Linear, true);
+
+    set_time(&env, 3_000);
+    assert_eq!(
+        client.revoke_vesting_grant(&1u64, &holder, &id, &tokenizer),
+        2_000
+    );
+    assert_eq!(client.get_token_balance(&1u64, &holder), 2_000);
+    assert_eq!(client.get_token_balance(&1u64, &tokenizer), 8_000);
+    assert_eq!(client.get_unvested_balance(&1u64, &holder), 0);
+    assert_eq!(client.get_tokenized_asset(&1u64).locked_tokens, 0);
+
+    // The vested half is now the holder's to move.
+    client.transfer_tokens(&1u64, &holder, &tokenizer, &2_000i128);
+
+    let again = client.try_revoke_vesting_grant(&1u64, &holder, &id, &tokenizer);
+    assert_eq!(again, Err(Ok(Error::InsufficientLockedTokens)));
+}
+
+#[test]
+fn an_irrevocable_grant_cannot_be_revoked() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    let id = grant(&client, &tokenizer, &holder, &VestingRelease::Linear, false);
+
+    let res = client.try_revoke_vesting_grant(&1u64, &holder, &id, &tokenizer);
+    assert_eq!(res, Err(Ok(Error::VestingNotRevocable)));
+}
+
+#[test]
+fn only_the_tokenizer_can_grant_or_revoke() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    let intruder = Address::generate(&env);
+
+    let res = client.try_create_vesting_grant(
+        &1u64,
+        &holder,
+        &100i128,
+        &START,
+        &CLIFF,
+        &END,
+        &VestingRelease::Linear,
+        &true,
+        &intruder,
+    );
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    let id = grant(&client, &tokenizer, &holder, &VestingRelease::Linear, true);
+    let res = client.try_revoke_vesting_grant(&1u64, &holder, &id, &intruder);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn an_inconsistent_schedule_is_rejected() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+
+    // Cliff after end.
+    let res = client.try_create_vesting_grant(
+        &1u64,
+        &holder,
+        &100i128,
+        &START,
+        &(END + 1),
+        &END,
+        &VestingRelease::Linear,
+        &true,
+        &tokenizer,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidTimestamps)));
+
+    // A zero-length step.
+    let res = client.try_create_vesting_grant(
+        &1u64,
+        &holder,
+        &100i128,
+        &START,
+        &CLIFF,
+        &END,
+        &VestingRelease::Stepped(0),
+        &true,
+        &tokenizer,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidTimestamps)));
+}
+
+#[test]
+fn unlock_tokens_requires_the_tokenizer() {
+    let env = create_env();
+    let (client, tokenizer) = setup(&env);
+    let holder = Address::generate(&env);
+    client.lock_tokens(&1u64, &holder, &(START + 100), &tokenizer);
+
+    env.set_auths(&[]);
+    assert!(
+        client.try_unlock_tokens(&1u64, &holder).is_err(),
+        "lifting a lock must require the tokenizer"
+    );
+}
@@ -11,6 +11,7 @@ fn test_cast_vote_success() {
     env.mock_all_auths();
 
     // Tokenize asset
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -21,6 +22,7 @@ fn test_cast_vote_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Cast vote
@@ -43,6 +45,7 @@ fn test_cast_vote_already_voted() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -53,6 +56,7 @@ fn test_cast_vote_already_voted() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.cast_vote(&1u64, &1u64, &user1);
@@ -71,6 +75,10 @@ fn test_cast_vote_insufficient_voting_power() {
     env.mock_all_auths();
 
     // Tokenize with high voting threshold
+    registry_link(
+        &env, &client, 1u64, // 50% threshold
+        &user1,
+    );
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -81,6 +89,7 @@ fn test_cast_vote_insufficient_voting_power() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer small amount to user2
@@ -98,6 +107,7 @@ fn test_proposal_passed() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -108,6 +118,7 @@ fn test_proposal_passed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 60% to user2
@@ -128,6 +139,7 @@ fn test_proposal_not_passed() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -138,6 +150,7 @@ fn test_proposal_not_passed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 40% to user2
@@ -158,6 +171,7 @@ fn test_multiple_voters() {
 
     env.mock_all_auths();
 
+    registry_link(&env, &client, 1u64, &user1);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -168,6 +182,7 @@ fn test_multiple_voters() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Distribute tokens
@@ -0,0 +1,124 @@
+//! SEP-41 token contracts for tokenized assets.
+//!
+//! Fractional balances live in this contract's `OwnershipRecord`s, which no
+//! wallet, DEX or other Soroban contract knows how to read. Each tokenized
+//! asset can therefore be given its own `asset-token` contract: a standard
+//! SEP-41 token (`balance`, `transfer`, `transfer_from`, `approve`, ...) whose
+//! balances are the asset's shares.
+//!
+//! The token contract holds no balances of its own. It authenticates the
+//! holder or spender, keeps SEP-41 allowances, and forwards every movement to
+//! [`AssetUpContract::sep41_transfer`] or [`AssetUpContract::sep41_burn`].
+//! Those apply exactly the checks `transfer_tokens` does — pause, transfer
+//! restrictions, locks and vesting — so the token interface is not a way
+//! around any of them.
+//!
+//! ## Binding
+//!
+//! The admin sets the `asset-token` WASM hash once with
+//! `set_token_wasm_hash`. From then on `tokenize_asset` deploys a token
+//! contract for each new asset, at an address derived from this contract and
+//! the asset id. Assets tokenized before the hash was set get one through
+//! `deploy_token_contract`.
+//!
+//! The binding is permanent. The forwarding entrypoints trust only the bound
+//! address, and it survives detokenization, so re-tokenizing the same asset id
+//! reuses the existing token contract rather than failing to redeploy at the
+//! same address.
+//!
+//! [`AssetUpContract::sep41_transfer`]: crate::AssetUpContract::sep41_transfer
+//! [`AssetUpContract::sep41_burn`]: crate::AssetUpContract::sep41_burn
+
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::audit;
+use crate::error::Error;
+use crate::tokenization::asset_id_to_bytes;
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+use crate::DataKey;
+
+pub fn set_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenWasmHash, wasm_hash);
+    ttl::extend_persistent(env, &DataKey::TokenWasmHash);
+}
+
+pub fn get_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    ttl::extend_persistent(env, &DataKey::TokenWasmHash);
+    env.storage().persistent().get(&DataKey::TokenWasmHash)
+}
+
+pub fn get_contract(env: &Env, asset_id: u64) -> Option<Address> {
+    let key = TokenDataKey::TokenContract(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+/// Deploys the asset's token contract if a WASM hash is configured and the
+/// asset has none yet. Returns the bound address, if any.
+///
+/// Called from `tokenize_asset`, where a missing hash is not an error: the
+/// asset simply has no SEP-41 interface until one is deployed.
+pub fn deploy_if_configured(env: &Env, asset_id: u64) -> Option<Address> {
+    if let Some(existing) = get_contract(env, asset_id) {
+        return Some(existing);
+    }
+    let wasm_hash = get_wasm_hash(env)?;
+    Some(deploy(env, asset_id, wasm_hash))
+}
+
+/// Deploys the token contract for an asset tokenized before the WASM hash was
+/// set. Only the tokenizer can deploy.
+pub fn deploy_for_asset(env: &Env, asset_id: u64, tokenizer: Address) -> Result<Address, Error> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != tokenizer {
+        return Err(Error::Unauthorized);
+    }
+    if get_contract(env, asset_id).is_some() {
+        return Err(Error::AlreadyInitialized);
+    }
+    let wasm_hash = get_wasm_hash(env).ok_or(Error::NotFound)?;
+
+    Ok(deploy(env, asset_id, wasm_hash))
+}
+
+/// Authenticates the asset's bound token contract as the caller.
+///
+/// A contract authorizes the calls it makes directly, so this passes only
+/// when the bound token contract itself is invoking us.
+pub fn require_token_contract(env: &Env, asset_id: u64) -> Result<(), Error> {
+    let token = get_contract(env, asset_id).ok_or(Error::NotFound)?;
+    token.require_auth();
+    Ok(())
+}
+
+fn deploy(env: &Env, asset_id: u64, wasm_hash: BytesN<32>) -> Address {
+    let salt = asset_id_to_bytes(env, asset_id);
+    let token = env
+        .deployer()
+        .with_current_contract(salt.clone())
+        .deploy_v2(wasm_hash, (env.current_contract_address(), asset_id));
+
+    let key = TokenDataKey::TokenContract(asset_id);
+    env.storage().persistent().set(&key, &token);
+    ttl::extend_persistent(env, &key);
+
+    audit::append_audit_log(
+        env,
+        &salt,
+        String::from_str(env, "TOKEN_CONTRACT_DEPLOYED"),
+        env.current_contract_address(),
+        String::from_str(env, "SEP-41 token contract deployed"),
+    );
+
+    crate::events::token_contract_deployed(env, asset_id, &token);
+
+    token
+}
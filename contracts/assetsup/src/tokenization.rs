@@ -1,11 +1,15 @@
 use crate::audit;
 use crate::error::Error;
+use crate::holders;
 use crate::math;
-use crate::types::{OwnershipRecord, TokenDataKey, TokenMetadata, TokenizedAsset};
+use crate::ttl;
+use crate::types::{
+    OwnershipRecord, TokenDataKey, TokenMetadata, TokenizedAsset, TransferableBalance,
+};
 use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 /// Helper function to convert u64 asset_id to BytesN<32> for audit logging
-fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
+pub(crate) fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
     let mut bytes = [0u8; 32];
     let id_bytes = asset_id.to_be_bytes();
     // Place the u64 bytes at the end of the 32-byte array
@@ -13,8 +17,16 @@ fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
+/// Largest `decimals` a tokenized asset may use, as for most token standards.
+/// Beyond it, one whole share (`10^decimals` units) leaves little room in an
+/// i128 for prices and payouts.
+pub const MAX_DECIMALS: u32 = 18;
+
 /// Initialize tokenization by creating tokenized asset
 /// Only contract admin or asset owner can tokenize
+///
+/// `max_supply`, if given, caps `total_supply` for the life of the asset and
+/// cannot be changed afterwards.
 #[allow(clippy::too_many_arguments)]
 pub fn tokenize_asset(
     env: &Env,
@@ -25,11 +37,20 @@ pub fn tokenize_asset(
     min_voting_threshold: i128,
     tokenizer: Address,
     metadata: TokenMetadata,
+    max_supply: Option<i128>,
 ) -> Result<TokenizedAsset, Error> {
     // Validate inputs
     if total_supply <= 0 {
         return Err(Error::InvalidTokenSupply);
     }
+    if let Some(cap) = max_supply {
+        if cap < total_supply {
+            return Err(Error::InvalidTokenSupply);
+        }
+    }
+    if decimals > MAX_DECIMALS {
+        return Err(Error::InvalidTokenDecimals);
+    }
 
     // Check if asset is already tokenized
     let store = env.storage().persistent();
@@ -58,10 +79,19 @@ pub fn tokenize_asset(
 
     // Store tokenized asset
     store.set(&key, &tokenized_asset);
+    ttl::extend_persistent(env, &key);
 
     // Store metadata
     let metadata_key = TokenDataKey::TokenMetadata(asset_id);
     store.set(&metadata_key, &metadata);
+    ttl::extend_persistent(env, &metadata_key);
+    crate::metadata::record_tokenization(env, asset_id, &metadata, &tokenizer);
+
+    if let Some(cap) = max_supply {
+        let cap_key = TokenDataKey::MaxSupply(asset_id);
+        store.set(&cap_key, &cap);
+        ttl::extend_persistent(env, &cap_key);
+    }
 
     // Initialize tokenizer as first holder with full supply
     let ownership = OwnershipRecord {
@@ -77,12 +107,10 @@ pub fn tokenize_asset(
 
     let holder_key = TokenDataKey::TokenHolder(asset_id, tokenizer.clone());
     store.set(&holder_key, &ownership);
+    ttl::extend_persistent(env, &holder_key);
 
-    // Initialize token holders list
-    let mut holders: Vec<Address> = Vec::new(env);
-    holders.push_back(tokenizer.clone());
-    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
-    store.set(&holders_list_key, &holders);
+    // The tokenizer is the first holder
+    holders::sync(env, asset_id, &tokenizer, total_supply)?;
 
     // Append audit log (convert u64 asset_id to BytesN<32>)
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
@@ -100,7 +128,7 @@ pub fn tokenize_asset(
     Ok(tokenized_asset)
 }
 
-/// Mint additional tokens
+/// Mint additional tokens to the tokenizer
 /// Only tokenizer can mint
 pub fn mint_tokens(
     env: &Env,
@@ -117,31 +145,15 @@ pub fn mint_tokens(
 
     // Get tokenized asset
     let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Only tokenizer can mint
     if tokenized_asset.tokenizer != minter {
         return Err(Error::Unauthorized);
     }
 
-    // Update total supply
-    tokenized_asset.total_supply = math::add(tokenized_asset.total_supply, amount)?;
-    tokenized_asset.tokens_in_circulation =
-        math::add(tokenized_asset.tokens_in_circulation, amount)?;
-
-    // Update tokenizer's ownership
-    let holder_key = TokenDataKey::TokenHolder(asset_id, minter.clone());
-    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
-
-    ownership.balance = math::add(ownership.balance, amount)?;
-    ownership.voting_power = ownership.balance;
-    ownership.dividend_entitlement = ownership.balance;
-
-    // Recalculate ownership percentage
-    ownership.ownership_percentage =
-        math::mul_div(ownership.balance, 10000, tokenized_asset.total_supply)?;
-
-    store.set(&holder_key, &ownership);
-    store.set(&key, &tokenized_asset.clone());
+    // The tokenizer may have transferred everything away since tokenizing
+    mint_balance(env, &mut tokenized_asset, &minter, amount)?;
 
     // Append audit log
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
@@ -153,12 +165,64 @@ pub fn mint_tokens(
         String::from_str(env, "Tokens minted"),
     );
 
-    // Emit event: (asset_id, amount, new_supply)
-    crate::events::tokens_minted(env, asset_id, amount, tokenized_asset.total_supply);
+    // Emit event: (asset_id, to, amount, new_supply)
+    crate::events::tokens_minted(env, asset_id, &minter, amount, tokenized_asset.total_supply);
 
     Ok(tokenized_asset)
 }
 
+/// Mint new tokens straight to `recipient`, as if the tokenizer had minted
+/// and then transferred them: the recipient must not be frozen and the
+/// asset's transfer restrictions must allow them to receive from the
+/// tokenizer. Authorization is the caller's responsibility.
+pub fn mint_to(
+    env: &Env,
+    asset_id: u64,
+    recipient: Address,
+    amount: i128,
+) -> Result<TokenizedAsset, Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidTokenSupply);
+    }
+
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+    let tokenizer = tokenized_asset.tokenizer.clone();
+
+    crate::compliance::require_not_frozen(env, asset_id, &recipient)?;
+    crate::transfer_restrictions::validate_issue(env, asset_id, recipient.clone(), amount)?;
+
+    mint_balance(env, &mut tokenized_asset, &recipient, amount)?;
+    crate::holding::record(env, &tokenized_asset, &recipient, amount);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "TOKENS_MINTED"),
+        tokenizer,
+        String::from_str(env, "Tokens minted to recipient"),
+    );
+
+    crate::events::tokens_minted(
+        env,
+        asset_id,
+        &recipient,
+        amount,
+        tokenized_asset.total_supply,
+    );
+
+    Ok(tokenized_asset)
+}
+
+/// The cap on an asset's total supply, if it was tokenized with one.
+pub fn get_max_supply(env: &Env, asset_id: u64) -> Option<i128> {
+    let key = TokenDataKey::MaxSupply(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
 /// Burn tokens
 /// Only tokenizer can burn, and only from their own account
 pub fn burn_tokens(
@@ -176,35 +240,14 @@ pub fn burn_tokens(
 
     // Get tokenized asset
     let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Only tokenizer can burn
     if tokenized_asset.tokenizer != burner {
         return Err(Error::Unauthorized);
     }
 
-    // Get burner's balance
-    let holder_key = TokenDataKey::TokenHolder(asset_id, burner.clone());
-    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
-
-    if ownership.balance < amount {
-        return Err(Error::InsufficientBalance);
-    }
-
-    // Update balances
-    ownership.balance = math::sub(ownership.balance, amount)?;
-    ownership.voting_power = ownership.balance;
-    ownership.dividend_entitlement = ownership.balance;
-
-    // Recalculate ownership percentage
-    ownership.ownership_percentage =
-        math::mul_div(ownership.balance, 10000, tokenized_asset.total_supply)?;
-
-    tokenized_asset.total_supply = math::sub(tokenized_asset.total_supply, amount)?;
-    tokenized_asset.tokens_in_circulation =
-        math::sub(tokenized_asset.tokens_in_circulation, amount)?;
-
-    store.set(&holder_key, &ownership);
-    store.set(&key, &tokenized_asset.clone());
+    burn_balance(env, &mut tokenized_asset, &burner, amount)?;
 
     // Append audit log
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
@@ -222,14 +265,15 @@ pub fn burn_tokens(
     Ok(tokenized_asset)
 }
 
-/// Transfer tokens from one address to another
+/// Transfer tokens from one address to another. Returns the shares `to`
+/// received once any share fee is withheld.
 pub fn transfer_tokens(
     env: &Env,
     asset_id: u64,
     from: Address,
     to: Address,
     amount: i128,
-) -> Result<(), Error> {
+) -> Result<i128, Error> {
     if amount <= 0 {
         return Err(Error::InvalidTokenSupply);
     }
@@ -239,15 +283,317 @@ pub fn transfer_tokens(
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+
+    crate::compliance::require_not_frozen(env, asset_id, &from)?;
+    crate::compliance::require_not_frozen(env, asset_id, &to)?;
+    require_unlocked(env, asset_id, &from, amount)?;
+
+    let charge = crate::fees::charge(env, &tokenized_asset, &from, &to, amount)?;
+    move_tokens(env, &tokenized_asset, &from, &to, charge.net)?;
+    crate::holding::record(env, &tokenized_asset, &to, charge.net);
+
+    // Append audit log
+    let asset_id_bytes = asset_id_to_bytes(env, asset_id);
+    audit::append_audit_log(
+        env,
+        &asset_id_bytes,
+        String::from_str(env, "TOKENS_TRANSFERRED"),
+        from.clone(),
+        String::from_str(env, "Tokens transferred to recipient"),
+    );
+
+    // Emit event: (asset_id, from, to, amount, fee, fee_token)
+    crate::events::tokens_transferred(
+        env,
+        asset_id,
+        &from,
+        &to,
+        amount,
+        charge.fee,
+        charge.fee_token,
+    );
+
+    Ok(charge.net)
+}
+
+/// Every check [`transfer_tokens`] and [`move_tokens`] make before moving
+/// anything, in the same order, without moving anything. Backs the
+/// `can_transfer` dry run. Returns the shares `to` would receive once any
+/// share fee is withheld.
+pub fn check_transfer(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidTokenSupply);
+    }
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    crate::compliance::require_not_frozen(env, asset_id, from)?;
+    crate::compliance::require_not_frozen(env, asset_id, to)?;
+    require_unlocked(env, asset_id, from, amount)?;
+
+    let charge = crate::fees::quote(env, &tokenized_asset, from, to, amount)?;
+    crate::fees::check(env, asset_id, &charge, from, from)?;
+
+    if from == to {
+        return Err(Error::InvalidInput);
+    }
+    let ownership: OwnershipRecord = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenHolder(asset_id, from.clone()))
+        .ok_or(Error::HolderNotFound)?;
+    if ownership.balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    Ok(charge.net)
+}
+
+/// Burn tokens from a holder's own balance.
+///
+/// The SEP-41 `burn` path. Unlike [`burn_tokens`], any holder may burn, so the
+/// same lock and vesting checks as a transfer apply: burning must not be a way
+/// out of a lock.
+pub fn burn_holder_tokens(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidTokenSupply);
+    }
+
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+
+    crate::compliance::require_not_frozen(env, asset_id, &holder)?;
+    require_unlocked(env, asset_id, &holder, amount)?;
+    burn_balance(env, &mut tokenized_asset, &holder, amount)?;
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "TOKENS_BURNED"),
+        holder,
+        String::from_str(env, "Tokens burned by holder"),
+    );
+
+    crate::events::tokens_burned(env, asset_id, amount, tokenized_asset.total_supply);
 
-    // Check if from address has locked tokens
-    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, from.clone());
-    if let Some(lock_time) = store.get::<_, u64>(&lock_key) {
+    Ok(())
+}
+
+/// Fails with `TokensAreLocked` if `holder` may not part with `amount` now:
+/// either a `lock_tokens` lock is active, or the amount would dip into the
+/// unvested part of the balance.
+pub(crate) fn require_unlocked(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
+    if let Some(lock_time) = env.storage().persistent().get::<_, u64>(&lock_key) {
         if env.ledger().timestamp() < lock_time {
             return Err(Error::TokensAreLocked);
         }
     }
 
+    // Only the vested part of a balance, outside its holding period, may move
+    let unvested = crate::vesting::unvested_balance(env, asset_id, holder)?;
+    let (in_holding_period, _) = crate::holding::restricted(env, asset_id, holder)?;
+    let restricted = math::add(unvested, in_holding_period)?;
+    if restricted > 0 {
+        let balance = get_token_balance(env, asset_id, holder.clone())?;
+        // An overdraw is reported as such by the caller's balance check.
+        if balance >= amount && math::sub(balance, restricted)? < amount {
+            return Err(Error::TokensAreLocked);
+        }
+    }
+
+    Ok(())
+}
+
+/// `holder`'s balance split by what [`require_unlocked`] lets move now.
+pub fn get_transferable_balance(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+) -> Result<TransferableBalance, Error> {
+    require_tokenized(env, asset_id)?;
+    let balance = get_token_balance(env, asset_id, holder.clone())?;
+    let (in_holding_period, next_release) = crate::holding::restricted(env, asset_id, holder)?;
+
+    let restricted = if is_tokens_locked(env, asset_id, holder.clone()) {
+        balance
+    } else {
+        let unvested = crate::vesting::unvested_balance(env, asset_id, holder)?;
+        math::add(unvested, in_holding_period)?.min(balance)
+    };
+
+    Ok(TransferableBalance {
+        balance,
+        in_holding_period,
+        restricted,
+        transferable: math::sub(balance, restricted)?,
+        next_release,
+    })
+}
+
+/// Adds `amount` to `holder`'s balance and to the supply, within the asset's
+/// `max_supply`, and stores both. Pure accounting, like [`move_tokens`].
+fn mint_balance(
+    env: &Env,
+    tokenized_asset: &mut TokenizedAsset,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let asset_id = tokenized_asset.asset_id;
+
+    let new_supply = math::add(tokenized_asset.total_supply, amount)?;
+    if let Some(cap) = get_max_supply(env, asset_id) {
+        if new_supply > cap {
+            return Err(Error::InvalidTokenSupply);
+        }
+    }
+    tokenized_asset.total_supply = new_supply;
+    tokenized_asset.tokens_in_circulation =
+        math::add(tokenized_asset.tokens_in_circulation, amount)?;
+
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    let mut ownership: OwnershipRecord = store
+        .get(&holder_key)
+        .unwrap_or_else(|| new_ownership(env, holder));
+
+    ownership.balance = math::add(ownership.balance, amount)?;
+    ownership.voting_power = ownership.balance;
+    ownership.dividend_entitlement = ownership.balance;
+    ownership.ownership_percentage =
+        math::mul_div(ownership.balance, 10000, tokenized_asset.total_supply)?;
+
+    store.set(&holder_key, &ownership);
+    ttl::extend_persistent(env, &holder_key);
+    store.set(
+        &TokenDataKey::TokenizedAsset(asset_id),
+        &tokenized_asset.clone(),
+    );
+    holders::sync(env, asset_id, holder, ownership.balance)?;
+    tokenized_asset.token_holders_count = holders::count(env, asset_id);
+
+    refresh_ownership_percentages(env, tokenized_asset)
+}
+
+/// Recomputes every holder's `ownership_percentage` against the current
+/// supply, a page of the holder set at a time. A change in supply moves
+/// everyone's share, not just the share of the holder whose balance changed.
+fn refresh_ownership_percentages(env: &Env, tokenized_asset: &TokenizedAsset) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let asset_id = tokenized_asset.asset_id;
+    let pages = holders::count(env, asset_id).div_ceil(holders::PAGE_SIZE);
+
+    for page in 0..pages {
+        for holder in holders::page(env, asset_id, page).iter() {
+            let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
+            let Some(mut ownership) = store.get::<_, OwnershipRecord>(&holder_key) else {
+                continue;
+            };
+            let percentage = if tokenized_asset.total_supply > 0 {
+                math::mul_div(ownership.balance, 10000, tokenized_asset.total_supply)?
+            } else {
+                0
+            };
+            if ownership.ownership_percentage != percentage {
+                ownership.ownership_percentage = percentage;
+                store.set(&holder_key, &ownership);
+            }
+            ttl::extend_persistent(env, &holder_key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes `amount` from `holder`'s balance and from the supply, and stores
+/// both, dropping `holder` from the holder set at zero. Pure accounting, like
+/// [`move_tokens`].
+fn burn_balance(
+    env: &Env,
+    tokenized_asset: &mut TokenizedAsset,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let asset_id = tokenized_asset.asset_id;
+
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    ttl::extend_persistent(env, &holder_key);
+
+    if ownership.balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // Update balances
+    ownership.balance = math::sub(ownership.balance, amount)?;
+    ownership.voting_power = ownership.balance;
+    ownership.dividend_entitlement = ownership.balance;
+
+    tokenized_asset.total_supply = math::sub(tokenized_asset.total_supply, amount)?;
+    tokenized_asset.tokens_in_circulation =
+        math::sub(tokenized_asset.tokens_in_circulation, amount)?;
+    ownership.ownership_percentage = if tokenized_asset.total_supply > 0 {
+        math::mul_div(ownership.balance, 10000, tokenized_asset.total_supply)?
+    } else {
+        0
+    };
+
+    store_ownership(env, &holder_key, &ownership);
+    store.set(
+        &TokenDataKey::TokenizedAsset(asset_id),
+        &tokenized_asset.clone(),
+    );
+    holders::sync(env, asset_id, holder, ownership.balance)?;
+    crate::holding::trim(env, asset_id, holder, ownership.balance);
+    tokenized_asset.token_holders_count = holders::count(env, asset_id);
+
+    refresh_ownership_percentages(env, tokenized_asset)
+}
+
+/// Moves `amount` from `from` to `to`, updating both ownership records and the
+/// holder set.
+///
+/// Pure accounting: no lock, vesting, restriction, audit or event handling.
+/// Every caller is responsible for the checks its entrypoint promises.
+pub(crate) fn move_tokens(
+    env: &Env,
+    tokenized_asset: &TokenizedAsset,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Both records share one key when from == to, and the credit would
+    // overwrite the debit, minting `amount` out of nothing.
+    if from == to {
+        return Err(Error::InvalidInput);
+    }
+
+    let store = env.storage().persistent();
+    let asset_id = tokenized_asset.asset_id;
+
     // Get from balance
     let from_holder_key = TokenDataKey::TokenHolder(asset_id, from.clone());
     let mut from_ownership: OwnershipRecord =
@@ -259,23 +605,9 @@ pub fn transfer_tokens(
 
     // Get to balance (or create new holder)
     let to_holder_key = TokenDataKey::TokenHolder(asset_id, to.clone());
-    let mut to_ownership: OwnershipRecord = match store.get(&to_holder_key) {
-        Some(ownership) => ownership,
-        None => {
-            // Create new holder
-            let timestamp = env.ledger().timestamp();
-            OwnershipRecord {
-                owner: to.clone(),
-                balance: 0,
-                acquisition_timestamp: timestamp,
-                average_purchase_price: 1,
-                voting_power: 0,
-                dividend_entitlement: 0,
-                unclaimed_dividends: 0,
-                ownership_percentage: 0,
-            }
-        }
-    };
+    let mut to_ownership: OwnershipRecord = store
+        .get(&to_holder_key)
+        .unwrap_or_else(|| new_ownership(env, to));
 
     // Update balances
     from_ownership.balance = math::sub(from_ownership.balance, amount)?;
@@ -290,41 +622,51 @@ pub fn transfer_tokens(
     to_ownership.ownership_percentage =
         math::mul_div(to_ownership.balance, 10000, tokenized_asset.total_supply)?;
 
-    store.set(&from_holder_key, &from_ownership);
+    store_ownership(env, &from_holder_key, &from_ownership);
     store.set(&to_holder_key, &to_ownership);
+    ttl::extend_persistent(env, &to_holder_key);
 
-    // Add to holder list if new
-    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
-    let mut holders: Vec<Address> = store
-        .get(&holders_list_key)
-        .ok_or(Error::AssetNotTokenized)?;
-
-    let is_new_holder = !holders.iter().any(|h| h == to);
-    if is_new_holder {
-        holders.push_back(to.clone());
-        store.set(&holders_list_key, &holders);
-    }
+    holders::sync(env, asset_id, from, from_ownership.balance)?;
+    holders::sync(env, asset_id, to, to_ownership.balance)?;
+    crate::holding::trim(env, asset_id, from, from_ownership.balance);
 
-    // Append audit log
-    let asset_id_bytes = asset_id_to_bytes(env, asset_id);
-    audit::append_audit_log(
-        env,
-        &asset_id_bytes,
-        String::from_str(env, "TOKENS_TRANSFERRED"),
-        from.clone(),
-        String::from_str(env, "Tokens transferred to recipient"),
-    );
+    Ok(())
+}
 
-    // Emit event: (asset_id, from, to, amount)
-    crate::events::tokens_transferred(env, asset_id, &from, &to, amount);
+/// A zero-balance record for a first-time holder.
+fn new_ownership(env: &Env, holder: &Address) -> OwnershipRecord {
+    OwnershipRecord {
+        owner: holder.clone(),
+        balance: 0,
+        acquisition_timestamp: env.ledger().timestamp(),
+        average_purchase_price: 1,
+        voting_power: 0,
+        dividend_entitlement: 0,
+        unclaimed_dividends: 0,
+        ownership_percentage: 0,
+    }
+}
 
-    Ok(())
+/// Stores a holder's ownership record, or removes it once there is nothing
+/// left in it: no balance and no unclaimed dividends.
+///
+/// A holder who leaves the holder set is no longer visited by
+/// detokenization, so an empty record left behind would outlive the asset.
+pub(crate) fn store_ownership(env: &Env, key: &TokenDataKey, ownership: &OwnershipRecord) {
+    let store = env.storage().persistent();
+    if ownership.balance == 0 && ownership.unclaimed_dividends == 0 {
+        store.remove(key);
+    } else {
+        store.set(key, ownership);
+        ttl::extend_persistent(env, key);
+    }
 }
 
 /// Get token balance for an address
 pub fn get_token_balance(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
     let store = env.storage().persistent();
     let key = TokenDataKey::TokenHolder(asset_id, holder);
+    ttl::extend_persistent(env, &key);
 
     match store.get::<_, OwnershipRecord>(&key) {
         Some(ownership) => Ok(ownership.balance),
@@ -332,12 +674,28 @@ pub fn get_token_balance(env: &Env, asset_id: u64, holder: Address) -> Result<i1
     }
 }
 
-/// Get all token holders for an asset
+/// Get all token holders for an asset: every address with a non-zero balance
 pub fn get_token_holders(env: &Env, asset_id: u64) -> Result<Vec<Address>, Error> {
-    let store = env.storage().persistent();
-    let key = TokenDataKey::TokenHoldersList(asset_id);
+    require_tokenized(env, asset_id)?;
+    Ok(holders::all(env, asset_id))
+}
 
-    store.get(&key).ok_or(Error::AssetNotTokenized)
+/// Get one page of an asset's token holders; see [`holders::PAGE_SIZE`]
+pub fn get_token_holders_page(env: &Env, asset_id: u64, page: u32) -> Result<Vec<Address>, Error> {
+    require_tokenized(env, asset_id)?;
+    Ok(holders::page(env, asset_id, page))
+}
+
+pub(crate) fn require_tokenized(env: &Env, asset_id: u64) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&TokenDataKey::TokenizedAsset(asset_id))
+    {
+        Ok(())
+    } else {
+        Err(Error::AssetNotTokenized)
+    }
 }
 
 /// Lock tokens until a specific timestamp.
@@ -353,6 +711,7 @@ pub fn lock_tokens(
     let store = env.storage().persistent();
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Only tokenizer can lock
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
@@ -363,6 +722,7 @@ pub fn lock_tokens(
 
     let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
     store.set(&lock_key, &until_timestamp);
+    ttl::extend_persistent(env, &lock_key);
 
     // Emit event: (asset_id, holder, until_timestamp)
     crate::events::tokens_locked(env, asset_id, &holder, until_timestamp);
@@ -377,6 +737,7 @@ pub fn unlock_tokens(env: &Env, asset_id: u64, holder: Address) -> Result<(), Er
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
 
@@ -395,6 +756,7 @@ pub fn unlock_tokens(env: &Env, asset_id: u64, holder: Address) -> Result<(), Er
 pub fn is_tokens_locked(env: &Env, asset_id: u64, holder: Address) -> bool {
     let store = env.storage().persistent();
     let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder);
+    ttl::extend_persistent(env, &lock_key);
     match store.get::<_, u64>(&lock_key) {
         Some(lock_until) => env.ledger().timestamp() < lock_until,
         None => false,
@@ -412,10 +774,12 @@ pub fn calculate_ownership_percentage(
     // Get asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get holder balance
     let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
     let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    ttl::extend_persistent(env, &holder_key);
 
     // Calculate percentage: (balance / total_supply) * 10000
     if tokenized_asset.total_supply <= 0 {
@@ -426,24 +790,39 @@ pub fn calculate_ownership_percentage(
 }
 
 /// Get tokenized asset details
+///
+/// The stored `locked_tokens` is the unvested total as of the last vesting
+/// grant or revoke; the contract's `get_tokenized_asset` replaces it with
+/// `vesting::locked_total` as of now.
 pub fn get_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
     let store = env.storage().persistent();
     let key = TokenDataKey::TokenizedAsset(asset_id);
 
-    store.get(&key).ok_or(Error::AssetNotTokenized)
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+    Ok(tokenized_asset)
 }
 
 /// Get token metadata
-#[allow(dead_code)]
 pub fn get_token_metadata(env: &Env, asset_id: u64) -> Result<TokenMetadata, Error> {
     let store = env.storage().persistent();
     let key = TokenDataKey::TokenMetadata(asset_id);
+    ttl::extend_persistent(env, &key);
 
     store.get(&key).ok_or(Error::AssetNotTokenized)
 }
 
-/// Update asset valuation
-pub fn update_valuation(env: &Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
+/// Overwrite asset valuation directly.
+///
+/// The admin override for when oracles are unavailable; routine valuations go
+/// through `valuation::submit_valuation`. Recorded in the valuation history
+/// with no oracle submissions behind it.
+pub fn update_valuation(
+    env: &Env,
+    asset_id: u64,
+    new_valuation: i128,
+    admin: Address,
+) -> Result<(), Error> {
     if new_valuation <= 0 {
         return Err(Error::InvalidValuation);
     }
@@ -455,6 +834,18 @@ pub fn update_valuation(env: &Env, asset_id: u64, new_valuation: i128) -> Result
 
     tokenized_asset.valuation = new_valuation;
     store.set(&key, &tokenized_asset);
+    ttl::extend_persistent(env, &key);
+
+    crate::valuation::record(env, asset_id, new_valuation, 0, 0);
+
+    let asset_id_bytes = asset_id_to_bytes(env, asset_id);
+    audit::append_audit_log(
+        env,
+        &asset_id_bytes,
+        String::from_str(env, "VALUATION_OVERRIDDEN"),
+        admin,
+        String::from_str(env, "Valuation set directly by admin"),
+    );
 
     // Emit event: (asset_id, new_valuation)
     crate::events::valuation_updated(env, asset_id, new_valuation);
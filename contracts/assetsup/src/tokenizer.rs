@@ -0,0 +1,277 @@
+//! The tokenizer role of a tokenized asset: handing it over, and keeping it
+//! with ownership of the underlying registry asset.
+//!
+//! The tokenizer alone can mint, burn, unlock, grant vesting and run
+//! offerings. The role moves in two steps, like the contract admin: the
+//! current tokenizer proposes a successor, and the role moves only when the
+//! successor accepts. Only the role moves; the outgoing tokenizer keeps any
+//! shares they hold.
+//!
+//! Registry assets (`BytesN<32>`) and tokenized assets (`u64`) live in
+//! separate id spaces. Before an asset id can be tokenized, the owner of the
+//! registry asset it represents **links** the two, and only that owner can
+//! then tokenize it. From then on control of the shares follows the asset:
+//!
+//! - `transfer_asset_ownership` of the registry asset moves the tokenizer role
+//!   to the new owner and withdraws any pending proposal.
+//! - Accepting a tokenizer proposal moves registry ownership to the new
+//!   tokenizer as well.
+//!
+//! A link is permanent for the life of the tokenization; detokenizing removes
+//! it. An asset tokenized before links were required has none until its
+//! tokenizer, if they own the registry asset, links it.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String};
+
+use crate::asset;
+use crate::audit;
+use crate::error::Error;
+use crate::tokenization::asset_id_to_bytes;
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `Address` proposed as the next tokenizer of asset_id. Absent when no
+    /// handover is in flight.
+    Pending(u64),
+    /// `BytesN<32>`: the registry asset linked to asset_id.
+    RegistryAsset(u64),
+    /// `u64`: the tokenized asset linked to a registry asset.
+    Tokenization(BytesN<32>),
+}
+
+// ─── Handover ─────────────────────────────────────────────────────────────────
+
+/// Step one: the current tokenizer nominates `new_tokenizer`. Replaces any
+/// proposal already in flight.
+pub fn propose(
+    env: &Env,
+    asset_id: u64,
+    new_tokenizer: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    if caller != tokenized_asset.tokenizer {
+        return Err(Error::Unauthorized);
+    }
+    if new_tokenizer == tokenized_asset.tokenizer {
+        return Err(Error::InvalidOwnerAddress);
+    }
+
+    let key = DataKey::Pending(asset_id);
+    env.storage().persistent().set(&key, &new_tokenizer);
+    ttl::extend_persistent(env, &key);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "TOKENIZER_PROPOSED"),
+        caller.clone(),
+        String::from_str(env, "Tokenizer role offered to a successor"),
+    );
+
+    crate::events::tokenizer_proposed(env, asset_id, &caller, &new_tokenizer);
+
+    Ok(())
+}
+
+/// Step two: the proposed tokenizer accepts and the role moves, together
+/// with the linked registry asset if there is one.
+pub fn accept(env: &Env, asset_id: u64, new_tokenizer: Address) -> Result<(), Error> {
+    let mut tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    let pending = get_pending(env, asset_id).ok_or(Error::NotFound)?;
+    if pending != new_tokenizer {
+        return Err(Error::Unauthorized);
+    }
+
+    if let Some(registry_id) = get_registry_asset(env, asset_id) {
+        let old_owner = asset::reassign_owner(env, &registry_id, &new_tokenizer)?;
+        audit::append_audit_log(
+            env,
+            &registry_id,
+            String::from_str(env, "OWNERSHIP_TRANSFERRED"),
+            new_tokenizer.clone(),
+            String::from_str(env, "Asset ownership moved with the tokenizer role"),
+        );
+        crate::events::asset_transferred(env, &registry_id, &old_owner, &new_tokenizer);
+    }
+
+    set_tokenizer(env, &mut tokenized_asset, &new_tokenizer, false);
+
+    Ok(())
+}
+
+/// Withdraws a pending proposal. Only the current tokenizer may cancel.
+pub fn cancel(env: &Env, asset_id: u64, caller: Address) -> Result<(), Error> {
+    let tokenized_asset = load_tokenized_asset(env, asset_id)?;
+    if caller != tokenized_asset.tokenizer {
+        return Err(Error::Unauthorized);
+    }
+    let pending = get_pending(env, asset_id).ok_or(Error::NotFound)?;
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Pending(asset_id));
+
+    crate::events::tokenizer_proposal_cancelled(env, asset_id, &caller, &pending);
+
+    Ok(())
+}
+
+// ─── Registry link ────────────────────────────────────────────────────────────
+
+/// Links asset id `asset_id` to the registry asset it represents, ahead of
+/// its tokenization. The caller must own the registry asset, and be the
+/// tokenizer if `asset_id` is already tokenized. Neither side may already be
+/// linked.
+pub fn link_registry_asset(
+    env: &Env,
+    asset_id: u64,
+    registry_id: BytesN<32>,
+    caller: Address,
+) -> Result<(), Error> {
+    if caller != registry_owner(env, &registry_id)? {
+        return Err(Error::Unauthorized);
+    }
+    if let Ok(tokenized_asset) = load_tokenized_asset(env, asset_id) {
+        if caller != tokenized_asset.tokenizer {
+            return Err(Error::Unauthorized);
+        }
+    }
+
+    let store = env.storage().persistent();
+    let forward = DataKey::RegistryAsset(asset_id);
+    let reverse = DataKey::Tokenization(registry_id.clone());
+    if store.has(&forward) || store.has(&reverse) {
+        return Err(Error::AssetAlreadyTokenized);
+    }
+    store.set(&forward, &registry_id);
+    store.set(&reverse, &asset_id);
+    ttl::extend_persistent(env, &forward);
+    ttl::extend_persistent(env, &reverse);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "REGISTRY_ASSET_LINKED"),
+        caller.clone(),
+        String::from_str(env, "Tokenizer role bound to registry ownership"),
+    );
+
+    crate::events::registry_asset_linked(env, asset_id, &registry_id, &caller);
+
+    Ok(())
+}
+
+/// Rejects tokenizing `asset_id` unless it is linked to a registry asset that
+/// `tokenizer` owns: `AssetNotFound` without a link, `Unauthorized` if the
+/// registry asset belongs to someone else.
+pub(crate) fn require_registry_owner(
+    env: &Env,
+    asset_id: u64,
+    tokenizer: &Address,
+) -> Result<(), Error> {
+    let registry_id = get_registry_asset(env, asset_id).ok_or(Error::AssetNotFound)?;
+    if *tokenizer != registry_owner(env, &registry_id)? {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Called after a registry asset changes hands: if it is linked to a live
+/// tokenization, the tokenizer role moves to `new_owner` too.
+pub(crate) fn follow_registry_owner(env: &Env, registry_id: &BytesN<32>, new_owner: &Address) {
+    let Some(asset_id) = get_tokenization(env, registry_id) else {
+        return;
+    };
+    let Ok(mut tokenized_asset) = load_tokenized_asset(env, asset_id) else {
+        return;
+    };
+    if tokenized_asset.tokenizer == *new_owner {
+        return;
+    }
+
+    set_tokenizer(env, &mut tokenized_asset, new_owner, true);
+}
+
+/// Drops the link and any pending proposal. Used by detokenization.
+pub(crate) fn clear(env: &Env, asset_id: u64) {
+    let store = env.storage().persistent();
+    store.remove(&DataKey::Pending(asset_id));
+    if let Some(registry_id) = get_registry_asset(env, asset_id) {
+        store.remove(&DataKey::Tokenization(registry_id));
+        store.remove(&DataKey::RegistryAsset(asset_id));
+    }
+}
+
+// ─── Reads ────────────────────────────────────────────────────────────────────
+
+pub fn get_pending(env: &Env, asset_id: u64) -> Option<Address> {
+    let key = DataKey::Pending(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_registry_asset(env: &Env, asset_id: u64) -> Option<BytesN<32>> {
+    let key = DataKey::RegistryAsset(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_tokenization(env: &Env, registry_id: &BytesN<32>) -> Option<u64> {
+    let key = DataKey::Tokenization(registry_id.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key)
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Stores `new_tokenizer` as the asset's tokenizer and clears any pending
+/// proposal. `with_registry` records that the move was driven by a registry
+/// ownership transfer rather than an accepted proposal.
+fn set_tokenizer(
+    env: &Env,
+    tokenized_asset: &mut TokenizedAsset,
+    new_tokenizer: &Address,
+    with_registry: bool,
+) {
+    let asset_id = tokenized_asset.asset_id;
+    let old_tokenizer = tokenized_asset.tokenizer.clone();
+    tokenized_asset.tokenizer = new_tokenizer.clone();
+
+    let store = env.storage().persistent();
+    store.set(&TokenDataKey::TokenizedAsset(asset_id), &*tokenized_asset);
+    store.remove(&DataKey::Pending(asset_id));
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "TOKENIZER_CHANGED"),
+        new_tokenizer.clone(),
+        if with_registry {
+            String::from_str(env, "Tokenizer role followed registry ownership")
+        } else {
+            String::from_str(env, "Tokenizer role accepted by successor")
+        },
+    );
+
+    crate::events::tokenizer_changed(env, asset_id, &old_tokenizer, new_tokenizer, with_registry);
+}
+
+fn registry_owner(env: &Env, registry_id: &BytesN<32>) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get::<_, asset::Asset>(&asset::DataKey::Asset(registry_id.clone()))
+        .map(|asset| asset.owner)
+        .ok_or(Error::AssetNotFound)
+}
+
+fn load_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)
+}
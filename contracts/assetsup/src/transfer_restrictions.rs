@@ -1,5 +1,8 @@
 use crate::error::Error;
-use crate::types::{TokenDataKey, TransferRestriction};
+use crate::math;
+use crate::tokenization::get_token_balance;
+use crate::ttl;
+use crate::types::{HolderLimits, TokenDataKey, TokenizedAsset, TransferRestriction};
 use soroban_sdk::{Address, Env, Vec};
 
 /// Set transfer restrictions for an asset
@@ -13,6 +16,7 @@ pub fn set_transfer_restriction(
     // Store the restriction
     let key = TokenDataKey::TransferRestriction(asset_id);
     store.set(&key, &restriction);
+    ttl::extend_persistent(env, &key);
 
     // Emit event: (asset_id, require_accredited)
     crate::events::restriction_set(env, asset_id, restriction.require_accredited);
@@ -34,6 +38,7 @@ pub fn add_to_whitelist(env: &Env, asset_id: u64, address: Address) -> Result<()
 
     whitelist.push_back(address.clone());
     store.set(&key, &whitelist);
+    ttl::extend_persistent(env, &key);
 
     // Emit event: (asset_id, address)
     crate::events::whitelist_added(env, asset_id, &address);
@@ -47,11 +52,13 @@ pub fn remove_from_whitelist(env: &Env, asset_id: u64, address: Address) -> Resu
 
     let key = TokenDataKey::Whitelist(asset_id);
     let mut whitelist: Vec<Address> = store.get(&key).flatten().unwrap_or_else(|| Vec::new(env));
+    ttl::extend_persistent(env, &key);
 
     // Find and remove address
     if let Some(index) = whitelist.iter().position(|a| a == address) {
         whitelist.remove(index as u32);
         store.set(&key, &whitelist);
+        ttl::extend_persistent(env, &key);
 
         // Emit event: (asset_id, address)
         crate::events::whitelist_removed(env, asset_id, &address);
@@ -66,6 +73,7 @@ pub fn is_whitelisted(env: &Env, asset_id: u64, address: Address) -> Result<bool
 
     let key = TokenDataKey::Whitelist(asset_id);
     let whitelist: Vec<Address> = store.get(&key).flatten().unwrap_or_else(|| Vec::new(env));
+    ttl::extend_persistent(env, &key);
 
     Ok(whitelist.iter().any(|a| a == address))
 }
@@ -75,16 +83,93 @@ pub fn get_whitelist(env: &Env, asset_id: u64) -> Result<Vec<Address>, Error> {
     let store = env.storage().persistent();
 
     let key = TokenDataKey::Whitelist(asset_id);
+    ttl::extend_persistent(env, &key);
     Ok(store.get(&key).flatten().unwrap_or_else(|| Vec::new(env)))
 }
 
 /// Validate if a transfer is allowed based on restrictions
+///
+/// `from` is the address whose balance the shares leave: the contract's own
+/// address for shares released from escrow. The recipient must be eligible,
+/// and the balances the transfer would leave must respect the asset's
+/// [`HolderLimits`].
 pub fn validate_transfer(
     env: &Env,
     asset_id: u64,
-    _from: Address,
+    from: Address,
     to: Address,
+    amount: i128,
 ) -> Result<bool, Error> {
+    check_recipient(env, asset_id, &to)?;
+    check_limits(env, asset_id, Some(&from), &to, amount)?;
+    Ok(true)
+}
+
+/// [`validate_transfer`] for a part of a transfer fee paid in shares. Only the
+/// payee's side is checked: the payer's is covered by the transfer the fee is
+/// charged on. The shares are treated as leaving escrow, which no limit
+/// applies to, so the payer's balance is not checked twice over.
+pub fn validate_fee(env: &Env, asset_id: u64, payee: &Address, amount: i128) -> Result<(), Error> {
+    check_recipient(env, asset_id, payee)?;
+    check_limits(
+        env,
+        asset_id,
+        Some(&env.current_contract_address()),
+        payee,
+        amount,
+    )
+}
+
+/// [`validate_transfer`] for shares going into escrow. Only the sender's side
+/// is checked: escrow is not an investor, and the buyer is checked on the fill.
+pub fn validate_escrow(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    check_limits(
+        env,
+        asset_id,
+        Some(from),
+        &env.current_contract_address(),
+        amount,
+    )
+}
+
+/// [`validate_transfer`] for newly minted shares, which grow the supply
+/// instead of leaving another balance.
+pub fn validate_issue(env: &Env, asset_id: u64, to: Address, amount: i128) -> Result<(), Error> {
+    check_recipient(env, asset_id, &to)?;
+    check_limits(env, asset_id, None, &to, amount)
+}
+
+/// Set the holder limits for an asset. Limits apply to later transfers only:
+/// holders already past a new limit keep their shares, but cannot take on
+/// more.
+pub fn set_holder_limits(env: &Env, asset_id: u64, limits: HolderLimits) -> Result<(), Error> {
+    if limits.max_ownership_bps > 10000 || limits.min_holding < 0 {
+        return Err(Error::InvalidInput);
+    }
+
+    let key = TokenDataKey::HolderLimits(asset_id);
+    env.storage().persistent().set(&key, &limits);
+    ttl::extend_persistent(env, &key);
+
+    crate::events::holder_limits_set(env, asset_id, &limits);
+
+    Ok(())
+}
+
+/// Get the holder limits for an asset; all zero (no limits) if none were set
+pub fn get_holder_limits(env: &Env, asset_id: u64) -> HolderLimits {
+    let key = TokenDataKey::HolderLimits(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Whitelist and accreditation checks on the recipient.
+fn check_recipient(env: &Env, asset_id: u64, to: &Address) -> Result<(), Error> {
     let store = env.storage().persistent();
 
     // Check whitelist: if non-empty, `to` must be whitelisted
@@ -95,7 +180,7 @@ pub fn validate_transfer(
         .unwrap_or_else(|| Vec::new(env));
 
     if !whitelist.is_empty() {
-        let is_listed = whitelist.iter().any(|a| a == to);
+        let is_listed = whitelist.iter().any(|a| a == *to);
         if !is_listed {
             return Err(Error::TransferRestrictionFailed);
         }
@@ -104,22 +189,110 @@ pub fn validate_transfer(
     let restriction_key = TokenDataKey::TransferRestriction(asset_id);
 
     // If no restrictions config, allow transfer
+    ttl::extend_persistent(env, &restriction_key);
     let restriction: TransferRestriction = match store.get(&restriction_key) {
         Some(Some(r)) => r,
         _ => {
-            return Ok(true);
+            return Ok(());
         }
     };
 
     // If accredited investor required, check whitelist as MVP proxy
     if restriction.require_accredited {
-        let is_listed = whitelist.iter().any(|a| a == to);
+        let is_listed = whitelist.iter().any(|a| a == *to);
         if !is_listed {
             return Err(Error::AccreditedInvestorRequired);
         }
     }
 
-    Ok(true)
+    Ok(())
+}
+
+/// Checks the asset's [`HolderLimits`] against the balances left after moving
+/// `amount` from `from` to `to`, or minting it to `to` when `from` is `None`.
+///
+/// The tokenizer and the contract itself are not investors: they hold
+/// unissued and escrowed shares, so no limit applies to them and they are not
+/// counted as holders. Shares sold in an offering and not yet claimed count as
+/// held by their buyer (see [`crate::offering::reserved`]). A limit only
+/// blocks a transfer that makes things worse, so an asset already past a
+/// lowered limit can still trade back within it.
+fn check_limits(
+    env: &Env,
+    asset_id: u64,
+    from: Option<&Address>,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let limits = get_holder_limits(env, asset_id);
+    // A non-positive amount is rejected by the transfer itself.
+    if limits == HolderLimits::default() || amount <= 0 {
+        return Ok(());
+    }
+
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let escrow = env.current_contract_address();
+    let is_investor = |a: &Address| *a != tokenized_asset.tokenizer && *a != escrow;
+
+    let from_after = match from {
+        Some(from) if is_investor(from) => Some(math::sub(
+            get_token_balance(env, asset_id, from.clone())?,
+            amount,
+        )?),
+        _ => None,
+    };
+    if let Some(balance) = from_after {
+        if balance > 0 && balance < limits.min_holding {
+            return Err(Error::BelowMinimumHolding);
+        }
+    }
+
+    if !is_investor(to) {
+        return Ok(());
+    }
+    let (to_reserved, reserved_holders) = crate::offering::reserved(env, asset_id, to);
+    let to_before = math::add(get_token_balance(env, asset_id, to.clone())?, to_reserved)?;
+    let to_after = math::add(to_before, amount)?;
+
+    if to_after < limits.min_holding {
+        return Err(Error::BelowMinimumHolding);
+    }
+
+    if limits.max_ownership_bps > 0 {
+        let supply_after = match from {
+            Some(_) => tokenized_asset.total_supply,
+            None => math::add(tokenized_asset.total_supply, amount)?,
+        };
+        if math::mul(to_after, 10000)? > math::mul(supply_after, limits.max_ownership_bps as i128)?
+        {
+            return Err(Error::OwnershipCapExceeded);
+        }
+    }
+
+    if limits.max_holders > 0 && to_before == 0 {
+        let mut investors = crate::holders::count(env, asset_id) + reserved_holders;
+        for holder in [&tokenized_asset.tokenizer, &escrow] {
+            if get_token_balance(env, asset_id, holder.clone())? > 0 {
+                investors = investors.saturating_sub(1);
+            }
+        }
+        // A sender who parts with everything makes room for the recipient,
+        // unless an offering still owes them shares.
+        if from_after == Some(0)
+            && from.is_some_and(|from| crate::offering::reserved(env, asset_id, from).0 == 0)
+        {
+            investors = investors.saturating_sub(1);
+        }
+        if investors >= limits.max_holders {
+            return Err(Error::MaxHoldersExceeded);
+        }
+    }
+
+    Ok(())
 }
 
 /// Check if transfer restrictions are enabled for an asset
@@ -128,6 +301,7 @@ pub fn has_transfer_restrictions(env: &Env, asset_id: u64) -> Result<bool, Error
     let store = env.storage().persistent();
 
     let restriction_key = TokenDataKey::TransferRestriction(asset_id);
+    ttl::extend_persistent(env, &restriction_key);
     Ok(store.has(&restriction_key))
 }
 
@@ -137,6 +311,7 @@ pub fn get_transfer_restriction(env: &Env, asset_id: u64) -> Result<TransferRest
     let store = env.storage().persistent();
 
     let key = TokenDataKey::TransferRestriction(asset_id);
+    ttl::extend_persistent(env, &key);
     store.get(&key).ok_or(Error::AssetNotTokenized)
 }
 
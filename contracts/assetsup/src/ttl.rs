@@ -25,8 +25,31 @@
 //! than `*_THRESHOLD` ledgers left, push it back out to `*_EXTEND_TO`. Setting
 //! the threshold below the target means the extension is a no-op on most calls
 //! and only costs rent when an entry is genuinely approaching expiry.
+//!
+//! ## Keep-alive
+//!
+//! An asset nobody touches still expires. `extend_asset_ttl` and
+//! `extend_token_ttl` let anyone, typically the asset's owner or an indexer,
+//! push every entry belonging to one asset out to the full window, paying
+//! the rent themselves.
+//!
+//! A contract cannot read an entry's TTL; only RPC's `getLedgerEntries`
+//! reports `liveUntilLedgerSeq`. So instead of ranking entries by expiry
+//! itself, the contract lists the keys belonging to an asset with
+//! `get_asset_ttl_keys` and `get_token_ttl_keys`, and tooling fetches and
+//! sorts their TTLs off-chain. The lists cover every entry the contract can
+//! find from the asset id. Vote records and tallies are keyed by proposal
+//! ids the contract does not index, and metadata revisions are history that
+//! grows without bound; both are extended whenever they are read or written
+//! but are not listed.
 
-use soroban_sdk::{Env, IntoVal, Val};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Val, Vec};
+
+use crate::types::TokenDataKey;
+use crate::{
+    asset, audit, compliance, fees, holders, holding, insurance, lease, market, metadata, offering,
+    tokenizer, valuation, vesting,
+};
 
 /// Ledgers per day, at the nominal 5 second close time.
 pub const LEDGERS_PER_DAY: u32 = 17_280;
@@ -69,6 +92,160 @@ pub fn extend_instance(env: &Env) {
         .extend_ttl(INSTANCE_THRESHOLD, INSTANCE_EXTEND_TO);
 }
 
+// ─── Keep-alive ───────────────────────────────────────────────────────────────
+
+/// Pushes every entry in `keys` out to the full [`PERSISTENT_EXTEND_TO`]
+/// window, whatever its current TTL. Returns how many were extended.
+pub fn renew_all(env: &Env, keys: &Vec<Val>) -> u32 {
+    let store = env.storage().persistent();
+    for key in keys.iter() {
+        store.extend_ttl(&key, PERSISTENT_EXTEND_TO, PERSISTENT_EXTEND_TO);
+    }
+    keys.len()
+}
+
+/// The stored entries belonging to registry asset `asset_id`: the record,
+/// its owner's index, audit log, active lease, policies and claims, and its
+/// link to a tokenized asset.
+pub fn asset_keys(env: &Env, asset_id: &BytesN<32>) -> Vec<Val> {
+    let store = env.storage().persistent();
+    let mut keys = Vec::new(env);
+
+    let asset_key = asset::DataKey::Asset(asset_id.clone());
+    if let Some(record) = store.get::<_, asset::Asset>(&asset_key) {
+        push(env, &mut keys, asset_key);
+        push(env, &mut keys, asset::DataKey::OwnerRegistry(record.owner));
+    }
+    push(env, &mut keys, audit::DataKey::AuditLog(asset_id.clone()));
+    push(
+        env,
+        &mut keys,
+        tokenizer::DataKey::Tokenization(asset_id.clone()),
+    );
+
+    let lease_key = lease::DataKey::AssetActiveLease(asset_id.clone());
+    if let Some(lease_id) = store.get::<_, BytesN<32>>(&lease_key) {
+        push(env, &mut keys, lease_key);
+        push(env, &mut keys, lease::DataKey::Lease(lease_id));
+    }
+
+    let policies_key = insurance::DataKey::AssetPolicies(asset_id.clone());
+    if let Some(policies) = store.get::<_, Vec<BytesN<32>>>(&policies_key) {
+        push(env, &mut keys, policies_key);
+        for id in policies.iter() {
+            push(env, &mut keys, insurance::DataKey::Policy(id));
+        }
+    }
+    let claims_key = insurance::DataKey::AssetClaims(asset_id.clone());
+    if let Some(claims) = store.get::<_, Vec<BytesN<32>>>(&claims_key) {
+        push(env, &mut keys, claims_key);
+        for id in claims.iter() {
+            push(env, &mut keys, insurance::DataKey::Claim(id));
+        }
+    }
+
+    keys
+}
+
+/// The stored entries belonging to tokenized asset `asset_id`: every
+/// asset-wide entry, the open listings, and the per-holder entries of the
+/// holders on `page` of the holder set. Walk the pages to cover every holder.
+pub fn token_keys(env: &Env, asset_id: u64, page: u32) -> Vec<Val> {
+    let store = env.storage().persistent();
+    let mut keys = Vec::new(env);
+
+    push(env, &mut keys, TokenDataKey::TokenizedAsset(asset_id));
+    push(env, &mut keys, TokenDataKey::TokenMetadata(asset_id));
+    push(env, &mut keys, TokenDataKey::TokenContract(asset_id));
+    push(env, &mut keys, TokenDataKey::MaxSupply(asset_id));
+    push(env, &mut keys, TokenDataKey::HolderLimits(asset_id));
+    push(env, &mut keys, TokenDataKey::TransferRestriction(asset_id));
+    push(env, &mut keys, TokenDataKey::Whitelist(asset_id));
+    push(
+        env,
+        &mut keys,
+        TokenDataKey::DetokenizationProposal(asset_id),
+    );
+    push(env, &mut keys, TokenDataKey::TokenHoldersList(asset_id));
+    push(env, &mut keys, holders::DataKey::Count(asset_id));
+    push(env, &mut keys, holders::DataKey::Page(asset_id, page));
+    push(env, &mut keys, fees::DataKey::Config(asset_id));
+    push(env, &mut keys, holding::DataKey::Period(asset_id));
+    push(env, &mut keys, metadata::DataKey::RevisionCount(asset_id));
+    push(env, &mut keys, offering::DataKey::Offering(asset_id));
+    push(env, &mut keys, valuation::DataKey::Submitters(asset_id));
+    push(env, &mut keys, valuation::DataKey::History(asset_id));
+    push(env, &mut keys, vesting::DataKey::Holders(asset_id));
+    push(env, &mut keys, tokenizer::DataKey::Pending(asset_id));
+    push(env, &mut keys, tokenizer::DataKey::RegistryAsset(asset_id));
+    push(env, &mut keys, market::DataKey::NextId(asset_id));
+
+    let book_key = market::DataKey::Book(asset_id);
+    if let Some(book) = store.get::<_, Vec<u64>>(&book_key) {
+        push(env, &mut keys, book_key);
+        for id in book.iter() {
+            push(env, &mut keys, market::DataKey::Listing(asset_id, id));
+        }
+    }
+
+    for holder in holders::page(env, asset_id, page).iter() {
+        push_holder(env, &mut keys, asset_id, holder);
+    }
+
+    keys
+}
+
+fn push_holder(env: &Env, keys: &mut Vec<Val>, asset_id: u64, holder: Address) {
+    push(
+        env,
+        keys,
+        TokenDataKey::TokenHolder(asset_id, holder.clone()),
+    );
+    push(
+        env,
+        keys,
+        TokenDataKey::TokenLockedUntil(asset_id, holder.clone()),
+    );
+    push(
+        env,
+        keys,
+        TokenDataKey::UnclaimedDividend(asset_id, holder.clone()),
+    );
+    push(
+        env,
+        keys,
+        holders::DataKey::Position(asset_id, holder.clone()),
+    );
+    push(env, keys, holding::DataKey::Lots(asset_id, holder.clone()));
+    push(
+        env,
+        keys,
+        compliance::DataKey::Frozen(asset_id, holder.clone()),
+    );
+    push(env, keys, fees::DataKey::Exempt(asset_id, holder.clone()));
+    push(
+        env,
+        keys,
+        offering::DataKey::Subscription(asset_id, holder.clone()),
+    );
+    push(env, keys, vesting::DataKey::Grants(asset_id, holder));
+}
+
+/// Adds `key` to `keys` if the entry exists. Only the conversion is generic,
+/// so the lookup is compiled once rather than once per key type.
+fn push<K>(env: &Env, keys: &mut Vec<Val>, key: K)
+where
+    K: IntoVal<Env, Val>,
+{
+    push_val(env, keys, key.into_val(env));
+}
+
+fn push_val(env: &Env, keys: &mut Vec<Val>, key: Val) {
+    if env.storage().persistent().has(&key) {
+        keys.push_back(key);
+    }
+}
+
 /// The extension target must be further out than the trigger point, otherwise
 /// every call would pay to extend while never actually pushing the entry out.
 ///
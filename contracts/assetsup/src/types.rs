@@ -85,7 +85,8 @@ pub enum TokenDataKey {
     TokenizedAsset(u64),
     /// Stores OwnershipRecord for (asset_id, holder_address)
     TokenHolder(u64, Address),
-    /// Stores `Vec<Address>` of all token holders for an asset
+    /// Legacy `Vec<Address>` of token holders, from before the holder set was
+    /// paged. Converted on first write; see `holders`.
     TokenHoldersList(u64),
     /// Stores lock timestamp for (asset_id, holder_address)
     TokenLockedUntil(u64, Address),
@@ -103,6 +104,12 @@ pub enum TokenDataKey {
     DetokenizationProposal(u64),
     /// Stores TokenMetadata for asset_id
     TokenMetadata(u64),
+    /// Stores the Address of the asset's SEP-41 token contract
+    TokenContract(u64),
+    /// Stores the i128 cap on total_supply, if one was set at tokenization
+    MaxSupply(u64),
+    /// Stores HolderLimits for asset_id
+    HolderLimits(u64),
 }
 
 /// Represents a tokenized asset on-chain
@@ -115,15 +122,16 @@ pub struct TokenizedAsset {
     pub total_supply: i128,
     /// Token symbol (unique per asset)
     pub symbol: String,
-    /// Number of decimals for fractional ownership
+    /// Number of decimals for fractional ownership, at most `MAX_DECIMALS`
     pub decimals: u32,
-    /// Total tokens currently locked (non-transferable)
+    /// Tokens still unvested under vesting grants (non-transferable)
     pub locked_tokens: i128,
     /// Tokenizer / asset owner
     pub tokenizer: Address,
     /// Asset valuation (in stroops)
     pub valuation: i128,
-    /// Number of unique token holders
+    /// Number of addresses holding a non-zero balance. Kept by `holders` and
+    /// filled in on read
     pub token_holders_count: u32,
     /// Tokens currently in circulation (not burned)
     pub tokens_in_circulation: i128,
@@ -173,10 +181,26 @@ pub struct OwnershipRecord {
     pub dividend_entitlement: i128,
     /// Unclaimed dividends pending
     pub unclaimed_dividends: i128,
-    /// Ownership percentage in basis points (e.g., 5000 = 50%)
+    /// Ownership percentage in basis points (e.g., 5000 = 50%), refreshed for
+    /// every holder when the supply changes
     pub ownership_percentage: i128,
 }
 
+/// A holder's balance split into what may move now and what may not
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferableBalance {
+    pub balance: i128,
+    /// Shares acquired too recently to leave their holding period
+    pub in_holding_period: i128,
+    /// Everything that cannot move now: a lock, unvested tokens or the
+    /// holding period
+    pub restricted: i128,
+    pub transferable: i128,
+    /// When the oldest lot in its holding period is released
+    pub next_release: Option<u64>,
+}
+
 /// Transfer restrictions for tokens
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -187,6 +211,22 @@ pub struct TransferRestriction {
     pub geographic_allowed: Vec<String>,
 }
 
+/// Caps on how an asset's shares may be spread, checked against the state a
+/// transfer would leave behind. Zero disables a limit.
+///
+/// Stored apart from `TransferRestriction` so that restrictions written before
+/// these limits existed still decode.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HolderLimits {
+    /// Most investors that may hold a non-zero balance at once
+    pub max_holders: u32,
+    /// Largest share of the supply one investor may hold, in basis points
+    pub max_ownership_bps: u32,
+    /// Smallest non-zero balance an investor may be left with
+    pub min_holding: i128,
+}
+
 // =====================
 // DetokenizationProposal — Option B: wrapper structs preserve named fields
 // while satisfying #[contracttype]'s restriction on enum variant fields.
@@ -1,15 +1,30 @@
 //! Contract upgrade and storage migration ([SC-49]).
 //!
-//! `assetsup` is **upgradeable**: the admin may replace the contract's WASM in
-//! place with [`AssetUpContract::upgrade`], keeping the same contract id and
-//! all existing storage. That is the right posture for a contract holding an
-//! asset registry — the alternative, redeploying, means a new contract id and
+//! `assetsup` is **upgradeable**: the admin may replace the contract's WASM by
+//! scheduling the new hash with [`AssetUpContract::schedule_upgrade`] and
+//! installing it with [`AssetUpContract::execute_upgrade`] once the timelock
+//! below has passed, keeping the same contract id and all existing storage.
+//! That is the right posture for a contract holding an asset registry — the alternative, redeploying, means a new contract id and
 //! either abandoning or manually re-importing every ownership record.
 //!
 //! Upgradeability is a trade: it means a compromised admin key can replace the
 //! contract with arbitrary code. See `contracts/UPGRADE.md` for the runbook and
 //! the mitigations.
 //!
+//! ## Timelock
+//!
+//! An upgrade is never immediate. [`AssetUpContract::schedule_upgrade`]
+//! records the new WASM hash and the earliest time it may be installed, at
+//! least [`MIN_UPGRADE_DELAY`] later; until then anyone watching the
+//! `upgrade_scheduled` event can react, and the admin can cancel it. Only
+//! [`AssetUpContract::execute_upgrade`], once the delay has passed, swaps the
+//! WASM. One upgrade may be pending at a time.
+//!
+//! The contract cannot read its own WASM hash, so it remembers each hash it
+//! installs. The hash an upgrade replaced is kept as the rollback target,
+//! which is scheduled like any other upgrade. The hash deployed originally is
+//! not known, so there is no rollback target until the first upgrade.
+//!
 //! ## Storage versioning
 //!
 //! Replacing the WASM does **not** touch storage. If a new version changes a
@@ -20,19 +35,80 @@
 //! [`AssetUpContract::migrate`] advances it, applying whatever transformation
 //! each step needs, and is **idempotent**: running it twice is a no-op, so a
 //! retried or duplicated migration transaction cannot corrupt state.
+//!
+//! ## Batched migration
+//!
+//! A step that rewrites many entries cannot do it in one transaction. Each
+//! `migrate` call therefore does at most [`MIGRATION_BATCH`] units of work
+//! and persists a cursor; the next call resumes from it, and the version
+//! advances only when the step is finished. Until then every mutating
+//! entrypoint fails with `MigrationInProgress`, so nothing writes the layout
+//! the step is halfway through rewriting. Reads keep working.
+//! `get_migration_progress` reports where the migration stands.
+//!
+//! Soroban storage cannot be enumerated, so a step cannot find the keys it
+//! must rewrite by itself. The admin lists them with `queue_migration`, from
+//! an off-chain index of the contract's events, before calling `migrate`.
+//!
+//! ### v1 to v2
+//!
+//! Version 2 holds every tokenized asset's holders in pages (see `holders`).
+//! The step converts each queued asset's pre-paging `TokenHoldersList`, one
+//! batch of addresses at a time. An asset left off the queue is not lost, but
+//! its holders cannot change until it is converted: balance changes fail with
+//! `MigrationInProgress`. It can still be queued once the step has finished,
+//! and each later `migrate` call converts what is queued.
 
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
 use crate::error::Error;
 use crate::events;
+use crate::holders;
+use crate::ttl;
 use crate::DataKey;
 
+/// Shortest delay the admin may set between scheduling and installing an
+/// upgrade: one day.
+pub const MIN_UPGRADE_DELAY: u64 = 24 * 60 * 60;
+
+/// Delay used until the admin sets one: two days.
+pub const DEFAULT_UPGRADE_DELAY: u64 = 2 * 24 * 60 * 60;
+
 /// The storage layout version this build of the contract expects.
 ///
 /// Bump this **in the same change** that alters a stored type, and add the
 /// corresponding arm to [`migrate_from`]. A build whose `CURRENT_VERSION` is
 /// ahead of the stored version will refuse to serve until `migrate` has run.
-pub const CURRENT_VERSION: u32 = 1;
+pub const CURRENT_VERSION: u32 = 2;
+
+/// Most units of work one `migrate` call does: entries rewritten, or queued
+/// keys found to need nothing.
+pub const MIGRATION_BATCH: u32 = 50;
+
+/// An upgrade waiting out its delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub scheduled_at: u64,
+    /// Earliest ledger timestamp at which `execute_upgrade` may install it.
+    pub executable_at: u64,
+}
+
+/// Where a migration stands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    /// Layout the stored data conforms to.
+    pub stored_version: u32,
+    /// Layout this build expects. The migration is done when the two match.
+    pub target_version: u32,
+    /// Queued keys the current step has yet to finish, including the one it
+    /// is working through.
+    pub queued: u32,
+    /// Position reached within the first queued key.
+    pub cursor: u32,
+}
 
 /// Reads the stored layout version.
 ///
@@ -42,7 +118,7 @@ pub fn stored_version(env: &Env) -> u32 {
     env.storage()
         .persistent()
         .get(&DataKey::StorageVersion)
-        .unwrap_or(CURRENT_VERSION)
+        .unwrap_or(1)
 }
 
 pub fn set_version(env: &Env, version: u32) {
@@ -51,10 +127,12 @@ pub fn set_version(env: &Env, version: u32) {
         .set(&DataKey::StorageVersion, &version);
 }
 
-/// Applies the migration steps between `from` and [`CURRENT_VERSION`].
+/// Applies the migration steps between `from` and [`CURRENT_VERSION`], within
+/// one call's budget of [`MIGRATION_BATCH`].
 ///
-/// Each arm transforms one version to the next. Steps are applied in order so
-/// a contract several versions behind catches up in a single call.
+/// Each arm transforms one version to the next. Steps are applied in order, so
+/// a contract several versions behind catches up as far as the budget allows;
+/// a step that runs out of budget keeps its cursor for the next call.
 ///
 /// Returns the version actually reached.
 pub fn migrate_from(env: &Env, from: u32) -> Result<u32, Error> {
@@ -65,27 +143,105 @@ pub fn migrate_from(env: &Env, from: u32) -> Result<u32, Error> {
     }
 
     let mut version = from;
+    let mut budget = MIGRATION_BATCH;
 
-    // Migration steps go here as the layout evolves, for example:
-    //
-    //     if version == 1 {
-    //         // v1 -> v2: Asset gained a `warranty_expires` field. Existing
-    //         // records decode with the default, so nothing to rewrite, but
-    //         // the version must still advance.
-    //         version = 2;
-    //     }
-    //
     // Each arm must be safe to skip when `version` is already past it, which
     // is what makes the whole function idempotent.
-
-    if version < CURRENT_VERSION {
-        version = CURRENT_VERSION;
+    if version == 1 {
+        if !paged_holder_lists(env, &mut budget) {
+            return Ok(version);
+        }
+        version = 2;
+    } else {
+        // Assets the v1 to v2 step was not given, queued since.
+        paged_holder_lists(env, &mut budget);
     }
 
     set_version(env, version);
     Ok(version)
 }
 
+/// v1 -> v2: converts the queued assets' legacy holder lists to pages.
+/// Returns whether the queue is exhausted.
+fn paged_holder_lists(env: &Env, budget: &mut u32) -> bool {
+    let mut queue = migration_queue(env);
+    let mut cursor = migration_cursor(env);
+
+    while *budget > 0 {
+        let Some(asset_id) = queue.first() else {
+            break;
+        };
+        let (done, finished) = holders::convert_legacy_batch(env, asset_id, cursor, *budget);
+        *budget -= done.max(1);
+        if finished {
+            queue.pop_front();
+            cursor = 0;
+        } else {
+            cursor += done;
+        }
+    }
+
+    let store = env.storage().persistent();
+    if queue.is_empty() {
+        store.remove(&DataKey::MigrationQueue);
+        store.remove(&DataKey::MigrationCursor);
+        return true;
+    }
+    store.set(&DataKey::MigrationQueue, &queue);
+    store.set(&DataKey::MigrationCursor, &cursor);
+    ttl::extend_persistent(env, &DataKey::MigrationQueue);
+    ttl::extend_persistent(env, &DataKey::MigrationCursor);
+    false
+}
+
+/// Adds keys for the pending migration step to visit. Once the stored data is
+/// current, queued assets still holding a legacy holder list are converted by
+/// later `migrate` calls.
+pub fn queue(env: &Env, keys: Vec<u64>) {
+    let mut queue = migration_queue(env);
+    queue.append(&keys);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MigrationQueue, &queue);
+    ttl::extend_persistent(env, &DataKey::MigrationQueue);
+}
+
+pub fn progress(env: &Env) -> MigrationProgress {
+    MigrationProgress {
+        stored_version: stored_version(env),
+        target_version: CURRENT_VERSION,
+        queued: migration_queue(env).len(),
+        cursor: migration_cursor(env),
+    }
+}
+
+/// Rejects the call while the stored data is behind this build's layout.
+///
+/// An uninitialized contract has nothing to migrate and is let through, to
+/// fail with its own error.
+pub fn require_current(env: &Env) -> Result<(), Error> {
+    if stored_version(env) < CURRENT_VERSION && env.storage().persistent().has(&DataKey::Admin) {
+        return Err(Error::MigrationInProgress);
+    }
+    Ok(())
+}
+
+fn migration_queue(env: &Env) -> Vec<u64> {
+    ttl::extend_persistent(env, &DataKey::MigrationQueue);
+    env.storage()
+        .persistent()
+        .get(&DataKey::MigrationQueue)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn migration_cursor(env: &Env) -> u32 {
+    ttl::extend_persistent(env, &DataKey::MigrationCursor);
+    env.storage()
+        .persistent()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(0)
+}
+
 /// Emits the upgrade event.
 pub fn emit_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>, version: u32) {
     events::contract_upgraded(env, admin, new_wasm_hash, version);
@@ -95,3 +251,89 @@ pub fn emit_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>, ver
 pub fn emit_migrated(env: &Env, from: u32, to: u32) {
     events::contract_migrated(env, from, to);
 }
+
+// ─── Timelock ─────────────────────────────────────────────────────────────────
+
+pub fn upgrade_delay(env: &Env) -> u64 {
+    ttl::extend_persistent(env, &DataKey::UpgradeDelay);
+    env.storage()
+        .persistent()
+        .get(&DataKey::UpgradeDelay)
+        .unwrap_or(DEFAULT_UPGRADE_DELAY)
+}
+
+/// Sets the delay applied to upgrades scheduled from now on. An upgrade
+/// already pending keeps the time it was given.
+pub fn set_upgrade_delay(env: &Env, delay: u64) -> Result<(), Error> {
+    if delay < MIN_UPGRADE_DELAY {
+        return Err(Error::InvalidInput);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeDelay, &delay);
+    ttl::extend_persistent(env, &DataKey::UpgradeDelay);
+    events::upgrade_delay_set(env, delay);
+    Ok(())
+}
+
+pub fn pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    ttl::extend_persistent(env, &DataKey::PendingUpgrade);
+    env.storage().persistent().get(&DataKey::PendingUpgrade)
+}
+
+/// Schedules `wasm_hash` for installation once the delay has passed. Fails
+/// with `InvalidProposal` while another upgrade is pending.
+pub fn schedule(env: &Env, wasm_hash: BytesN<32>) -> Result<PendingUpgrade, Error> {
+    if pending_upgrade(env).is_some() {
+        return Err(Error::InvalidProposal);
+    }
+
+    let now = env.ledger().timestamp();
+    let pending = PendingUpgrade {
+        wasm_hash,
+        scheduled_at: now,
+        executable_at: now.saturating_add(upgrade_delay(env)),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingUpgrade, &pending);
+    ttl::extend_persistent(env, &DataKey::PendingUpgrade);
+
+    events::upgrade_scheduled(env, &pending.wasm_hash, pending.executable_at);
+    Ok(pending)
+}
+
+pub fn cancel(env: &Env) -> Result<(), Error> {
+    let pending = pending_upgrade(env).ok_or(Error::NotFound)?;
+    env.storage().persistent().remove(&DataKey::PendingUpgrade);
+    events::upgrade_cancelled(env, &pending.wasm_hash);
+    Ok(())
+}
+
+/// Takes the pending upgrade once its delay has passed, recording its hash
+/// as the installed one and the hash it replaces as the rollback target.
+/// Returns the hash to install.
+pub fn take_due(env: &Env) -> Result<BytesN<32>, Error> {
+    let pending = pending_upgrade(env).ok_or(Error::NotFound)?;
+    if env.ledger().timestamp() < pending.executable_at {
+        return Err(Error::InvalidProposal);
+    }
+    env.storage().persistent().remove(&DataKey::PendingUpgrade);
+
+    let store = env.storage().persistent();
+    if let Some(current) = store.get::<_, BytesN<32>>(&DataKey::CurrentWasmHash) {
+        store.set(&DataKey::PreviousWasmHash, &current);
+        ttl::extend_persistent(env, &DataKey::PreviousWasmHash);
+    }
+    store.set(&DataKey::CurrentWasmHash, &pending.wasm_hash);
+    ttl::extend_persistent(env, &DataKey::CurrentWasmHash);
+
+    Ok(pending.wasm_hash)
+}
+
+/// The WASM hash the last upgrade replaced, if known: the target of a
+/// rollback.
+pub fn previous_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    ttl::extend_persistent(env, &DataKey::PreviousWasmHash);
+    env.storage().persistent().get(&DataKey::PreviousWasmHash)
+}
@@ -0,0 +1,341 @@
+//! Oracle-fed valuation of tokenized assets.
+//!
+//! A tokenized asset's `valuation` drives its net asset value, so it must not
+//! be something any caller can overwrite. Valuations are instead submitted by
+//! oracle addresses the admin has approved, each with a hash of the report
+//! backing the figure, and the stored valuation is an aggregate of them.
+//!
+//! ## Aggregation
+//!
+//! Every submission replaces that oracle's previous one for the asset. The
+//! aggregate is then recomputed from the submissions that are still **fresh**
+//! (no older than `freshness_window`) and still come from an approved oracle:
+//!
+//! 1. Take the median of the fresh values.
+//! 2. Reject every value further than `max_deviation_bps` from that median.
+//!    A single compromised or broken oracle therefore cannot drag the figure,
+//!    however extreme its submission.
+//! 3. If at least `min_submissions` values survive, their median becomes the
+//!    new valuation and is appended to the history. Otherwise the stored
+//!    valuation is left alone until more oracles report.
+//!
+//! The median rounds down for an even count, matching the rounding policy in
+//! [`crate::math`].
+//!
+//! `AssetUpContract::update_valuation` remains as an admin override for when
+//! the oracles are unavailable. It is recorded in the history like any other
+//! update, with zero oracle submissions behind it.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+use crate::audit;
+use crate::error::Error;
+use crate::math;
+use crate::tokenization::asset_id_to_bytes;
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+/// How many history records are kept per asset. The oldest is dropped once the
+/// cap is reached, so the entry cannot grow without bound.
+pub const MAX_VALUATION_HISTORY: u32 = 50;
+
+/// Default: a submission counts towards the aggregate for one day.
+pub const DEFAULT_FRESHNESS_WINDOW: u64 = 86_400;
+/// Default: values more than 10% from the median are rejected.
+pub const DEFAULT_MAX_DEVIATION_BPS: u32 = 1_000;
+/// Default: two fresh, non-outlying submissions, so no single oracle can
+/// move a valuation.
+pub const DEFAULT_MIN_SUBMISSIONS: u32 = 2;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Contract-wide aggregation parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValuationConfig {
+    /// Maximum age, in seconds, of a submission that still counts.
+    pub freshness_window: u64,
+    /// Maximum distance from the median, in basis points of the median.
+    pub max_deviation_bps: u32,
+    /// Fresh, non-outlying submissions required before the valuation moves.
+    pub min_submissions: u32,
+}
+
+/// One oracle's latest report for an asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValuationSubmission {
+    pub oracle: Address,
+    pub value: i128,
+    pub report_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// A valuation that was actually applied to the asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValuationRecord {
+    pub value: i128,
+    pub timestamp: u64,
+    /// Submissions the value was aggregated from; zero for an admin override.
+    pub submissions: u32,
+    /// Fresh submissions excluded as outliers.
+    pub rejected: u32,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `bool`: the address is an approved valuation oracle.
+    Oracle(Address),
+    /// `ValuationConfig`; defaults apply while unset.
+    Config,
+    /// `ValuationSubmission` for (asset_id, oracle).
+    Submission(u64, Address),
+    /// `Vec<Address>` of oracles that have ever submitted for an asset.
+    Submitters(u64),
+    /// `Vec<ValuationRecord>`, oldest first.
+    History(u64),
+}
+
+// ─── Oracle allowlist and configuration ───────────────────────────────────────
+
+pub fn add_oracle(env: &Env, oracle: &Address) {
+    let key = DataKey::Oracle(oracle.clone());
+    env.storage().persistent().set(&key, &true);
+    ttl::extend_persistent(env, &key);
+    crate::events::oracle_added(env, oracle);
+}
+
+pub fn remove_oracle(env: &Env, oracle: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Oracle(oracle.clone()));
+    crate::events::oracle_removed(env, oracle);
+}
+
+pub fn is_oracle(env: &Env, oracle: &Address) -> bool {
+    let key = DataKey::Oracle(oracle.clone());
+    ttl::extend_persistent(env, &key);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+pub fn get_config(env: &Env) -> ValuationConfig {
+    ttl::extend_persistent(env, &DataKey::Config);
+    env.storage()
+        .persistent()
+        .get(&DataKey::Config)
+        .unwrap_or(ValuationConfig {
+            freshness_window: DEFAULT_FRESHNESS_WINDOW,
+            max_deviation_bps: DEFAULT_MAX_DEVIATION_BPS,
+            min_submissions: DEFAULT_MIN_SUBMISSIONS,
+        })
+}
+
+pub fn set_config(env: &Env, config: ValuationConfig) -> Result<(), Error> {
+    if config.freshness_window == 0 || config.min_submissions == 0 {
+        return Err(Error::InvalidInput);
+    }
+    env.storage().persistent().set(&DataKey::Config, &config);
+    ttl::extend_persistent(env, &DataKey::Config);
+    Ok(())
+}
+
+// ─── Submission and aggregation ───────────────────────────────────────────────
+
+/// Records `oracle`'s valuation of `asset_id` and re-aggregates.
+///
+/// Returns the asset's valuation after the submission, which is unchanged if
+/// too few fresh, non-outlying submissions exist yet.
+pub fn submit_valuation(
+    env: &Env,
+    asset_id: u64,
+    oracle: Address,
+    value: i128,
+    report_hash: BytesN<32>,
+) -> Result<i128, Error> {
+    if value <= 0 {
+        return Err(Error::InvalidValuation);
+    }
+    if !is_oracle(env, &oracle) {
+        return Err(Error::OracleNotAuthorized);
+    }
+
+    let store = env.storage().persistent();
+    let asset_key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset =
+        store.get(&asset_key).ok_or(Error::AssetNotTokenized)?;
+
+    let now = env.ledger().timestamp();
+    let submission = ValuationSubmission {
+        oracle: oracle.clone(),
+        value,
+        report_hash: report_hash.clone(),
+        timestamp: now,
+    };
+    let submission_key = DataKey::Submission(asset_id, oracle.clone());
+    store.set(&submission_key, &submission);
+    ttl::extend_persistent(env, &submission_key);
+
+    let submitters_key = DataKey::Submitters(asset_id);
+    let mut submitters: Vec<Address> = store.get(&submitters_key).unwrap_or_else(|| Vec::new(env));
+    if !submitters.iter().any(|s| s == oracle) {
+        submitters.push_back(oracle.clone());
+        store.set(&submitters_key, &submitters);
+    }
+    ttl::extend_persistent(env, &submitters_key);
+
+    crate::events::valuation_submitted(env, asset_id, &oracle, value, &report_hash);
+
+    // Gather the submissions that still count.
+    let config = get_config(env);
+    let mut fresh: Vec<ValuationSubmission> = Vec::new(env);
+    for submitter in submitters.iter() {
+        if !is_oracle(env, &submitter) {
+            continue;
+        }
+        let key = DataKey::Submission(asset_id, submitter);
+        if let Some(s) = store.get::<_, ValuationSubmission>(&key) {
+            if now.saturating_sub(s.timestamp) <= config.freshness_window {
+                fresh.push_back(s);
+            }
+        }
+    }
+
+    if fresh.len() < config.min_submissions {
+        return Ok(tokenized_asset.valuation);
+    }
+
+    let mut values: Vec<i128> = Vec::new(env);
+    for s in fresh.iter() {
+        values.push_back(s.value);
+    }
+    let reference = median(&values)?;
+
+    let mut accepted: Vec<i128> = Vec::new(env);
+    let mut rejected: u32 = 0;
+    for s in fresh.iter() {
+        if is_outlier(s.value, reference, config.max_deviation_bps)? {
+            rejected += 1;
+            crate::events::valuation_outlier_rejected(env, asset_id, &s.oracle, s.value, reference);
+        } else {
+            accepted.push_back(s.value);
+        }
+    }
+
+    if accepted.len() < config.min_submissions {
+        return Ok(tokenized_asset.valuation);
+    }
+
+    let new_valuation = median(&accepted)?;
+    tokenized_asset.valuation = new_valuation;
+    store.set(&asset_key, &tokenized_asset);
+
+    record(env, asset_id, new_valuation, accepted.len(), rejected);
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "VALUATION_AGGREGATED"),
+        oracle,
+        String::from_str(env, "Valuation updated from oracle submissions"),
+    );
+
+    crate::events::valuation_updated(env, asset_id, new_valuation);
+
+    Ok(new_valuation)
+}
+
+/// Appends an applied valuation to the asset's history, dropping the oldest
+/// record once [`MAX_VALUATION_HISTORY`] is reached.
+pub fn record(env: &Env, asset_id: u64, value: i128, submissions: u32, rejected: u32) {
+    let key = DataKey::History(asset_id);
+    let store = env.storage().persistent();
+    let mut history: Vec<ValuationRecord> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+
+    history.push_back(ValuationRecord {
+        value,
+        timestamp: env.ledger().timestamp(),
+        submissions,
+        rejected,
+    });
+    while history.len() > MAX_VALUATION_HISTORY {
+        history.pop_front();
+    }
+
+    store.set(&key, &history);
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn get_history(env: &Env, asset_id: u64) -> Vec<ValuationRecord> {
+    let key = DataKey::History(asset_id);
+    ttl::extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Net asset value of one whole token, in the valuation's unit.
+///
+/// Balances are held in base units, so a whole token is `10^decimals` of
+/// them. Rounds down.
+pub fn nav_per_token(env: &Env, asset_id: u64) -> Result<i128, Error> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.total_supply <= 0 {
+        return Ok(0);
+    }
+
+    let one_token = 10i128
+        .checked_pow(tokenized_asset.decimals)
+        .ok_or(Error::MathOverflow)?;
+
+    math::mul_div(
+        tokenized_asset.valuation,
+        one_token,
+        tokenized_asset.total_supply,
+    )
+}
+
+// ─── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Median of `values`, rounding down between the two middle values of an
+/// even-length input. `values` must not be empty.
+fn median(values: &Vec<i128>) -> Result<i128, Error> {
+    // Insertion sort: the input is bounded by the number of approved oracles,
+    // and the SDK `Vec` has no sort of its own.
+    let mut sorted = values.clone();
+    let n = sorted.len();
+    for i in 1..n {
+        let mut j = i;
+        while j > 0 && sorted.get_unchecked(j - 1) > sorted.get_unchecked(j) {
+            let a = sorted.get_unchecked(j - 1);
+            let b = sorted.get_unchecked(j);
+            sorted.set(j - 1, b);
+            sorted.set(j, a);
+            j -= 1;
+        }
+    }
+
+    let mid = n / 2;
+    if n % 2 == 1 {
+        Ok(sorted.get_unchecked(mid))
+    } else {
+        let sum = math::add(sorted.get_unchecked(mid - 1), sorted.get_unchecked(mid))?;
+        Ok(sum / 2)
+    }
+}
+
+/// Whether `value` lies more than `max_deviation_bps` away from `reference`.
+fn is_outlier(value: i128, reference: i128, max_deviation_bps: u32) -> Result<bool, Error> {
+    let distance = math::sub(value, reference)?.abs();
+    let allowed = math::mul_div(reference, max_deviation_bps as i128, 10_000)?;
+    Ok(distance > allowed)
+}
@@ -0,0 +1,310 @@
+//! Vesting grants: partial, time-released locks on a holder's tokens.
+//!
+//! `lock_tokens` freezes a holder's whole balance until a timestamp. A vesting
+//! grant instead locks a specific amount and releases it over time, so the
+//! rest of the holder's balance stays freely transferable.
+//!
+//! A grant moves `amount` from the tokenizer to the holder when it is
+//! created. Nothing vests before `cliff`; everything has vested at `end`.
+//! Between the two, release follows the grant's [`VestingRelease`]:
+//!
+//! - `Linear` vests proportionally to the time elapsed since `start`.
+//! - `Stepped(period)` vests in equal tranches, one per whole `period`
+//!   elapsed since `start`.
+//!
+//! Both round down, so a holder never has access to a token early.
+//!
+//! `transfer_tokens` refuses any transfer that would dip into the unvested
+//! part of the sender's balance. Revoking a revocable grant returns whatever
+//! has not yet vested to the tokenizer and freezes the grant at its vested
+//! amount.
+//!
+//! The asset's stored `locked_tokens` is the unvested total as of the last
+//! grant or revoke, so internal reads of the asset never walk its grantees;
+//! `get_tokenized_asset` gives the total as of now. Holders whose grants have
+//! all vested are dropped from the grantee list at each grant or revoke, so
+//! the list only holds grants still releasing.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::audit;
+use crate::error::Error;
+use crate::math;
+use crate::tokenization::{asset_id_to_bytes, move_tokens};
+use crate::ttl;
+use crate::types::{TokenDataKey, TokenizedAsset};
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// How a grant releases tokens between its cliff and its end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingRelease {
+    /// Continuously, in proportion to time elapsed since `start`.
+    Linear,
+    /// In equal tranches, one per whole period (in seconds) since `start`.
+    Stepped(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    /// Total tokens under the grant. Reduced to the vested amount on revoke.
+    pub amount: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+    pub release: VestingRelease,
+    pub revocable: bool,
+    pub revoked: bool,
+}
+
+// ─── Storage Keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// `Vec<VestingGrant>` for (asset_id, holder); the index is the grant id.
+    Grants(u64, Address),
+    /// `Vec<Address>` of holders with tokens still unvested as of the last
+    /// grant or revoke.
+    Holders(u64),
+}
+
+// ─── Grant lifecycle ──────────────────────────────────────────────────────────
+
+/// Moves `amount` from the tokenizer to `holder` under a new vesting grant.
+/// Only the tokenizer can grant. Returns the grant id.
+#[allow(clippy::too_many_arguments)]
+pub fn create_grant(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    amount: i128,
+    start: u64,
+    cliff: u64,
+    end: u64,
+    release: VestingRelease,
+    revocable: bool,
+    tokenizer: Address,
+) -> Result<u32, Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidTokenSupply);
+    }
+    if start >= end || cliff < start || cliff > end {
+        return Err(Error::InvalidTimestamps);
+    }
+    if let VestingRelease::Stepped(period) = release {
+        if period == 0 || period > end - start {
+            return Err(Error::InvalidTimestamps);
+        }
+    }
+
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != tokenizer {
+        return Err(Error::Unauthorized);
+    }
+    // The tokenizer's own balance is the grant pool; a grant to itself would
+    // lock the source of every other grant.
+    if holder == tokenizer {
+        return Err(Error::InvalidInput);
+    }
+
+    move_tokens(env, &tokenized_asset, &tokenizer, &holder, amount)?;
+
+    let key = DataKey::Grants(asset_id, holder.clone());
+    let mut grants: Vec<VestingGrant> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+    let grant_id = grants.len();
+    grants.push_back(VestingGrant {
+        amount,
+        start,
+        cliff,
+        end,
+        release,
+        revocable,
+        revoked: false,
+    });
+    store.set(&key, &grants);
+    ttl::extend_persistent(env, &key);
+
+    let holders_key = DataKey::Holders(asset_id);
+    let mut holders: Vec<Address> = store.get(&holders_key).unwrap_or_else(|| Vec::new(env));
+    if !holders.contains(&holder) {
+        holders.push_back(holder.clone());
+        store.set(&holders_key, &holders);
+        ttl::extend_persistent(env, &holders_key);
+    }
+
+    checkpoint(env, asset_id)?;
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "VESTING_GRANTED"),
+        tokenizer,
+        String::from_str(env, "Tokens granted under a vesting schedule"),
+    );
+
+    crate::events::vesting_grant_created(env, asset_id, &holder, grant_id, amount, cliff, end);
+
+    Ok(grant_id)
+}
+
+/// Returns the unvested part of a revocable grant to the tokenizer. Returns
+/// the amount returned.
+pub fn revoke_grant(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    grant_id: u32,
+    tokenizer: Address,
+) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != tokenizer {
+        return Err(Error::Unauthorized);
+    }
+
+    let key = DataKey::Grants(asset_id, holder.clone());
+    let mut grants: Vec<VestingGrant> = store.get(&key).ok_or(Error::NotFound)?;
+    let mut grant = grants.get(grant_id).ok_or(Error::NotFound)?;
+
+    if !grant.revocable {
+        return Err(Error::VestingNotRevocable);
+    }
+
+    let vested = vested_amount(&grant, env.ledger().timestamp())?;
+    let unvested = math::sub(grant.amount, vested)?;
+    if grant.revoked || unvested <= 0 {
+        return Err(Error::InsufficientLockedTokens);
+    }
+
+    // The holder cannot normally hold less than their unvested tokens, but
+    // never try to take back more than is there.
+    let balance = crate::tokenization::get_token_balance(env, asset_id, holder.clone())?;
+    let returned = unvested.min(balance);
+    if returned > 0 {
+        move_tokens(env, &tokenized_asset, &holder, &tokenizer, returned)?;
+    }
+
+    grant.amount = vested;
+    grant.revoked = true;
+    grants.set(grant_id, grant);
+    store.set(&key, &grants);
+    ttl::extend_persistent(env, &key);
+
+    checkpoint(env, asset_id)?;
+
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "VESTING_REVOKED"),
+        tokenizer,
+        String::from_str(env, "Unvested tokens returned to tokenizer"),
+    );
+
+    crate::events::vesting_grant_revoked(env, asset_id, &holder, grant_id, returned);
+
+    Ok(returned)
+}
+
+// ─── Queries ──────────────────────────────────────────────────────────────────
+
+pub fn get_grants(env: &Env, asset_id: u64, holder: Address) -> Vec<VestingGrant> {
+    let key = DataKey::Grants(asset_id, holder);
+    ttl::extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Tokens vested under `grant` at time `now`.
+pub fn vested_amount(grant: &VestingGrant, now: u64) -> Result<i128, Error> {
+    if grant.revoked || now >= grant.end {
+        return Ok(grant.amount);
+    }
+    if now < grant.cliff {
+        return Ok(0);
+    }
+
+    let elapsed = now - grant.start;
+    let duration = grant.end - grant.start;
+    match grant.release {
+        VestingRelease::Linear => math::mul_div(grant.amount, elapsed as i128, duration as i128),
+        VestingRelease::Stepped(period) => {
+            let steps = duration.div_ceil(period);
+            math::mul_div(grant.amount, (elapsed / period) as i128, steps as i128)
+        }
+    }
+}
+
+/// Tokens in `holder`'s balance that have not vested yet.
+pub fn unvested_balance(env: &Env, asset_id: u64, holder: &Address) -> Result<i128, Error> {
+    let grants: Vec<VestingGrant> = match env
+        .storage()
+        .persistent()
+        .get(&DataKey::Grants(asset_id, holder.clone()))
+    {
+        Some(grants) => grants,
+        None => return Ok(0),
+    };
+
+    let now = env.ledger().timestamp();
+    let mut unvested = 0i128;
+    for grant in grants.iter() {
+        let vested = vested_amount(&grant, now)?;
+        unvested = math::add(unvested, math::sub(grant.amount, vested)?)?;
+    }
+    Ok(unvested)
+}
+
+/// Unvested tokens across every grantee of the asset, as of now.
+pub fn locked_total(env: &Env, asset_id: u64) -> Result<i128, Error> {
+    let holders: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Holders(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut total = 0i128;
+    for holder in holders.iter() {
+        total = math::add(total, unvested_balance(env, asset_id, &holder)?)?;
+    }
+    Ok(total)
+}
+
+/// Writes the current [`locked_total`] into the stored `locked_tokens`, and
+/// drops grantees with nothing left to vest. Vesting only ever releases, so a
+/// dropped grantee stays at zero until a new grant adds them back.
+fn checkpoint(env: &Env, asset_id: u64) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let holders_key = DataKey::Holders(asset_id);
+    let holders: Vec<Address> = store.get(&holders_key).unwrap_or_else(|| Vec::new(env));
+
+    let mut active = Vec::new(env);
+    let mut total = 0i128;
+    for holder in holders.iter() {
+        let unvested = unvested_balance(env, asset_id, &holder)?;
+        if unvested > 0 {
+            total = math::add(total, unvested)?;
+            active.push_back(holder);
+        }
+    }
+    if active.len() != holders.len() {
+        store.set(&holders_key, &active);
+    }
+
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.locked_tokens = total;
+    store.set(&key, &tokenized_asset);
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::ttl;
 use crate::types::{OwnershipRecord, TokenDataKey, TokenizedAsset};
 use soroban_sdk::{Address, Env, Vec};
 
@@ -9,10 +10,14 @@ pub fn cast_vote(env: &Env, asset_id: u64, proposal_id: u64, voter: Address) ->
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
+
+    crate::compliance::require_not_frozen(env, asset_id, &voter)?;
 
     // Get voter's balance
     let holder_key = TokenDataKey::TokenHolder(asset_id, voter.clone());
     let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    ttl::extend_persistent(env, &holder_key);
 
     // Check if voter has sufficient voting power
     if ownership.balance < tokenized_asset.min_voting_threshold {
@@ -27,6 +32,7 @@ pub fn cast_vote(env: &Env, asset_id: u64, proposal_id: u64, voter: Address) ->
 
     // Record vote
     store.set(&vote_key, &true);
+    ttl::extend_persistent(env, &vote_key);
 
     // Update vote tally
     let tally_key = TokenDataKey::VoteTally(asset_id, proposal_id);
@@ -34,6 +40,7 @@ pub fn cast_vote(env: &Env, asset_id: u64, proposal_id: u64, voter: Address) ->
 
     let new_tally = current_tally + ownership.balance;
     store.set(&tally_key, &new_tally);
+    ttl::extend_persistent(env, &tally_key);
 
     // Emit event: (asset_id, proposal_id, voter, weight)
     crate::events::vote_cast(env, asset_id, proposal_id, &voter, ownership.balance);
@@ -48,8 +55,10 @@ pub fn get_vote_tally(env: &Env, asset_id: u64, proposal_id: u64) -> Result<i128
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     let tally_key = TokenDataKey::VoteTally(asset_id, proposal_id);
+    ttl::extend_persistent(env, &tally_key);
 
     Ok(store.get::<_, i128>(&tally_key).unwrap_or(0))
 }
@@ -66,8 +75,10 @@ pub fn has_voted(
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     let vote_key = TokenDataKey::VoteRecord(asset_id, proposal_id, voter);
+    ttl::extend_persistent(env, &vote_key);
 
     Ok(store.has(&vote_key))
 }
@@ -79,10 +90,12 @@ pub fn proposal_passed(env: &Env, asset_id: u64, proposal_id: u64) -> Result<boo
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get vote tally
     let tally_key = TokenDataKey::VoteTally(asset_id, proposal_id);
     let tally: i128 = store.get::<_, i128>(&tally_key).unwrap_or(0);
+    ttl::extend_persistent(env, &tally_key);
 
     // Calculate required threshold
     let threshold =
@@ -103,10 +116,10 @@ pub fn get_proposal_voters(
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get all token holders
-    let holders_key = TokenDataKey::TokenHoldersList(asset_id);
-    let holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
+    let holders = crate::holders::all(env, asset_id);
 
     // Filter those who voted
     let mut voters = Vec::new(env);
@@ -127,10 +140,10 @@ pub fn clear_proposal_votes(env: &Env, asset_id: u64, proposal_id: u64) -> Resul
     // Verify asset is tokenized
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    ttl::extend_persistent(env, &key);
 
     // Get all token holders
-    let holders_key = TokenDataKey::TokenHoldersList(asset_id);
-    let holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
+    let holders = crate::holders::all(env, asset_id);
 
     // Remove all vote records
     for holder in holders.iter() {
@@ -12,6 +12,7 @@ fn setup(env: &Env) -> AssetUpContractClient<'_> {
 }
 
 fn tokenize(client: &AssetUpContractClient<'_>, env: &Env, asset_id: u64, tokenizer: &Address) {
+    super::helpers::registry_link(env, client, asset_id, tokenizer);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(env, "DIV"),
@@ -22,6 +23,7 @@ fn tokenize(client: &AssetUpContractClient<'_>, env: &Env, asset_id: u64, tokeni
         &String::from_str(env, "Dividend Token"),
         &String::from_str(env, "Dividend test asset"),
         &AssetType::Physical,
+        &None,
     );
 }
 
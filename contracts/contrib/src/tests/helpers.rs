@@ -0,0 +1,29 @@
+use assetsup::asset::Asset;
+use assetsup::{AssetStatus, AssetUpContractClient};
+use soroban_sdk::{testutils::BytesN as _, Address, BytesN, Env, String, Vec};
+
+/// Registers an asset owned by `owner` in the registry and links it to
+/// `asset_id`, which `tokenize_asset` requires first.
+pub fn registry_link(
+    env: &Env,
+    client: &AssetUpContractClient<'_>,
+    asset_id: u64,
+    owner: &Address,
+) {
+    let registry_id = BytesN::random(env);
+    let asset = Asset {
+        id: registry_id.clone(),
+        name: String::from_str(env, "Test Asset"),
+        description: String::from_str(env, "A test asset"),
+        category: String::from_str(env, "Electronics"),
+        owner: owner.clone(),
+        registration_timestamp: 0,
+        last_transfer_timestamp: 0,
+        status: AssetStatus::Active,
+        metadata_uri: String::from_str(env, "ipfs://QmTest123456789"),
+        purchase_value: 1000,
+        custom_attributes: Vec::new(env),
+    };
+    client.register_asset(&asset, &client.get_admin());
+    client.link_registry_asset(&asset_id, &registry_id, owner);
+}
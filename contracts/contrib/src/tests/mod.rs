@@ -2,6 +2,7 @@ mod asset_registry_tests;
 
 mod detokenization;
 mod dividends;
+mod helpers;
 mod tokenization;
 mod voting;
 use crate::{Asset, AssetStatus, ContribContract, ContribContractClient};
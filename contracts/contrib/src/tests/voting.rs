@@ -12,6 +12,7 @@ fn setup(env: &Env) -> AssetUpContractClient<'_> {
 }
 
 fn tokenize(client: &AssetUpContractClient<'_>, env: &Env, asset_id: u64, tokenizer: &Address) {
+    super::helpers::registry_link(env, client, asset_id, tokenizer);
     client.tokenize_asset(
         &asset_id,
         &String::from_str(env, "VOT"),
@@ -22,6 +23,7 @@ fn tokenize(client: &AssetUpContractClient<'_>, env: &Env, asset_id: u64, tokeni
         &String::from_str(env, "Vote Token"),
         &String::from_str(env, "Voting test asset"),
         &AssetType::Physical,
+        &None,
     );
 }
 
@@ -65,6 +67,7 @@ fn test_cast_vote_below_threshold_panics() {
 
     env.mock_all_auths();
     // min_voting_threshold = 500_000; low_voter gets only 50 tokens
+    super::helpers::registry_link(&env, &client, 1, &tokenizer);
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "VOT"),
@@ -75,6 +78,7 @@ fn test_cast_vote_below_threshold_panics() {
         &String::from_str(&env, "Vote Token"),
         &String::from_str(&env, "Voting test asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer a tiny amount to low_voter
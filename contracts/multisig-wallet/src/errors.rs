@@ -33,22 +33,38 @@ pub enum Error {
     // ---------------------------------------------------------------
     /// No transaction exists under this id.
     TransactionNotFound = 300,
-    /// The transaction has already been executed, cancelled, or expired.
+    /// The transaction has already been executed, cancelled, vetoed, or
+    /// expired.
     TransactionAlreadyExecuted = 301,
     /// The transaction is past its deadline.
     TransactionExpired = 302,
-    /// This owner has already confirmed this transaction or proposal.
+    /// This owner has already confirmed this transaction or proposal, or
+    /// already vetoed this transaction.
     AlreadyConfirmed = 303,
     /// The initiator may not confirm their own transaction.
     CannotConfirmOwnTransaction = 304,
+    /// The owners who signed an authorization payload do not have enough
+    /// combined weight to reach the threshold.
+    InsufficientSignatures = 305,
+    /// A signer has not registered an ed25519 signing key.
+    SigningKeyNotSet = 306,
+    /// A signed transaction does not carry the wallet's current nonce.
+    InvalidNonce = 307,
+    /// The transaction's delay has not ended, or has not started because
+    /// its threshold is not met.
+    ExecutionDelayed = 308,
+    /// The transaction cannot be vetoed now: its type has no veto quorum,
+    /// or it is not inside its delay.
+    NotInVetoWindow = 309,
 
     // ---------------------------------------------------------------
     // Owner and threshold governance: 320–339
     // ---------------------------------------------------------------
-    /// The requested threshold is zero, or exceeds the owner count.
+    /// The requested threshold is zero, or exceeds the owners' total weight.
     InvalidThreshold = 320,
-    /// The wallet would be left with fewer than two owners, or with a
-    /// threshold its remaining owners could never reach.
+    /// The wallet would be left with fewer than two owners, with no Primary
+    /// owner, or with a threshold its remaining voting weight could never
+    /// reach.
     InsufficientOwners = 321,
     /// This address is already an owner.
     OwnerAlreadyExists = 322,
@@ -62,13 +78,17 @@ pub enum Error {
     NotAnOwner = 326,
     /// The requested threshold exceeds the number of owners.
     ThresholdTooHigh = 327,
+    /// The owner's role does not allow this action: only Primary owners
+    /// raise proposals, and Emergency owners may only freeze.
+    RoleNotPermitted = 328,
 
     // ---------------------------------------------------------------
     // Emergency controls and limits: 340–349
     // ---------------------------------------------------------------
     /// The wallet is frozen; mutating operations are rejected.
     WalletFrozen = 340,
-    /// The transaction would exceed the configured daily spend limit.
+    /// A token transfer has only its reduced confirmations, and would take the
+    /// token over its rolling 24-hour limit.
     DailyLimitExceeded = 341,
 }
 
@@ -93,6 +113,11 @@ mod tests {
             Error::TransactionExpired as u32,
             Error::AlreadyConfirmed as u32,
             Error::CannotConfirmOwnTransaction as u32,
+            Error::InsufficientSignatures as u32,
+            Error::SigningKeyNotSet as u32,
+            Error::InvalidNonce as u32,
+            Error::ExecutionDelayed as u32,
+            Error::NotInVetoWindow as u32,
             Error::InvalidThreshold as u32,
             Error::InsufficientOwners as u32,
             Error::OwnerAlreadyExists as u32,
@@ -101,6 +126,7 @@ mod tests {
             Error::InvalidProposal as u32,
             Error::NotAnOwner as u32,
             Error::ThresholdTooHigh as u32,
+            Error::RoleNotPermitted as u32,
             Error::WalletFrozen as u32,
             Error::DailyLimitExceeded as u32,
         ];
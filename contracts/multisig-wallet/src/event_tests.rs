@@ -7,8 +7,9 @@
 //! See `contracts/EVENTS.md` for the catalogue these assertions pin down.
 
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Symbol, TryIntoVal, Val, Vec};
+use soroban_sdk::{Address, BytesN, Env, Symbol, TryIntoVal, Val, Vec};
 
+use crate::types::{OwnerType, TransactionType, TypePolicy};
 use crate::{MultisigWallet, MultisigWalletClient};
 
 fn setup(env: &Env) -> (MultisigWalletClient<'_>, Address, Address, Address) {
@@ -137,8 +138,10 @@ fn freeze_and_unfreeze_emit_distinct_events() {
         Symbol::new(&env, "wallet_frozen")
     );
 
-    // Unfreezing requires an owner, not the admin.
-    client.emergency_unfreeze(&bob);
+    // Unfreezing takes a proposal; the second confirmation executes it.
+    let proposal_id = client.propose_unfreeze(&bob);
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
     let topics = last_topics(&env);
     assert_eq!(
         event_name(&env, &topics),
@@ -147,12 +150,14 @@ fn freeze_and_unfreeze_emit_distinct_events() {
 }
 
 #[test]
-fn set_daily_limit_emits_daily_limit_changed() {
-    // set_daily_limit was silent before the event catalogue.
+fn daily_limit_proposal_emits_daily_limit_changed() {
     let env = Env::default();
-    let (client, _admin, alice, _bob) = setup(&env);
+    let (client, _admin, alice, bob) = setup(&env);
+    let token = Address::generate(&env);
+    let proposal_id = client.propose_change_daily_limit(&alice, &token, &1_000u128, &1);
 
-    client.set_daily_limit(&alice, &1_000u128);
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
 
     let topics = last_topics(&env);
     assert_eq!(
@@ -160,3 +165,142 @@ fn set_daily_limit_emits_daily_limit_changed() {
         Symbol::new(&env, "daily_limit_changed")
     );
 }
+
+#[test]
+fn weight_change_reaching_quorum_emits_weight_changed() {
+    let env = Env::default();
+    let (client, _admin, alice, bob) = setup(&env);
+    let proposal_id = client.propose_change_weight(&alice, &bob, &2);
+
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
+
+    let topics = last_topics(&env);
+    assert_eq!(
+        event_name(&env, &topics),
+        Symbol::new(&env, "weight_changed")
+    );
+    let emitted: Address = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(emitted, bob, "the reweighted owner must be indexable");
+}
+
+#[test]
+fn owner_type_change_emits_owner_type_changed() {
+    let env = Env::default();
+    let (client, _admin, alice, bob) = setup(&env);
+    let proposal_id = client.propose_change_owner_type(&alice, &bob, &OwnerType::Secondary);
+
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
+
+    let topics = last_topics(&env);
+    assert_eq!(
+        event_name(&env, &topics),
+        Symbol::new(&env, "owner_type_changed")
+    );
+}
+
+#[test]
+fn a_failed_execution_emits_transaction_failed() {
+    let env = Env::default();
+    let (client, _admin, alice, bob) = setup(&env);
+    let tx_id = client.submit_transaction(
+        &alice,
+        &TransactionType::Routine,
+        &Address::generate(&env),
+        &Symbol::new(&env, "noop"),
+        &Vec::new(&env),
+        &3600,
+        &0,
+    );
+
+    client.confirm_transaction(&alice, &tx_id);
+    client.confirm_transaction(&bob, &tx_id);
+
+    let topics = last_topics(&env);
+    assert_eq!(
+        event_name(&env, &topics),
+        Symbol::new(&env, "transaction_failed")
+    );
+    let emitted: u64 = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(emitted, tx_id);
+}
+
+#[test]
+fn set_signing_key_emits_signing_key_set() {
+    let env = Env::default();
+    let (client, _admin, alice, _bob) = setup(&env);
+
+    client.set_signing_key(&alice, &BytesN::from_array(&env, &[1; 32]));
+
+    let topics = last_topics(&env);
+    assert_eq!(
+        event_name(&env, &topics),
+        Symbol::new(&env, "signing_key_set")
+    );
+    let emitted: Address = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(emitted, alice);
+}
+
+#[test]
+fn type_policy_proposal_emits_type_policy_changed() {
+    let env = Env::default();
+    let (client, _admin, alice, bob) = setup(&env);
+    let policy = TypePolicy {
+        threshold: 2,
+        delay: 3600,
+        veto_quorum: 1,
+    };
+    let proposal_id = client.propose_change_type_policy(&alice, &TransactionType::Update, &policy);
+
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
+
+    let topics = last_topics(&env);
+    assert_eq!(
+        event_name(&env, &topics),
+        Symbol::new(&env, "type_policy_changed")
+    );
+}
+
+#[test]
+fn a_delayed_transaction_is_queued_and_can_be_vetoed() {
+    let env = Env::default();
+    let (client, _admin, alice, bob) = setup(&env);
+    let policy = TypePolicy {
+        threshold: 2,
+        delay: 3600,
+        veto_quorum: 1,
+    };
+    let proposal_id = client.propose_change_type_policy(&alice, &TransactionType::Update, &policy);
+    client.confirm_proposal(&alice, &proposal_id);
+    client.confirm_proposal(&bob, &proposal_id);
+
+    let tx_id = client.submit_transaction(
+        &alice,
+        &TransactionType::Update,
+        &Address::generate(&env),
+        &Symbol::new(&env, "noop"),
+        &Vec::new(&env),
+        &86_400,
+        &0,
+    );
+    client.confirm_transaction(&alice, &tx_id);
+    client.confirm_transaction(&bob, &tx_id);
+    assert_eq!(
+        event_name(&env, &last_topics(&env)),
+        Symbol::new(&env, "transaction_queued")
+    );
+
+    client.veto_transaction(&bob, &tx_id);
+    assert_eq!(
+        event_names(&env),
+        Vec::from_array(
+            &env,
+            [
+                Symbol::new(&env, "veto_cast"),
+                Symbol::new(&env, "transaction_vetoed"),
+            ]
+        )
+    );
+}
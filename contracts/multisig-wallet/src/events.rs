@@ -5,9 +5,9 @@
 //! `lower_snake_case`. The identifier a consumer would filter on (`tx_id`,
 //! `proposal_id`, an owner address) is marked `#[topic]`.
 
-use soroban_sdk::{contractevent, Address, Env, Vec};
+use soroban_sdk::{contractevent, Address, BytesN, Env, Vec};
 
-use crate::types::{ProposalType, TransactionType};
+use crate::types::{ExecutionError, OwnerType, ProposalType, TransactionType, TypePolicy};
 
 /// The wallet was initialized with its owner set and threshold.
 #[contractevent]
@@ -62,6 +62,17 @@ pub struct TransactionExecuted {
     pub timestamp: u64,
 }
 
+/// An execution attempt failed. The transaction is now `Failed` and may be
+/// executed again until its deadline.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionFailed {
+    #[topic]
+    pub tx_id: u64,
+    pub error: ExecutionError,
+    pub timestamp: u64,
+}
+
 /// A pending transaction was cancelled before execution.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -123,6 +134,38 @@ pub struct ThresholdChanged {
     pub timestamp: u64,
 }
 
+/// An owner's voting weight was changed by an executed proposal.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeightChanged {
+    #[topic]
+    pub owner: Address,
+    pub old_weight: u32,
+    pub new_weight: u32,
+    pub timestamp: u64,
+}
+
+/// An owner registered or replaced their ed25519 signing key.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningKeySet {
+    #[topic]
+    pub owner: Address,
+    pub key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// An owner's role was changed by an executed proposal.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerTypeChanged {
+    #[topic]
+    pub owner: Address,
+    pub old_type: OwnerType,
+    pub new_type: OwnerType,
+    pub timestamp: u64,
+}
+
 /// The wallet was frozen; all mutating operations now reject.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -132,7 +175,9 @@ pub struct WalletFrozen {
     pub timestamp: u64,
 }
 
-/// The wallet was unfrozen and resumed normal operation.
+/// An unfreeze proposal executed and the wallet resumed normal operation.
+///
+/// `caller` is the owner who raised the proposal.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct WalletUnfrozen {
@@ -141,20 +186,72 @@ pub struct WalletUnfrozen {
     pub timestamp: u64,
 }
 
-/// The daily spend limit was changed.
+/// A token's daily limit was changed by an executed proposal. A `limit` of
+/// `0` means the limit was removed.
+///
+/// `caller` is the owner who raised the proposal.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimitChanged {
     #[topic]
     pub caller: Address,
+    pub token: Address,
     pub limit: u128,
     pub timestamp: u64,
 }
 
-/// A transaction was rejected because it would exceed the daily spend limit.
+/// A transaction type's policy was set or removed by an executed proposal.
+/// `caller` is the owner who raised the proposal. A zero `threshold` means
+/// the type is back to the wallet's threshold with no delay.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypePolicyChanged {
+    #[topic]
+    pub caller: Address,
+    pub tx_type: TransactionType,
+    pub threshold: u32,
+    pub delay: u64,
+    pub veto_quorum: u32,
+    pub timestamp: u64,
+}
+
+/// A transaction reached its threshold and is waiting out its delay.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionQueued {
+    #[topic]
+    pub tx_id: u64,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+/// An owner vetoed a transaction during its delay.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VetoCast {
+    #[topic]
+    pub tx_id: u64,
+    pub vetoer: Address,
+    pub veto_count: u32,
+    pub timestamp: u64,
+}
+
+/// Vetoes reached the transaction's veto quorum; it will not execute.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionVetoed {
+    #[topic]
+    pub tx_id: u64,
+    pub timestamp: u64,
+}
+
+/// A token transfer short of the full threshold was rejected because it would
+/// take the token over its daily limit.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimitReached {
+    #[topic]
+    pub token: Address,
     pub limit: u128,
     pub attempted_total: u128,
     pub timestamp: u64,
@@ -212,6 +309,42 @@ pub fn transaction_executed(env: &Env, tx_id: u64, initiator: &Address) {
     .publish(env);
 }
 
+pub fn transaction_queued(env: &Env, tx_id: u64, executable_at: u64) {
+    TransactionQueued {
+        tx_id,
+        executable_at,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn veto_cast(env: &Env, tx_id: u64, vetoer: &Address, veto_count: u32) {
+    VetoCast {
+        tx_id,
+        vetoer: vetoer.clone(),
+        veto_count,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn transaction_vetoed(env: &Env, tx_id: u64) {
+    TransactionVetoed {
+        tx_id,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn transaction_failed(env: &Env, tx_id: u64, error: ExecutionError) {
+    TransactionFailed {
+        tx_id,
+        error,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
 pub fn transaction_cancelled(env: &Env, tx_id: u64, caller: &Address) {
     TransactionCancelled {
         tx_id,
@@ -289,20 +422,68 @@ pub fn wallet_unfrozen(env: &Env, caller: &Address) {
     .publish(env);
 }
 
-pub fn daily_limit_changed(env: &Env, caller: &Address, limit: u128) {
+pub fn daily_limit_changed(env: &Env, caller: &Address, token: &Address, limit: u128) {
     DailyLimitChanged {
         caller: caller.clone(),
+        token: token.clone(),
         limit,
         timestamp: env.ledger().timestamp(),
     }
     .publish(env);
 }
 
-pub fn daily_limit_reached(env: &Env, limit: u128, attempted_total: u128) {
+pub fn type_policy_changed(
+    env: &Env,
+    caller: &Address,
+    tx_type: TransactionType,
+    policy: &TypePolicy,
+) {
+    TypePolicyChanged {
+        caller: caller.clone(),
+        tx_type,
+        threshold: policy.threshold,
+        delay: policy.delay,
+        veto_quorum: policy.veto_quorum,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn daily_limit_reached(env: &Env, token: &Address, limit: u128, attempted_total: u128) {
     DailyLimitReached {
+        token: token.clone(),
         limit,
         attempted_total,
         timestamp: env.ledger().timestamp(),
     }
     .publish(env);
 }
+
+pub fn weight_changed(env: &Env, owner: &Address, old_weight: u32, new_weight: u32) {
+    WeightChanged {
+        owner: owner.clone(),
+        old_weight,
+        new_weight,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn signing_key_set(env: &Env, owner: &Address, key: BytesN<32>) {
+    SigningKeySet {
+        owner: owner.clone(),
+        key,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
+pub fn owner_type_changed(env: &Env, owner: &Address, old_type: OwnerType, new_type: OwnerType) {
+    OwnerTypeChanged {
+        owner: owner.clone(),
+        old_type,
+        new_type,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
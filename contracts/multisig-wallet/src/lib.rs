@@ -8,13 +8,59 @@
 //! itself are changed through the same confirmation flow, so no single owner can
 //! unilaterally alter the wallet.
 //!
+//! ## Voting weight
+//!
+//! Every owner carries a `voting_weight`, 1 by default. A confirmation adds the
+//! confirmer's weight rather than 1, and the threshold is a *weight*: the
+//! combined weight of the confirming owners must reach it. Weights change only
+//! through a `ChangeWeight` proposal. With every weight at 1 this reduces to
+//! the plain *m-of-n* count.
+//!
+//! ## Token transfers and daily limits
+//!
+//! [`MultisigWallet::submit_token_transfer`] moves tokens the wallet holds,
+//! through the token's own `transfer`. A token may have a daily limit, set by
+//! a `ChangeDailyLimit` proposal. The limit applies over a rolling 24 hours,
+//! not a calendar day. A transfer that fits inside it executes on the limit's
+//! smaller threshold. A transfer that does not fit needs the full threshold.
+//! The limit is a tier, not a cap: with the full threshold, any amount can
+//! move.
+//!
+//! ## Transaction type policies
+//!
+//! A [`TypePolicy`] gives one [`TransactionType`] its own threshold, a delay
+//! between reaching it and executing, and a veto quorum that can block the
+//! transaction during that delay. Policies change only through a
+//! `ChangeTypePolicy` proposal. A transaction keeps the policy it was
+//! submitted under.
+//!
+//! ## Acting as an account
+//!
+//! The wallet implements [`CustomAccountInterface`], so its own address can
+//! hold assets and roles in other contracts. When a contract calls
+//! `require_auth` on the wallet address, the host runs `__check_auth`, which
+//! accepts the payload once owners with enough combined weight have signed
+//! it. This is an alternative to routing the call through
+//! [`MultisigWallet::execute_transaction`], not a replacement for it.
+//!
+//! ## Signed execution
+//!
+//! [`MultisigWallet::execute_with_signatures`] executes a transaction that
+//! owners approved off-chain, by checking each owner's ed25519 signature
+//! against the key in their profile. The signed hash binds the wallet, the
+//! network and a nonce, so each set of signatures executes once.
+//!
 //! ## Invariants
 //!
-//! - The threshold is always `>= 1` and `<= owners.len()`.
+//! - The threshold is always `>= 1` and `<=` the total weight of the active
+//!   owners, so the owners can always reach it. So is every type policy's
+//!   threshold.
 //! - A wallet always has at least two owners.
 //! - A transaction executes at most once.
-//! - A frozen wallet rejects every mutating operation except
-//!   [`MultisigWallet::emergency_unfreeze`].
+//! - A frozen wallet rejects every transaction operation. Proposals still go
+//!   through, so [`MultisigWallet::propose_unfreeze`] can lift the freeze.
+//! - A wallet always has at least one Primary owner, since only Primary
+//!   owners can raise proposals. See [`OwnerType`] for the roles.
 //! - One owner cannot confirm the same transaction twice.
 //!
 //! ## Not to be confused with `multisig-transfer`
@@ -27,7 +73,13 @@
 //! See [`README.md`](https://github.com/DistinctCodes/AssetsUp/blob/main/contracts/multisig-wallet/README.md)
 //! for the full entrypoint, storage, event, and error tables.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Val, Vec};
+use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contractimpl, token, Address, Bytes, BytesN, Env, IntoVal, InvokeError, Symbol,
+    TryFromVal, Val, Vec,
+};
 
 mod errors;
 #[cfg(test)]
@@ -43,6 +95,27 @@ mod types;
 pub use crate::errors::Error;
 pub use crate::types::*;
 
+/// Upper bound on a single owner's voting weight.
+///
+/// Keeps weight sums far from `u32` overflow, and stops one owner being given
+/// a weight that dwarfs everyone else's by accident of a typo.
+pub const MAX_VOTING_WEIGHT: u32 = 100;
+
+/// Most calls one batch transaction may carry. Every call runs inside one
+/// invocation, so a batch has to fit one invocation's resource budget.
+pub const MAX_BATCH_CALLS: u32 = 10;
+
+/// Domain tag at the start of every `execute_with_signatures` payload, so a
+/// signature made for it cannot be replayed as a signature over anything else.
+pub const SIGNATURE_DOMAIN: &[u8] = b"multisig-wallet:execute_with_signatures:v1";
+
+/// Longest delay a transaction type's policy may impose. A transaction whose
+/// deadline falls before its delay ends will expire instead of running.
+pub const MAX_EXECUTION_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// Length of the rolling window a token's daily limit applies over.
+pub const LIMIT_WINDOW_SECS: u64 = 24 * 60 * 60;
+
 #[contract]
 pub struct MultisigWallet;
 
@@ -65,6 +138,7 @@ impl MultisigWallet {
             return Err(Error::InsufficientOwners);
         }
 
+        // Every initial owner has weight 1, so the total weight is the count.
         if threshold == 0 || threshold > owners.len() {
             return Err(Error::InvalidThreshold);
         }
@@ -78,7 +152,6 @@ impl MultisigWallet {
             .instance()
             .set(&DataKey::NextProposalId, &1u64);
         env.storage().instance().set(&DataKey::Frozen, &false);
-        env.storage().instance().set(&DataKey::DailyLimit, &0u128);
 
         for owner in owners.iter() {
             let profile = OwnerProfile {
@@ -90,6 +163,7 @@ impl MultisigWallet {
                 is_active: true,
                 total_confirmations: 0,
                 last_activity: env.ledger().timestamp(),
+                signing_key: None,
             };
             env.storage()
                 .persistent()
@@ -114,37 +188,114 @@ impl MultisigWallet {
         value: u128,
     ) -> Result<u64, Error> {
         initiator.require_auth();
-        Self::check_owner(&env, &initiator)?;
+        Self::check_can_vote(&env, &initiator)?;
         Self::check_not_frozen(&env)?;
 
-        let tx_id: u64 = env.storage().instance().get(&DataKey::NextTxId).unwrap();
-        env.storage()
-            .instance()
-            .set(&DataKey::NextTxId, &(tx_id + 1));
-
-        let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap();
-
+        let policy = Self::type_policy(&env, &tx_type);
         let tx = Transaction {
-            id: tx_id,
+            id: 0,
             tx_type,
             target,
             function_name,
             parameters,
-            initiator: initiator.clone(),
+            initiator,
             created_at: env.ledger().timestamp(),
             deadline: env.ledger().timestamp() + deadline_offset,
-            required_confirmations: threshold,
+            required_confirmations: policy.threshold,
             confirmations_count: 0,
             status: TransactionStatus::Pending,
             execution_timestamp: 0,
             value,
+            results: Vec::new(&env),
+            delay: policy.delay,
+            veto_quorum: policy.veto_quorum,
+            veto_count: 0,
+            executable_at: 0,
         };
 
+        Ok(Self::store_transaction(&env, tx))
+    }
+
+    /// Submit a batch: an ordered list of calls that execute together once
+    /// the threshold is met.
+    ///
+    /// The batch is atomic. If any call fails, the whole execution reverts,
+    /// including the calls before it, and the transaction stays pending.
+    /// The transaction's `target`, `function_name` and `parameters` show the
+    /// first call; [`MultisigWallet::get_calls`] lists them all.
+    pub fn submit_batch(
+        env: Env,
+        initiator: Address,
+        tx_type: TransactionType,
+        calls: Vec<Call>,
+        deadline_offset: u64,
+    ) -> Result<u64, Error> {
+        if calls.is_empty() || calls.len() > MAX_BATCH_CALLS {
+            return Err(Error::InvalidArguments);
+        }
+
+        let first = calls.get(0).unwrap();
+        let tx_id = Self::submit_transaction(
+            env.clone(),
+            initiator,
+            tx_type,
+            first.target,
+            first.function_name,
+            first.parameters,
+            deadline_offset,
+            0,
+        )?;
         env.storage()
             .persistent()
-            .set(&DataKey::Transaction(tx_id), &tx);
+            .set(&DataKey::BatchCalls(tx_id), &calls);
 
-        events::transaction_submitted(&env, tx_id, &initiator, tx.tx_type);
+        Ok(tx_id)
+    }
+
+    /// Submit a transfer of `amount` of `token` from the wallet to `to`.
+    ///
+    /// If the token has a daily limit and `amount` is within it, the transfer
+    /// executes on the limit's reduced threshold, provided the rolling 24-hour
+    /// total still fits when it runs. Otherwise it needs the full threshold.
+    pub fn submit_token_transfer(
+        env: Env,
+        initiator: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+        deadline_offset: u64,
+    ) -> Result<u64, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidArguments);
+        }
+
+        let parameters: Vec<Val> =
+            (env.current_contract_address(), to.clone(), amount).into_val(&env);
+        let tx_id = Self::submit_transaction(
+            env.clone(),
+            initiator,
+            TransactionType::Transfer,
+            token.clone(),
+            Symbol::new(&env, "transfer"),
+            parameters,
+            deadline_offset,
+            amount as u128,
+        )?;
+
+        let threshold = Self::type_policy(&env, &TransactionType::Transfer).threshold;
+        let reduced_confirmations = match Self::token_limit(&env, &token) {
+            Some(l) if amount as u128 <= l.limit => l.threshold.min(threshold),
+            _ => threshold,
+        };
+        env.storage().persistent().set(
+            &DataKey::TokenTransfer(tx_id),
+            &TokenTransfer {
+                token,
+                to,
+                amount,
+                reduced_confirmations,
+            },
+        );
 
         Ok(tx_id)
     }
@@ -152,7 +303,7 @@ impl MultisigWallet {
     /// Confirm transaction (by wallet owner)
     pub fn confirm_transaction(env: Env, confirmer: Address, tx_id: u64) -> Result<(), Error> {
         confirmer.require_auth();
-        Self::check_owner(&env, &confirmer)?;
+        Self::check_can_vote(&env, &confirmer)?;
         Self::check_not_frozen(&env)?;
 
         let mut tx: Transaction = env
@@ -161,7 +312,7 @@ impl MultisigWallet {
             .get(&DataKey::Transaction(tx_id))
             .ok_or(Error::TransactionNotFound)?;
 
-        if tx.status != TransactionStatus::Pending {
+        if !Self::is_open(&tx) {
             return Err(Error::TransactionAlreadyExecuted);
         }
 
@@ -189,8 +340,10 @@ impl MultisigWallet {
             .get(&DataKey::OwnerProfile(confirmer.clone()))
             .unwrap();
 
-        env.storage().persistent().set(&confirm_key, &true);
-        tx.confirmations_count += profile.voting_weight;
+        env.storage()
+            .persistent()
+            .set(&confirm_key, &profile.voting_weight);
+        tx.confirmations_count = Self::confirmed_weight(&env, |o| DataKey::Confirmation(tx_id, o));
         env.storage()
             .persistent()
             .set(&DataKey::Transaction(tx_id), &tx);
@@ -203,8 +356,29 @@ impl MultisigWallet {
 
         events::transaction_confirmed(&env, tx_id, &confirmer, tx.confirmations_count);
 
-        // Auto-execute if threshold reached
-        if tx.confirmations_count >= tx.required_confirmations {
+        // The delay starts once the threshold, full or reduced, is first met.
+        if tx.executable_at == 0 && tx.delay > 0 && Self::threshold_met(&env, &tx) {
+            tx.executable_at = env.ledger().timestamp() + tx.delay;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Transaction(tx_id), &tx);
+            events::transaction_queued(&env, tx_id, tx.executable_at);
+        }
+
+        // Auto-execute once enough weight is in: the full threshold, or a
+        // token transfer's reduced threshold while it fits the daily limit.
+        // Delayed transactions wait for an explicit execute. Batches are never
+        // auto-executed: a failing batch reverts the whole invocation, and that
+        // would take this confirmation with it.
+        let reduced = Self::get_token_transfer(env.clone(), tx_id).filter(|t| {
+            tx.confirmations_count >= t.reduced_confirmations
+                && Self::fits_daily_limit(&env, &t.token, t.amount)
+        });
+        let batch = env.storage().persistent().has(&DataKey::BatchCalls(tx_id));
+        if !batch
+            && tx.delay == 0
+            && (tx.confirmations_count >= tx.required_confirmations || reduced.is_some())
+        {
             Self::execute_transaction(env, tx_id)?;
         }
 
@@ -222,7 +396,7 @@ impl MultisigWallet {
             .get(&DataKey::Transaction(tx_id))
             .ok_or(Error::TransactionNotFound)?;
 
-        if tx.status != TransactionStatus::Pending {
+        if !Self::is_open(&tx) {
             return Err(Error::TransactionAlreadyExecuted);
         }
 
@@ -231,14 +405,13 @@ impl MultisigWallet {
             return Err(Error::Unauthorized);
         }
 
-        let profile: OwnerProfile = env
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerProfile(revoker.clone()))
-            .unwrap();
-
         env.storage().persistent().remove(&confirm_key);
-        tx.confirmations_count -= profile.voting_weight;
+        tx.confirmations_count = Self::confirmed_weight(&env, |o| DataKey::Confirmation(tx_id, o));
+        // Falling below the threshold stops the delay; reaching it again
+        // starts a fresh one.
+        if !Self::threshold_met(&env, &tx) {
+            tx.executable_at = 0;
+        }
         env.storage()
             .persistent()
             .set(&DataKey::Transaction(tx_id), &tx);
@@ -249,6 +422,18 @@ impl MultisigWallet {
     }
 
     /// Execute transaction (anyone can trigger after threshold)
+    ///
+    /// A transaction whose type has a delay runs only once the delay that
+    /// started when it reached its threshold has ended.
+    ///
+    /// A call that fails does not fail this entrypoint. The transaction is
+    /// marked `Failed`, the reason is kept for
+    /// [`MultisigWallet::get_execution_error`], and it can be executed again
+    /// until its deadline. Batches are the exception: undoing the calls that
+    /// already ran needs the whole invocation to revert, so a failing batch
+    /// reverts and stays as it was. The host forbids a contract from calling
+    /// itself, so the batch cannot be run in a frame of its own that fails
+    /// without this one.
     pub fn execute_transaction(env: Env, tx_id: u64) -> Result<(), Error> {
         Self::check_not_frozen(&env)?;
 
@@ -258,11 +443,22 @@ impl MultisigWallet {
             .get(&DataKey::Transaction(tx_id))
             .ok_or(Error::TransactionNotFound)?;
 
-        if tx.status != TransactionStatus::Pending {
+        if !Self::is_open(&tx) {
             return Err(Error::TransactionAlreadyExecuted);
         }
 
-        if tx.confirmations_count < tx.required_confirmations {
+        // Owners removed or made Emergency since they confirmed no longer
+        // count.
+        tx.confirmations_count = Self::confirmed_weight(&env, |o| DataKey::Confirmation(tx_id, o));
+
+        // A token transfer short of the full threshold may still run on its
+        // reduced threshold, if it fits the daily limit (checked below).
+        let transfer = Self::get_token_transfer(env.clone(), tx_id);
+        let on_reduced = tx.confirmations_count < tx.required_confirmations;
+        let reduced_met = transfer
+            .as_ref()
+            .is_some_and(|t| tx.confirmations_count >= t.reduced_confirmations);
+        if on_reduced && !reduced_met {
             return Err(Error::Unauthorized);
         }
 
@@ -274,9 +470,17 @@ impl MultisigWallet {
             return Err(Error::TransactionExpired);
         }
 
-        // Check daily limit if it's a transfer with value
-        if tx.value > 0 {
-            Self::check_daily_limit(&env, tx.value)?;
+        if tx.delay > 0 && (tx.executable_at == 0 || env.ledger().timestamp() < tx.executable_at) {
+            return Err(Error::ExecutionDelayed);
+        }
+
+        if let (true, Some(t)) = (on_reduced, &transfer) {
+            if !Self::fits_daily_limit(&env, &t.token, t.amount) {
+                let limit = Self::token_limit(&env, &t.token).map_or(0, |l| l.limit);
+                let attempted = Self::spent_in_window(&env, &t.token) + t.amount as u128;
+                events::daily_limit_reached(&env, &t.token, limit, attempted);
+                return Err(Error::DailyLimitExceeded);
+            }
         }
 
         // Mark as executed first to prevent re-entrancy issues if any
@@ -286,11 +490,48 @@ impl MultisigWallet {
             .persistent()
             .set(&DataKey::Transaction(tx_id), &tx);
 
-        // Perform cross-contract call
-        let _result: Val =
-            env.invoke_contract(&tx.target, &tx.function_name, tx.parameters.clone());
+        let calls = Self::get_calls(env.clone(), tx_id);
+        let outcome = match transfer {
+            Some(t) => Self::try_token_transfer(&env, &t),
+            None if env.storage().persistent().has(&DataKey::BatchCalls(tx_id)) => {
+                // A failing call traps, which reverts every call before it
+                // along with the status change above.
+                let mut results = Vec::new(&env);
+                for call in calls.iter() {
+                    let result: Val =
+                        env.invoke_contract(&call.target, &call.function_name, call.parameters);
+                    results.push_back(result);
+                }
+                Ok(results)
+            }
+            None => Self::try_call(&env, &calls.get(0).unwrap()),
+        };
 
-        events::transaction_executed(&env, tx_id, &tx.initiator);
+        match outcome {
+            Ok(results) => {
+                tx.results = results;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Transaction(tx_id), &tx);
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::ExecutionError(tx_id));
+
+                events::transaction_executed(&env, tx_id, &tx.initiator);
+            }
+            Err(error) => {
+                tx.status = TransactionStatus::Failed;
+                tx.execution_timestamp = 0;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Transaction(tx_id), &tx);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::ExecutionError(tx_id), &error);
+
+                events::transaction_failed(&env, tx_id, error);
+            }
+        }
 
         Ok(())
     }
@@ -305,7 +546,7 @@ impl MultisigWallet {
             .get(&DataKey::Transaction(tx_id))
             .ok_or(Error::TransactionNotFound)?;
 
-        if tx.status != TransactionStatus::Pending {
+        if !Self::is_open(&tx) {
             return Err(Error::TransactionAlreadyExecuted);
         }
 
@@ -325,28 +566,193 @@ impl MultisigWallet {
         Ok(())
     }
 
+    /// Veto a transaction during its delay. Once the vetoes' combined weight
+    /// reaches the transaction's veto quorum it is `Vetoed` and never runs.
+    pub fn veto_transaction(env: Env, vetoer: Address, tx_id: u64) -> Result<(), Error> {
+        vetoer.require_auth();
+        Self::check_can_vote(&env, &vetoer)?;
+        Self::check_not_frozen(&env)?;
+
+        let mut tx: Transaction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Transaction(tx_id))
+            .ok_or(Error::TransactionNotFound)?;
+
+        if !Self::is_open(&tx) {
+            return Err(Error::TransactionAlreadyExecuted);
+        }
+        if tx.veto_quorum == 0
+            || tx.executable_at == 0
+            || env.ledger().timestamp() >= tx.executable_at
+        {
+            return Err(Error::NotInVetoWindow);
+        }
+
+        let veto_key = DataKey::Veto(tx_id, vetoer.clone());
+        if env.storage().persistent().has(&veto_key) {
+            return Err(Error::AlreadyConfirmed);
+        }
+
+        let weight = Self::weight_of(&env, &vetoer);
+        env.storage().persistent().set(&veto_key, &weight);
+        tx.veto_count += weight;
+
+        events::veto_cast(&env, tx_id, &vetoer, tx.veto_count);
+
+        if tx.veto_count >= tx.veto_quorum {
+            tx.status = TransactionStatus::Vetoed;
+            events::transaction_vetoed(&env, tx_id);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &tx);
+
+        Ok(())
+    }
+
+    /// Register the ed25519 public key `owner` signs [`SignedTransaction`]s
+    /// with. Replaces any key registered before.
+    pub fn set_signing_key(env: Env, owner: Address, key: BytesN<32>) -> Result<(), Error> {
+        owner.require_auth();
+        Self::check_owner(&env, &owner)?;
+
+        let mut profile: OwnerProfile = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerProfile(owner.clone()))
+            .unwrap();
+        profile.signing_key = Some(key.clone());
+        profile.last_activity = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::OwnerProfile(owner.clone()), &profile);
+
+        events::signing_key_set(&env, &owner, key);
+
+        Ok(())
+    }
+
+    /// Execute a transaction the owners approved off-chain, in one call.
+    ///
+    /// Each signature is an owner's ed25519 signature, made with the key in
+    /// their profile, over [`MultisigWallet::get_signature_payload`] for
+    /// `tx_payload`. An invalid signature traps. The signers' combined weight
+    /// must reach the threshold, `tx_payload.nonce` must be the current nonce,
+    /// and `tx_payload.expires_at` must not have passed.
+    ///
+    /// The transaction is recorded with the signers' confirmations and
+    /// executed as [`MultisigWallet::execute_transaction`] would run it, so a
+    /// failing call leaves it `Failed` and open until `expires_at`. A type
+    /// with a delay is queued instead, and executed once the delay ends.
+    /// Anyone may submit the signatures.
+    pub fn execute_with_signatures(
+        env: Env,
+        tx_payload: SignedTransaction,
+        signatures: Vec<OwnerSignature>,
+    ) -> Result<u64, Error> {
+        Self::check_not_frozen(&env)?;
+        if env.ledger().timestamp() > tx_payload.expires_at {
+            return Err(Error::TransactionExpired);
+        }
+        let nonce = Self::get_nonce(env.clone());
+        if tx_payload.nonce != nonce {
+            return Err(Error::InvalidNonce);
+        }
+
+        let message: Bytes = Self::get_signature_payload(env.clone(), tx_payload.clone()).into();
+        let mut weight = 0;
+        for (i, signed) in signatures.iter().enumerate() {
+            Self::check_can_vote(&env, &signed.owner)?;
+            if signatures.iter().position(|s| s.owner == signed.owner) != Some(i) {
+                return Err(Error::AlreadyConfirmed);
+            }
+            let profile: OwnerProfile = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerProfile(signed.owner.clone()))
+                .unwrap();
+            let key = profile.signing_key.ok_or(Error::SigningKeyNotSet)?;
+            env.crypto()
+                .ed25519_verify(&key, &message, &signed.signature);
+            weight += profile.voting_weight;
+        }
+        let policy = Self::type_policy(&env, &tx_payload.tx_type);
+        if weight < policy.threshold {
+            return Err(Error::InsufficientSignatures);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SignatureNonce, &(nonce + 1));
+
+        let tx = Transaction {
+            id: 0,
+            tx_type: tx_payload.tx_type,
+            target: tx_payload.target,
+            function_name: tx_payload.function_name,
+            parameters: tx_payload.parameters,
+            initiator: signatures.get(0).unwrap().owner,
+            created_at: env.ledger().timestamp(),
+            deadline: tx_payload.expires_at,
+            required_confirmations: policy.threshold,
+            confirmations_count: weight,
+            status: TransactionStatus::Pending,
+            execution_timestamp: 0,
+            value: tx_payload.value,
+            results: Vec::new(&env),
+            delay: policy.delay,
+            veto_quorum: policy.veto_quorum,
+            veto_count: 0,
+            executable_at: env.ledger().timestamp() + policy.delay,
+        };
+        let tx_id = Self::store_transaction(&env, tx);
+
+        for signed in signatures.iter() {
+            let mut profile: OwnerProfile = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerProfile(signed.owner.clone()))
+                .unwrap();
+            env.storage().persistent().set(
+                &DataKey::Confirmation(tx_id, signed.owner.clone()),
+                &profile.voting_weight,
+            );
+            profile.total_confirmations += 1;
+            profile.last_activity = env.ledger().timestamp();
+            env.storage()
+                .persistent()
+                .set(&DataKey::OwnerProfile(signed.owner.clone()), &profile);
+        }
+
+        if policy.delay > 0 {
+            events::transaction_queued(&env, tx_id, env.ledger().timestamp() + policy.delay);
+        } else {
+            Self::execute_transaction(env, tx_id)?;
+        }
+
+        Ok(tx_id)
+    }
+
     /// Ownership proposals
+    ///
+    /// Only Primary owners may raise a proposal; see [`OwnerType`].
     pub fn propose_add_owner(
         env: Env,
         proposer: Address,
         new_owner: Address,
     ) -> Result<u64, Error> {
         proposer.require_auth();
-        Self::check_owner(&env, &proposer)?;
+        Self::check_primary(&env, &proposer)?;
 
         let owners: Vec<Address> = env.storage().instance().get(&DataKey::Owners).unwrap();
         if owners.contains(&new_owner) {
             return Err(Error::OwnerAlreadyExists);
         }
 
-        let proposal_id = Self::create_proposal(
-            &env,
-            proposer,
-            ProposalType::AddOwner,
-            Some(new_owner),
-            None,
-        )?;
-        Ok(proposal_id)
+        let proposal =
+            Self::draft_proposal(&env, &proposer, ProposalType::AddOwner, Some(new_owner));
+        Self::create_proposal(&env, proposal)
     }
 
     pub fn propose_remove_owner(
@@ -355,26 +761,17 @@ impl MultisigWallet {
         owner_to_remove: Address,
     ) -> Result<u64, Error> {
         proposer.require_auth();
-        Self::check_owner(&env, &proposer)?;
+        Self::check_primary(&env, &proposer)?;
 
-        let owners: Vec<Address> = env.storage().instance().get(&DataKey::Owners).unwrap();
-        if !owners.contains(&owner_to_remove) {
-            return Err(Error::OwnerNotFound);
-        }
+        Self::check_removal(&env, &owner_to_remove)?;
 
-        let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap();
-        if owners.len() <= 2 || owners.len() <= threshold {
-            return Err(Error::InsufficientOwners);
-        }
-
-        let proposal_id = Self::create_proposal(
+        let proposal = Self::draft_proposal(
             &env,
-            proposer,
+            &proposer,
             ProposalType::RemoveOwner,
             Some(owner_to_remove),
-            None,
-        )?;
-        Ok(proposal_id)
+        );
+        Self::create_proposal(&env, proposal)
     }
 
     pub fn propose_change_threshold(
@@ -383,26 +780,125 @@ impl MultisigWallet {
         new_threshold: u32,
     ) -> Result<u64, Error> {
         proposer.require_auth();
-        Self::check_owner(&env, &proposer)?;
+        Self::check_primary(&env, &proposer)?;
 
-        let owners: Vec<Address> = env.storage().instance().get(&DataKey::Owners).unwrap();
-        if new_threshold == 0 || new_threshold > owners.len() {
-            return Err(Error::InvalidThreshold);
+        Self::check_wallet_threshold(&env, new_threshold)?;
+
+        let proposal = OwnershipProposal {
+            new_threshold: Some(new_threshold),
+            ..Self::draft_proposal(&env, &proposer, ProposalType::ChangeThreshold, None)
+        };
+        Self::create_proposal(&env, proposal)
+    }
+
+    /// Propose setting `owner`'s voting weight to `new_weight`.
+    ///
+    /// Rejected if the weight is outside `1..=MAX_VOTING_WEIGHT`, or if
+    /// lowering it would leave the total weight below the threshold.
+    pub fn propose_change_weight(
+        env: Env,
+        proposer: Address,
+        owner: Address,
+        new_weight: u32,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+        Self::check_primary(&env, &proposer)?;
+
+        Self::check_weight_change(&env, &owner, new_weight)?;
+
+        let proposal = OwnershipProposal {
+            new_weight: Some(new_weight),
+            ..Self::draft_proposal(&env, &proposer, ProposalType::ChangeWeight, Some(owner))
+        };
+        Self::create_proposal(&env, proposal)
+    }
+
+    /// Propose giving `owner` a new role.
+    ///
+    /// Rejected if it would demote the last Primary owner, or if making the
+    /// owner an Emergency owner would take away weight the threshold needs.
+    pub fn propose_change_owner_type(
+        env: Env,
+        proposer: Address,
+        owner: Address,
+        new_type: OwnerType,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+        Self::check_primary(&env, &proposer)?;
+
+        Self::check_owner_type_change(&env, &owner, &new_type)?;
+
+        let proposal = Self::draft_proposal(
+            &env,
+            &proposer,
+            ProposalType::ChangeOwnerType(new_type),
+            Some(owner),
+        );
+        Self::create_proposal(&env, proposal)
+    }
+
+    /// Propose lifting a freeze. Proposals are still accepted while the
+    /// wallet is frozen, so this is how a frozen wallet recovers.
+    pub fn propose_unfreeze(env: Env, proposer: Address) -> Result<u64, Error> {
+        proposer.require_auth();
+        Self::check_primary(&env, &proposer)?;
+
+        if !Self::is_frozen(env.clone()) {
+            return Err(Error::InvalidProposal);
         }
 
-        let proposal_id = Self::create_proposal(
+        let proposal = Self::draft_proposal(&env, &proposer, ProposalType::Unfreeze, None);
+        Self::create_proposal(&env, proposal)
+    }
+
+    /// Propose a daily limit for `token`, with the smaller `threshold` that
+    /// transfers within it need. A `limit` of `0` removes the limit.
+    pub fn propose_change_daily_limit(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        limit: u128,
+        threshold: u32,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+        Self::check_primary(&env, &proposer)?;
+
+        Self::check_limit_threshold(&env, limit, threshold)?;
+
+        let proposal = OwnershipProposal {
+            new_daily_limit: Some(limit),
+            new_threshold: Some(threshold),
+            ..Self::draft_proposal(&env, &proposer, ProposalType::ChangeDailyLimit, Some(token))
+        };
+        Self::create_proposal(&env, proposal)
+    }
+
+    /// Propose the policy for `tx_type`: the weight it needs, the delay
+    /// before it runs and the veto quorum that can block it during the
+    /// delay. A policy with a zero `threshold` removes it.
+    pub fn propose_change_type_policy(
+        env: Env,
+        proposer: Address,
+        tx_type: TransactionType,
+        policy: TypePolicy,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+        Self::check_primary(&env, &proposer)?;
+
+        Self::check_type_policy(&env, &tx_type, &policy)?;
+
+        let proposal = Self::draft_proposal(
             &env,
-            proposer,
-            ProposalType::ChangeThreshold,
+            &proposer,
+            ProposalType::ChangeTypePolicy(tx_type, policy),
             None,
-            Some(new_threshold),
-        )?;
-        Ok(proposal_id)
+        );
+        Self::create_proposal(&env, proposal)
     }
 
     pub fn confirm_proposal(env: Env, confirmer: Address, proposal_id: u64) -> Result<(), Error> {
         confirmer.require_auth();
-        Self::check_owner(&env, &confirmer)?;
+        Self::check_can_vote(&env, &confirmer)?;
 
         let mut proposal: OwnershipProposal = env
             .storage()
@@ -419,8 +915,10 @@ impl MultisigWallet {
             return Err(Error::AlreadyConfirmed);
         }
 
-        env.storage().persistent().set(&confirm_key, &true);
-        proposal.confirmations_received += 1;
+        let weight = Self::weight_of(&env, &confirmer);
+        env.storage().persistent().set(&confirm_key, &weight);
+        proposal.confirmations_received =
+            Self::confirmed_weight(&env, |o| DataKey::ProposalConfirmation(proposal_id, o));
         env.storage()
             .persistent()
             .set(&DataKey::Proposal(proposal_id), &proposal);
@@ -451,14 +949,23 @@ impl MultisigWallet {
             return Err(Error::InvalidProposal);
         }
 
+        // Owners removed or made Emergency since they confirmed no longer
+        // count.
+        proposal.confirmations_received =
+            Self::confirmed_weight(&env, |o| DataKey::ProposalConfirmation(proposal_id, o));
         let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap();
         if proposal.confirmations_received < threshold {
             return Err(Error::Unauthorized);
         }
 
+        // Re-validate against the wallet as it is now: another proposal may
+        // have removed an owner or changed a weight since this one was raised.
         match proposal.proposal_type {
             ProposalType::AddOwner => {
                 let new_owner = proposal.target_address.clone().unwrap();
+                if Self::get_owners(env.clone()).contains(&new_owner) {
+                    return Err(Error::OwnerAlreadyExists);
+                }
                 let mut owners: Vec<Address> =
                     env.storage().instance().get(&DataKey::Owners).unwrap();
                 owners.push_back(new_owner.clone());
@@ -473,6 +980,7 @@ impl MultisigWallet {
                     is_active: true,
                     total_confirmations: 0,
                     last_activity: env.ledger().timestamp(),
+                    signing_key: None,
                 };
                 env.storage()
                     .persistent()
@@ -482,6 +990,7 @@ impl MultisigWallet {
             }
             ProposalType::RemoveOwner => {
                 let owner_to_remove = proposal.target_address.clone().unwrap();
+                Self::check_removal(&env, &owner_to_remove)?;
                 let mut owners: Vec<Address> =
                     env.storage().instance().get(&DataKey::Owners).unwrap();
                 if let Some(i) = owners.iter().position(|x| x == owner_to_remove) {
@@ -496,6 +1005,7 @@ impl MultisigWallet {
             }
             ProposalType::ChangeThreshold => {
                 let new_threshold = proposal.new_threshold.unwrap();
+                Self::check_wallet_threshold(&env, new_threshold)?;
                 let old_threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap();
                 env.storage()
                     .instance()
@@ -503,6 +1013,66 @@ impl MultisigWallet {
 
                 events::threshold_changed(&env, old_threshold, new_threshold);
             }
+            ProposalType::ChangeWeight => {
+                let owner = proposal.target_address.clone().unwrap();
+                let new_weight = proposal.new_weight.unwrap();
+                Self::check_weight_change(&env, &owner, new_weight)?;
+
+                let key = DataKey::OwnerProfile(owner.clone());
+                let mut profile: OwnerProfile = env.storage().persistent().get(&key).unwrap();
+                let old_weight = profile.voting_weight;
+                profile.voting_weight = new_weight;
+                env.storage().persistent().set(&key, &profile);
+
+                events::weight_changed(&env, &owner, old_weight, new_weight);
+            }
+            ProposalType::ChangeOwnerType(ref new_type) => {
+                let owner = proposal.target_address.clone().unwrap();
+                let new_type = new_type.clone();
+                Self::check_owner_type_change(&env, &owner, &new_type)?;
+
+                let key = DataKey::OwnerProfile(owner.clone());
+                let mut profile: OwnerProfile = env.storage().persistent().get(&key).unwrap();
+                let old_type = profile.owner_type.clone();
+                profile.owner_type = new_type.clone();
+                env.storage().persistent().set(&key, &profile);
+
+                events::owner_type_changed(&env, &owner, old_type, new_type);
+            }
+            ProposalType::Unfreeze => {
+                env.storage().instance().set(&DataKey::Frozen, &false);
+
+                events::wallet_unfrozen(&env, &proposal.proposer);
+            }
+            ProposalType::ChangeDailyLimit => {
+                let token = proposal.target_address.clone().unwrap();
+                let limit = proposal.new_daily_limit.unwrap();
+                let threshold = proposal.new_threshold.unwrap();
+                Self::check_limit_threshold(&env, limit, threshold)?;
+
+                let key = DataKey::TokenLimit(token.clone());
+                if limit == 0 {
+                    env.storage().persistent().remove(&key);
+                } else {
+                    env.storage()
+                        .persistent()
+                        .set(&key, &TokenLimit { limit, threshold });
+                }
+
+                events::daily_limit_changed(&env, &proposal.proposer, &token, limit);
+            }
+            ProposalType::ChangeTypePolicy(ref tx_type, ref policy) => {
+                Self::check_type_policy(&env, tx_type, policy)?;
+
+                let key = DataKey::TypePolicy(tx_type.clone());
+                if policy.threshold == 0 {
+                    env.storage().persistent().remove(&key);
+                } else {
+                    env.storage().persistent().set(&key, policy);
+                }
+
+                events::type_policy_changed(&env, &proposal.proposer, tx_type.clone(), policy);
+            }
         }
 
         proposal.status = ProposalStatus::Executed;
@@ -514,52 +1084,20 @@ impl MultisigWallet {
     }
 
     /// Emergency functions
+    ///
+    /// Any owner, whatever their role, may freeze the wallet on their own:
+    /// freezing only ever stops things. Lifting the freeze takes a
+    /// [`MultisigWallet::propose_unfreeze`] proposal.
     pub fn emergency_freeze(env: Env, caller: Address) -> Result<(), Error> {
         caller.require_auth();
         Self::check_owner(&env, &caller)?;
 
-        // Majority required for emergency freeze
-        // For simplicity, let's say it just needs the threshold or a separate majority
-        // The requirements say "requires majority"
-
-        // This probably needs a proposal too, or just a direct call if we have enough auth?
-        // Actually, require_auth() only checks the caller.
-        // A true multisig freeze should probably be a transaction type.
-        // But the user listed it as a core function.
-
-        // If we want it to be "requires majority" without a full proposal, we might need a way to track freeze votes.
-        // Let's use a simpler approach: any owner can trigger it, but maybe it should be a proposal?
-        // User says: "Majority owners: Can execute emergency freeze"
-
-        // I'll stick to a transaction type for this or a proposal.
-        // For now, let's just set it.
         env.storage().instance().set(&DataKey::Frozen, &true);
 
         events::wallet_frozen(&env, &caller);
         Ok(())
     }
 
-    pub fn emergency_unfreeze(env: Env, caller: Address) -> Result<(), Error> {
-        caller.require_auth();
-        Self::check_owner(&env, &caller)?;
-
-        env.storage().instance().set(&DataKey::Frozen, &false);
-
-        events::wallet_unfrozen(&env, &caller);
-        Ok(())
-    }
-
-    pub fn set_daily_limit(env: Env, caller: Address, limit: u128) -> Result<(), Error> {
-        caller.require_auth();
-        Self::check_owner(&env, &caller)?;
-
-        // Should probably be a proposal too.
-        env.storage().instance().set(&DataKey::DailyLimit, &limit);
-
-        events::daily_limit_changed(&env, &caller, limit);
-        Ok(())
-    }
-
     /// Getters
     pub fn get_owners(env: Env) -> Vec<Address> {
         env.storage()
@@ -575,6 +1113,76 @@ impl MultisigWallet {
             .unwrap_or(0)
     }
 
+    /// `token`'s daily limit and reduced threshold, if it has one.
+    pub fn get_daily_limit(env: Env, token: Address) -> Option<TokenLimit> {
+        Self::token_limit(&env, &token)
+    }
+
+    /// How much of `token` has left the wallet over the last 24 hours.
+    pub fn get_spent_in_window(env: Env, token: Address) -> u128 {
+        Self::spent_in_window(&env, &token)
+    }
+
+    /// The policy set for `tx_type`, if any. Without one, the type needs the
+    /// wallet's threshold and runs as soon as it is met.
+    pub fn get_type_policy(env: Env, tx_type: TransactionType) -> Option<TypePolicy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TypePolicy(tx_type))
+    }
+
+    /// The nonce the next [`SignedTransaction`] must carry.
+    pub fn get_nonce(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SignatureNonce)
+            .unwrap_or(0)
+    }
+
+    /// The hash owners sign for [`MultisigWallet::execute_with_signatures`]:
+    /// SHA-256 over [`SIGNATURE_DOMAIN`], the network id, this wallet's
+    /// address and the XDR of `tx_payload`.
+    pub fn get_signature_payload(env: Env, tx_payload: SignedTransaction) -> BytesN<32> {
+        let mut message = Bytes::from_slice(&env, SIGNATURE_DOMAIN);
+        message.extend_from_array(&env.ledger().network_id().to_array());
+        message.append(&env.current_contract_address().to_xdr(&env));
+        message.append(&tx_payload.to_xdr(&env));
+        env.crypto().sha256(&message).to_bytes()
+    }
+
+    /// Why a `Failed` transaction's last execution attempt failed.
+    pub fn get_execution_error(env: Env, tx_id: u64) -> Option<ExecutionError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExecutionError(tx_id))
+    }
+
+    /// The calls a transaction makes, in order: the batch for a
+    /// [`MultisigWallet::submit_batch`] transaction, otherwise its one call.
+    /// Empty for an unknown id.
+    pub fn get_calls(env: Env, tx_id: u64) -> Vec<Call> {
+        if let Some(calls) = env.storage().persistent().get(&DataKey::BatchCalls(tx_id)) {
+            return calls;
+        }
+        let mut calls = Vec::new(&env);
+        if let Some(tx) = Self::get_transaction(env.clone(), tx_id) {
+            calls.push_back(Call {
+                target: tx.target,
+                function_name: tx.function_name,
+                parameters: tx.parameters,
+            });
+        }
+        calls
+    }
+
+    /// The token movement behind a transaction, if it was raised with
+    /// [`MultisigWallet::submit_token_transfer`].
+    pub fn get_token_transfer(env: Env, tx_id: u64) -> Option<TokenTransfer> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenTransfer(tx_id))
+    }
+
     pub fn get_transaction(env: Env, tx_id: u64) -> Option<Transaction> {
         env.storage().persistent().get(&DataKey::Transaction(tx_id))
     }
@@ -586,6 +1194,11 @@ impl MultisigWallet {
             .unwrap_or(false)
     }
 
+    /// The combined voting weight a transaction or proposal needs to execute.
+    ///
+    /// This is a weight, not a head count: with weights above 1, fewer owners
+    /// than this number may be enough. Compare it against
+    /// [`MultisigWallet::get_total_weight`], not `get_owners().len()`.
     pub fn get_required_confirmations(env: Env) -> u32 {
         env.storage()
             .instance()
@@ -593,6 +1206,12 @@ impl MultisigWallet {
             .unwrap_or(0)
     }
 
+    /// The combined voting weight of every active owner — the most any
+    /// transaction or proposal can collect.
+    pub fn get_total_weight(env: Env) -> u32 {
+        Self::total_weight(&env)
+    }
+
     pub fn get_owner_profile(env: Env, owner: Address) -> Option<OwnerProfile> {
         env.storage()
             .persistent()
@@ -618,6 +1237,197 @@ impl MultisigWallet {
         Ok(())
     }
 
+    /// A Primary owner: may raise proposals.
+    fn check_primary(env: &Env, address: &Address) -> Result<(), Error> {
+        Self::check_owner(env, address)?;
+        match Self::owner_type_of(env, address) {
+            Some(OwnerType::Primary) => Ok(()),
+            _ => Err(Error::RoleNotPermitted),
+        }
+    }
+
+    /// A Primary or Secondary owner: may submit and confirm transactions and
+    /// confirm proposals.
+    fn check_can_vote(env: &Env, address: &Address) -> Result<(), Error> {
+        Self::check_owner(env, address)?;
+        match Self::owner_type_of(env, address) {
+            Some(OwnerType::Emergency) | None => Err(Error::RoleNotPermitted),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn owner_type_of(env: &Env, owner: &Address) -> Option<OwnerType> {
+        env.storage()
+            .persistent()
+            .get::<_, OwnerProfile>(&DataKey::OwnerProfile(owner.clone()))
+            .map(|p| p.owner_type)
+    }
+
+    /// Whether a profile's weight counts toward the total: it must be active
+    /// and able to confirm.
+    fn counts_toward_threshold(profile: &OwnerProfile) -> bool {
+        profile.is_active && profile.owner_type != OwnerType::Emergency
+    }
+
+    /// How many owners hold the Primary role, optionally ignoring one.
+    fn primary_count_except(env: &Env, skip: &Address) -> u32 {
+        let mut count = 0;
+        for owner in Self::get_owners(env.clone()).iter() {
+            if &owner != skip && Self::owner_type_of(env, &owner) == Some(OwnerType::Primary) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// An owner's current voting weight, or 0 if they have no profile.
+    fn weight_of(env: &Env, owner: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, OwnerProfile>(&DataKey::OwnerProfile(owner.clone()))
+            .map(|p| p.voting_weight)
+            .unwrap_or(0)
+    }
+
+    /// Whether `owner` has a profile whose weight counts toward the total.
+    fn counts(env: &Env, owner: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, OwnerProfile>(&DataKey::OwnerProfile(owner.clone()))
+            .is_some_and(|p| Self::counts_toward_threshold(&p))
+    }
+
+    /// The weight of the confirmations stored under `key` for each owner
+    /// whose weight still counts, at the weight each confirmed with. An owner
+    /// removed or made Emergency since confirming drops out.
+    fn confirmed_weight(env: &Env, key: impl Fn(Address) -> DataKey) -> u32 {
+        let mut weight = 0;
+        for owner in Self::get_owners(env.clone()).iter() {
+            if Self::counts(env, &owner) {
+                weight += env
+                    .storage()
+                    .persistent()
+                    .get::<_, u32>(&key(owner))
+                    .unwrap_or(0);
+            }
+        }
+        weight
+    }
+
+    /// The combined weight of every active, non-Emergency owner.
+    fn total_weight(env: &Env) -> u32 {
+        Self::total_weight_except(env, None)
+    }
+
+    /// The combined weight of every active, non-Emergency owner other than
+    /// `skip`.
+    fn total_weight_except(env: &Env, skip: Option<&Address>) -> u32 {
+        let mut total = 0;
+        for owner in Self::get_owners(env.clone()).iter() {
+            if Some(&owner) == skip {
+                continue;
+            }
+            let profile: Option<OwnerProfile> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerProfile(owner));
+            if let Some(profile) = profile.filter(Self::counts_toward_threshold) {
+                total += profile.voting_weight;
+            }
+        }
+        total
+    }
+
+    fn current_threshold(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .unwrap_or(0)
+    }
+
+    fn check_threshold(env: &Env, threshold: u32) -> Result<(), Error> {
+        if threshold == 0 || threshold > Self::total_weight(env) {
+            return Err(Error::InvalidThreshold);
+        }
+        Ok(())
+    }
+
+    /// A new wallet threshold must be reachable and stay below the Emergency
+    /// policy's, if there is one.
+    fn check_wallet_threshold(env: &Env, threshold: u32) -> Result<(), Error> {
+        Self::check_threshold(env, threshold)?;
+        if Self::get_type_policy(env.clone(), TransactionType::Emergency)
+            .is_some_and(|p| threshold >= p.threshold)
+        {
+            return Err(Error::InvalidThreshold);
+        }
+        Ok(())
+    }
+
+    /// Removing `owner` must keep two owners and a Primary owner, and leave
+    /// enough weight behind to reach every threshold.
+    fn check_removal(env: &Env, owner: &Address) -> Result<(), Error> {
+        let owners = Self::get_owners(env.clone());
+        if !owners.contains(owner) {
+            return Err(Error::OwnerNotFound);
+        }
+
+        let remaining = Self::total_weight_except(env, Some(owner));
+        if owners.len() <= 2
+            || remaining < Self::highest_threshold(env)
+            || Self::primary_count_except(env, owner) == 0
+        {
+            return Err(Error::InsufficientOwners);
+        }
+        Ok(())
+    }
+
+    /// Giving `owner` the role `new_type` must keep a Primary owner, and
+    /// leave enough weight to reach every threshold.
+    fn check_owner_type_change(
+        env: &Env,
+        owner: &Address,
+        new_type: &OwnerType,
+    ) -> Result<(), Error> {
+        let current = Self::owner_type_of(env, owner).ok_or(Error::OwnerNotFound)?;
+        if &current == new_type {
+            return Err(Error::InvalidArguments);
+        }
+
+        if *new_type != OwnerType::Primary && Self::primary_count_except(env, owner) == 0 {
+            return Err(Error::InsufficientOwners);
+        }
+
+        let mut total = Self::total_weight_except(env, Some(owner));
+        if *new_type != OwnerType::Emergency {
+            total += Self::weight_of(env, owner);
+        }
+        if total < Self::highest_threshold(env) {
+            return Err(Error::InsufficientOwners);
+        }
+        Ok(())
+    }
+
+    /// Setting `owner`'s weight to `new_weight` must keep it in range and
+    /// leave every threshold reachable.
+    fn check_weight_change(env: &Env, owner: &Address, new_weight: u32) -> Result<(), Error> {
+        if new_weight == 0 || new_weight > MAX_VOTING_WEIGHT {
+            return Err(Error::InvalidArguments);
+        }
+        if !Self::get_owners(env.clone()).contains(owner) {
+            return Err(Error::OwnerNotFound);
+        }
+
+        let mut total = Self::total_weight_except(env, Some(owner));
+        if Self::counts(env, owner) {
+            total += new_weight;
+        }
+        if total < Self::highest_threshold(env) {
+            return Err(Error::InsufficientOwners);
+        }
+        Ok(())
+    }
+
     fn check_not_frozen(env: &Env) -> Result<(), Error> {
         let frozen: bool = env
             .storage()
@@ -630,41 +1440,245 @@ impl MultisigWallet {
         Ok(())
     }
 
-    fn check_daily_limit(env: &Env, amount: u128) -> Result<(), Error> {
-        let limit: u128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::DailyLimit)
-            .unwrap_or(0);
-        if limit == 0 {
-            return Ok(());
+    /// A limit's reduced threshold must be at least 1 and no more than the
+    /// wallet's own threshold. Removing a limit needs no threshold.
+    fn check_limit_threshold(env: &Env, limit: u128, threshold: u32) -> Result<(), Error> {
+        if limit > 0 && (threshold == 0 || threshold > Self::current_threshold(env)) {
+            return Err(Error::InvalidThreshold);
         }
+        Ok(())
+    }
 
-        let day = env.ledger().timestamp() / 86400;
-        let spent: u128 = env
+    fn token_limit(env: &Env, token: &Address) -> Option<TokenLimit> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenLimit(token.clone()))
+    }
+
+    /// `token`'s spends that are still inside the rolling window.
+    fn spends_in_window(env: &Env, token: &Address) -> Vec<Spend> {
+        let now = env.ledger().timestamp();
+        let all: Vec<Spend> = env
             .storage()
             .persistent()
-            .get(&DataKey::DailySpent(day))
-            .unwrap_or(0);
+            .get(&DataKey::TokenSpends(token.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut live = Vec::new(env);
+        for spend in all.iter() {
+            if spend.at + LIMIT_WINDOW_SECS > now {
+                live.push_back(spend);
+            }
+        }
+        live
+    }
+
+    fn spent_in_window(env: &Env, token: &Address) -> u128 {
+        Self::spends_in_window(env, token)
+            .iter()
+            .map(|s| s.amount)
+            .sum()
+    }
+
+    /// Whether `amount` more of `token` fits the token's daily limit now.
+    /// A token with no limit has no reduced threshold, so nothing fits.
+    fn fits_daily_limit(env: &Env, token: &Address, amount: i128) -> bool {
+        Self::token_limit(env, token)
+            .is_some_and(|l| Self::spent_in_window(env, token) + amount as u128 <= l.limit)
+    }
+
+    /// The policy `tx_type` is held to, falling back to the wallet's
+    /// threshold with no delay and no veto.
+    fn type_policy(env: &Env, tx_type: &TransactionType) -> TypePolicy {
+        Self::get_type_policy(env.clone(), tx_type.clone()).unwrap_or(TypePolicy {
+            threshold: Self::current_threshold(env),
+            delay: 0,
+            veto_quorum: 0,
+        })
+    }
+
+    /// The policies set for any transaction type.
+    fn type_policies(env: &Env) -> impl Iterator<Item = TypePolicy> + '_ {
+        [
+            TransactionType::Transfer,
+            TransactionType::Update,
+            TransactionType::Admin,
+            TransactionType::Emergency,
+            TransactionType::Routine,
+        ]
+        .into_iter()
+        .filter_map(|t| Self::get_type_policy(env.clone(), t))
+    }
+
+    /// The highest weight any transaction type needs. Owner changes must
+    /// leave it reachable, not only the wallet's own threshold.
+    fn highest_threshold(env: &Env) -> u32 {
+        Self::type_policies(env)
+            .map(|p| p.threshold)
+            .fold(Self::current_threshold(env), u32::max)
+    }
 
-        if spent + amount > limit {
-            events::daily_limit_reached(env, limit, spent + amount);
-            return Err(Error::DailyLimitExceeded);
+    /// A policy needs a reachable threshold, a delay no longer than
+    /// [`MAX_EXECUTION_DELAY`], and a delay to cast vetoes in if it has a
+    /// veto quorum. An Emergency policy must ask for more than the wallet's
+    /// threshold. A zero threshold removes the policy and needs nothing else.
+    fn check_type_policy(
+        env: &Env,
+        tx_type: &TransactionType,
+        policy: &TypePolicy,
+    ) -> Result<(), Error> {
+        if policy.threshold == 0 {
+            return Ok(());
         }
+        Self::check_threshold(env, policy.threshold)?;
+        if *tx_type == TransactionType::Emergency
+            && policy.threshold <= Self::current_threshold(env)
+        {
+            return Err(Error::InvalidThreshold);
+        }
+        if policy.delay > MAX_EXECUTION_DELAY
+            || policy.veto_quorum > Self::total_weight(env)
+            || (policy.veto_quorum > 0 && policy.delay == 0)
+        {
+            return Err(Error::InvalidArguments);
+        }
+        Ok(())
+    }
+
+    /// Whether a transaction has the weight to run: its full threshold, or
+    /// a token transfer's reduced one. The daily limit is checked on
+    /// execution.
+    fn threshold_met(env: &Env, tx: &Transaction) -> bool {
+        tx.confirmations_count >= tx.required_confirmations
+            || Self::get_token_transfer(env.clone(), tx.id)
+                .is_some_and(|t| tx.confirmations_count >= t.reduced_confirmations)
+    }
 
+    /// Pending and Failed transactions are open: they can still be confirmed,
+    /// revoked, cancelled and executed.
+    fn is_open(tx: &Transaction) -> bool {
+        matches!(
+            tx.status,
+            TransactionStatus::Pending | TransactionStatus::Failed
+        )
+    }
+
+    /// Makes one call, reporting a failure instead of trapping. The host
+    /// rolls back whatever the failed call changed. A successful `transfer`
+    /// of a limited token counts toward its daily limit.
+    fn try_call(env: &Env, call: &Call) -> Result<Vec<Val>, ExecutionError> {
+        let result = env.try_invoke_contract::<Val, InvokeError>(
+            &call.target,
+            &call.function_name,
+            call.parameters.clone(),
+        );
+        match result {
+            Ok(Ok(value)) => {
+                Self::record_transfer_call(
+                    env,
+                    &call.target,
+                    &call.function_name,
+                    &call.parameters,
+                );
+                Ok(Vec::from_array(env, [value]))
+            }
+            Ok(Err(_)) => Err(ExecutionError::Aborted),
+            Err(Ok(error)) | Err(Err(error)) => Err(Self::execution_error(error)),
+        }
+    }
+
+    /// Moves the tokens, counting them toward the window only on success.
+    fn try_token_transfer(env: &Env, t: &TokenTransfer) -> Result<Vec<Val>, ExecutionError> {
+        let result = token::Client::new(env, &t.token).try_transfer(
+            &env.current_contract_address(),
+            &t.to,
+            &t.amount,
+        );
+        match result {
+            Ok(_) => {
+                // Every transfer counts toward the window, whichever
+                // threshold approved it.
+                Self::record_spend(env, &t.token, t.amount as u128);
+                Ok(Vec::from_array(env, [().into_val(env)]))
+            }
+            Err(Ok(error)) => Err(Self::execution_error(error.into())),
+            Err(Err(error)) => Err(Self::execution_error(error)),
+        }
+    }
+
+    fn execution_error(error: InvokeError) -> ExecutionError {
+        match error {
+            InvokeError::Contract(code) => ExecutionError::Contract(code),
+            InvokeError::Abort => ExecutionError::Aborted,
+        }
+    }
+
+    /// Counts a `transfer` call on a token with a daily limit toward the
+    /// window. Calls to other functions or unlimited tokens are ignored.
+    fn record_transfer_call(env: &Env, contract: &Address, fn_name: &Symbol, args: &Vec<Val>) {
+        if *fn_name != Symbol::new(env, "transfer") || Self::token_limit(env, contract).is_none() {
+            return;
+        }
+        if let Some(amount) = args.get(2) {
+            let amount = i128::try_from_val(env, &amount).unwrap_or(0);
+            if amount > 0 {
+                Self::record_spend(env, contract, amount as u128);
+            }
+        }
+    }
+
+    /// Records a spend, dropping the ones that have left the window.
+    fn record_spend(env: &Env, token: &Address, amount: u128) {
+        let mut spends = Self::spends_in_window(env, token);
+        spends.push_back(Spend {
+            at: env.ledger().timestamp(),
+            amount,
+        });
         env.storage()
             .persistent()
-            .set(&DataKey::DailySpent(day), &(spent + amount));
-        Ok(())
+            .set(&DataKey::TokenSpends(token.clone()), &spends);
     }
 
-    fn create_proposal(
+    /// A pending proposal with no parameters set; callers fill in the field
+    /// their proposal type needs.
+    fn draft_proposal(
         env: &Env,
-        proposer: Address,
+        proposer: &Address,
         p_type: ProposalType,
         target: Option<Address>,
-        threshold: Option<u32>,
-    ) -> Result<u64, Error> {
+    ) -> OwnershipProposal {
+        OwnershipProposal {
+            id: 0,
+            proposal_type: p_type,
+            target_address: target,
+            new_threshold: None,
+            new_weight: None,
+            new_daily_limit: None,
+            proposer: proposer.clone(),
+            timestamp: env.ledger().timestamp(),
+            confirmations_received: 0,
+            status: ProposalStatus::Pending,
+        }
+    }
+
+    /// Gives `tx` the next id, stores it and announces it.
+    fn store_transaction(env: &Env, mut tx: Transaction) -> u64 {
+        let tx_id: u64 = env.storage().instance().get(&DataKey::NextTxId).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTxId, &(tx_id + 1));
+
+        tx.id = tx_id;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &tx);
+
+        events::transaction_submitted(env, tx_id, &tx.initiator, tx.tx_type);
+
+        tx_id
+    }
+
+    fn create_proposal(env: &Env, mut proposal: OwnershipProposal) -> Result<u64, Error> {
         let id: u64 = env
             .storage()
             .instance()
@@ -674,26 +1688,65 @@ impl MultisigWallet {
             .instance()
             .set(&DataKey::NextProposalId, &(id + 1));
 
-        let proposal = OwnershipProposal {
-            id,
-            proposal_type: p_type,
-            target_address: target,
-            new_threshold: threshold,
-            proposer: proposer.clone(),
-            timestamp: env.ledger().timestamp(),
-            confirmations_received: 0,
-            status: ProposalStatus::Pending,
-        };
-
+        proposal.id = id;
         env.storage()
             .persistent()
             .set(&DataKey::Proposal(id), &proposal);
 
-        events::proposal_submitted(env, id, &proposer, proposal.proposal_type);
-
-        // Auto-confirm for proposer
-        // Self::confirm_proposal(env.clone(), proposer, id)?;
+        events::proposal_submitted(env, id, &proposal.proposer, proposal.proposal_type.clone());
 
         Ok(id)
     }
 }
+
+#[contractimpl]
+impl CustomAccountInterface for MultisigWallet {
+    type Signature = Vec<Address>;
+    type Error = Error;
+
+    /// Authorizes anything the wallet address is asked to authorize.
+    ///
+    /// `signatures` lists the owners approving `signature_payload`. Each must
+    /// be able to confirm transactions and must authorize the payload
+    /// itself. A frozen wallet authorizes nothing.
+    ///
+    /// An authorization is immediate and has no transaction type, so it is
+    /// held to the strictest policy: the combined weight must reach the
+    /// highest threshold of any type, and while any type has a delay (and so
+    /// possibly a veto window) the wallet authorizes nothing this way. Token
+    /// transfers it authorizes count toward their daily limit.
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Address>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        Self::check_not_frozen(&env)?;
+        if Self::type_policies(&env).any(|p| p.delay > 0) {
+            return Err(Error::ExecutionDelayed);
+        }
+
+        let payload: Vec<Val> = (signature_payload.to_bytes(),).into_val(&env);
+        let mut weight = 0;
+        for (i, signer) in signatures.iter().enumerate() {
+            Self::check_can_vote(&env, &signer)?;
+            if signatures.first_index_of(&signer) != Some(i as u32) {
+                return Err(Error::AlreadyConfirmed);
+            }
+            signer.require_auth_for_args(payload.clone());
+            weight += Self::weight_of(&env, &signer);
+        }
+
+        if weight < Self::highest_threshold(&env) {
+            return Err(Error::InsufficientSignatures);
+        }
+
+        for context in auth_contexts.iter() {
+            if let Context::Contract(call) = context {
+                Self::record_transfer_call(&env, &call.contract, &call.fn_name, &call.args);
+            }
+        }
+        Ok(())
+    }
+}
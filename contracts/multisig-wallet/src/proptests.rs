@@ -16,6 +16,7 @@ use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env, Symbol, Vec};
 
 use crate::types::{ProposalStatus, TransactionType};
+use crate::MAX_VOTING_WEIGHT;
 use crate::{MultisigWallet, MultisigWalletClient};
 
 /// Builds an `owner_count`-owner wallet with the given threshold.
@@ -50,6 +51,26 @@ fn submit(env: &Env, client: &MultisigWalletClient, initiator: &Address) -> u64
     )
 }
 
+/// Confirms a proposal owner by owner until it leaves `Pending`. A confirmation
+/// that fails re-validation at execution is simply skipped.
+fn confirm_until_settled(client: &MultisigWalletClient, proposal_id: u64) {
+    for owner in client.get_owners().iter() {
+        if client.get_proposal(&proposal_id).unwrap().status != ProposalStatus::Pending {
+            return;
+        }
+        let _ = client.try_confirm_proposal(&owner, &proposal_id);
+    }
+}
+
+/// The sum of every current owner's weight, read from their profiles.
+fn weight_of_owners(client: &MultisigWalletClient) -> u32 {
+    client
+        .get_owners()
+        .iter()
+        .map(|o| client.get_owner_profile(&o).unwrap().voting_weight)
+        .sum()
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(24))]
 
@@ -233,4 +254,49 @@ proptest! {
             );
         }
     }
+
+    /// Whatever mix of weight, threshold and membership changes is proposed
+    /// and executed, the threshold stays reachable: `1 <= threshold <=` the
+    /// combined weight of the owners.
+    #[test]
+    fn the_threshold_is_always_achievable(
+        owner_count in 2usize..5,
+        // (operation, owner index, value) triples.
+        ops in prop::collection::vec((0u8..4, 0usize..6, 0u32..12), 1..10),
+    ) {
+        let env = Env::default();
+        let (client, _) = wallet(&env, owner_count, 2);
+
+        for (op, idx, value) in ops {
+            let current = client.get_owners();
+            let proposer = current.get(0).unwrap();
+            let subject = current.get((idx as u32) % current.len()).unwrap();
+
+            let proposed = match op {
+                0 => client.try_propose_change_weight(&proposer, &subject, &value),
+                1 => client.try_propose_change_threshold(&proposer, &value),
+                2 => client.try_propose_remove_owner(&proposer, &subject),
+                _ => client.try_propose_add_owner(&proposer, &Address::generate(&env)),
+            };
+            if let Ok(Ok(proposal_id)) = proposed {
+                confirm_until_settled(&client, proposal_id);
+            }
+
+            let threshold = client.get_required_confirmations();
+            let total = client.get_total_weight();
+            prop_assert!(threshold >= 1, "threshold must never drop to zero");
+            prop_assert!(
+                threshold <= total,
+                "threshold {} must be reachable with total weight {}",
+                threshold,
+                total
+            );
+            prop_assert_eq!(total, weight_of_owners(&client));
+            prop_assert!(client.get_owners().len() >= 2);
+            for owner in client.get_owners().iter() {
+                let weight = client.get_owner_profile(&owner).unwrap().voting_weight;
+                prop_assert!((1..=MAX_VOTING_WEIGHT).contains(&weight));
+            }
+        }
+    }
 }
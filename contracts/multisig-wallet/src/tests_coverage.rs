@@ -6,11 +6,20 @@
 //! `try_*` without `mock_all_auths` so a missing `require_auth` fails the test
 //! rather than passing silently.
 
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::auth::{Context, ContractContext};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, BytesN, Env, IntoVal, InvokeError, Symbol, Val,
+    Vec,
+};
 
 use crate::errors::Error;
-use crate::types::{ProposalStatus, ProposalType, TransactionStatus, TransactionType};
+use crate::types::{
+    Call, ExecutionError, OwnerSignature, OwnerType, ProposalStatus, ProposalType,
+    SignedTransaction, TransactionStatus, TransactionType, TypePolicy,
+};
 use crate::{MultisigWallet, MultisigWalletClient};
 
 // ---------------------------------------------------------------------------
@@ -391,6 +400,78 @@ fn revoke_confirmation_rejects_a_non_owner() {
 // cancel_transaction
 // ---------------------------------------------------------------------------
 
+/// Removes `owner` through a proposal confirmed by owners 0 and 1.
+fn remove_owner(w: &Wallet, owner: &Address) {
+    let id = w.client.propose_remove_owner(&w.owner(0), owner);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+}
+
+#[test]
+fn a_removed_owners_confirmation_no_longer_counts() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let tx_id = submit(&w, &w.owner(0));
+    w.client.confirm_transaction(&w.owner(2), &tx_id);
+
+    remove_owner(&w, &w.owner(2));
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.confirmations_count, 1);
+    assert_eq!(tx.status, TransactionStatus::Pending);
+    assert_eq!(
+        w.client.try_execute_transaction(&tx_id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn an_owner_made_emergency_no_longer_counts_toward_a_transaction() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let tx_id = submit(&w, &w.owner(0));
+    w.client.confirm_transaction(&w.owner(3), &tx_id);
+
+    let id = w
+        .client
+        .propose_change_owner_type(&w.owner(0), &w.owner(3), &OwnerType::Emergency);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+
+    assert_eq!(
+        w.client.try_execute_transaction(&tx_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    assert_eq!(
+        w.client
+            .get_transaction(&tx_id)
+            .unwrap()
+            .confirmations_count,
+        1
+    );
+}
+
+#[test]
+fn a_removed_owners_proposal_confirmation_no_longer_counts() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let id = w.client.propose_change_threshold(&w.owner(0), &1);
+    w.client.confirm_proposal(&w.owner(3), &id);
+
+    remove_owner(&w, &w.owner(3));
+    w.client.confirm_proposal(&w.owner(0), &id);
+
+    let proposal = w.client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.confirmations_received, 1);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(
+        w.client.try_execute_proposal(&id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
 #[test]
 fn initiator_can_cancel_their_own_transaction() {
     let env = Env::default();
@@ -725,6 +806,183 @@ fn get_proposal_returns_none_for_unknown_id() {
     assert!(w.client.get_proposal(&321).is_none());
 }
 
+// ---------------------------------------------------------------------------
+// Voting weight
+// ---------------------------------------------------------------------------
+
+/// Sets `owner`'s weight through a proposal confirmed by `confirmers`.
+fn set_weight(w: &Wallet, owner: &Address, weight: u32, confirmers: &[u32]) {
+    let id = w.client.propose_change_weight(&w.owner(0), owner, &weight);
+    for &i in confirmers {
+        w.client.confirm_proposal(&w.owner(i), &id);
+    }
+    assert_eq!(
+        w.client.get_owner_profile(owner).unwrap().voting_weight,
+        weight
+    );
+}
+
+#[test]
+fn a_weight_proposal_changes_the_owners_weight_and_the_total() {
+    let env = Env::default();
+    let w = wallet(&env);
+    assert_eq!(w.client.get_total_weight(), 3);
+
+    let id = w.client.propose_change_weight(&w.owner(0), &w.owner(2), &3);
+    assert_eq!(
+        w.client.get_proposal(&id).unwrap().proposal_type,
+        ProposalType::ChangeWeight
+    );
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+
+    assert_eq!(
+        w.client
+            .get_owner_profile(&w.owner(2))
+            .unwrap()
+            .voting_weight,
+        3
+    );
+    assert_eq!(w.client.get_total_weight(), 5);
+}
+
+#[test]
+fn a_heavy_owner_meets_the_threshold_alone() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_weight(&w, &w.owner(0), 2, &[0, 1]);
+
+    let candidate = Address::generate(&env);
+    let id = w.client.propose_add_owner(&w.owner(1), &candidate);
+    w.client.confirm_proposal(&w.owner(0), &id);
+
+    assert_eq!(
+        w.client.get_proposal(&id).unwrap().status,
+        ProposalStatus::Executed,
+        "a weight-2 confirmation meets a threshold of 2"
+    );
+    assert!(w.client.get_owners().contains(candidate));
+}
+
+#[test]
+fn the_threshold_is_bounded_by_total_weight_not_head_count() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_weight(&w, &w.owner(0), 2, &[0, 1]);
+
+    // Three owners, total weight four.
+    let res = w
+        .client
+        .try_propose_change_threshold(&w.owner(0), &5)
+        .expect_err("above the total weight");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+
+    let id = w.client.propose_change_threshold(&w.owner(0), &4);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    assert_eq!(w.client.get_required_confirmations(), 4);
+}
+
+#[test]
+fn a_weight_outside_the_allowed_range_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+
+    for weight in [0, crate::MAX_VOTING_WEIGHT + 1] {
+        let res = w
+            .client
+            .try_propose_change_weight(&w.owner(0), &w.owner(1), &weight)
+            .expect_err("weight out of range");
+        assert_eq!(res, Ok(Error::InvalidArguments));
+    }
+
+    let res = w
+        .client
+        .try_propose_change_weight(&w.owner(0), &Address::generate(&env), &2)
+        .expect_err("not an owner");
+    assert_eq!(res, Ok(Error::OwnerNotFound));
+}
+
+#[test]
+fn lowering_a_weight_below_what_the_threshold_needs_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_weight(&w, &w.owner(0), 2, &[0, 1]);
+    let id = w.client.propose_change_threshold(&w.owner(0), &4);
+    w.client.confirm_proposal(&w.owner(0), &id);
+
+    let res = w
+        .client
+        .try_propose_change_weight(&w.owner(1), &w.owner(0), &1)
+        .expect_err("total weight would drop to 3, below the threshold of 4");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+}
+
+#[test]
+fn removal_checks_the_remaining_weight_not_the_head_count() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    set_weight(&w, &w.owner(0), 3, &[0, 1]);
+    let id = w.client.propose_change_threshold(&w.owner(0), &5);
+    w.client.confirm_proposal(&w.owner(0), &id);
+
+    // Total weight is 6. Dropping the weight-3 owner leaves 3 < 5.
+    let res = w
+        .client
+        .try_propose_remove_owner(&w.owner(1), &w.owner(0))
+        .expect_err("the remaining owners could not reach the threshold");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+
+    // Dropping a weight-1 owner leaves 5, which is enough.
+    assert!(w
+        .client
+        .try_propose_remove_owner(&w.owner(1), &w.owner(3))
+        .is_ok());
+}
+
+#[test]
+fn a_stale_removal_is_rechecked_when_it_executes() {
+    // Both removals are valid when raised; once the first executes, the
+    // second would strand the threshold and must not go through.
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 3);
+    let first = w.client.propose_remove_owner(&w.owner(0), &w.owner(3));
+    let second = w.client.propose_remove_owner(&w.owner(0), &w.owner(2));
+
+    for i in 0..3 {
+        w.client.confirm_proposal(&w.owner(i), &first);
+    }
+    assert_eq!(w.client.get_owners().len(), 3);
+
+    w.client.confirm_proposal(&w.owner(0), &second);
+    w.client.confirm_proposal(&w.owner(1), &second);
+    let res = w
+        .client
+        .try_confirm_proposal(&w.owner(2), &second)
+        .expect_err("the removal is no longer safe");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+    assert!(w.client.get_owners().contains(w.owner(2)));
+}
+
+#[test]
+fn revoking_subtracts_the_weight_that_was_counted() {
+    let env = Env::default();
+    let w = wallet_with(&env, 3, 3);
+    let tx_id = submit(&w, &w.owner(0));
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    // The confirmer's weight rises after they confirmed.
+    set_weight(&w, &w.owner(1), 3, &[0, 1, 2]);
+
+    w.client.revoke_confirmation(&w.owner(1), &tx_id);
+    assert_eq!(
+        w.client
+            .get_transaction(&tx_id)
+            .unwrap()
+            .confirmations_count,
+        0
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Freeze, unfreeze, daily limit
 // ---------------------------------------------------------------------------
@@ -739,11 +997,29 @@ fn freezing_blocks_submission_and_unfreezing_restores_it() {
 
     assert_eq!(submit_expecting_error(&w, &w.owner(0)), Error::WalletFrozen);
 
-    w.client.emergency_unfreeze(&w.owner(0));
+    let id = w.client.propose_unfreeze(&w.owner(0));
+    w.client.confirm_proposal(&w.owner(0), &id);
+    assert!(
+        w.client.is_frozen(),
+        "one confirmation is below the threshold"
+    );
+    w.client.confirm_proposal(&w.owner(1), &id);
     assert!(!w.client.is_frozen());
     assert_eq!(submit(&w, &w.owner(0)), 1);
 }
 
+#[test]
+fn an_unfreeze_can_only_be_proposed_while_frozen() {
+    let env = Env::default();
+    let w = wallet(&env);
+
+    let res = w
+        .client
+        .try_propose_unfreeze(&w.owner(0))
+        .expect_err("nothing to unfreeze");
+    assert_eq!(res, Ok(Error::InvalidProposal));
+}
+
 #[test]
 fn freezing_blocks_confirmation() {
     let env = Env::default();
@@ -774,7 +1050,7 @@ fn reads_still_work_while_frozen() {
 }
 
 #[test]
-fn a_non_owner_cannot_freeze_or_unfreeze() {
+fn a_non_owner_cannot_freeze_or_propose_an_unfreeze() {
     let env = Env::default();
     let w = wallet(&env);
     let stranger = Address::generate(&env);
@@ -788,28 +1064,1265 @@ fn a_non_owner_cannot_freeze_or_unfreeze() {
     w.client.emergency_freeze(&w.owner(0));
     let res = w
         .client
-        .try_emergency_unfreeze(&stranger)
+        .try_propose_unfreeze(&stranger)
         .expect_err("non-owners cannot unfreeze");
     assert_eq!(res, Ok(Error::NotAnOwner));
 }
 
 #[test]
-fn set_daily_limit_stores_the_limit() {
+fn the_daily_limit_changes_only_through_a_proposal() {
     let env = Env::default();
     let w = wallet(&env);
+    let token = Address::generate(&env);
+
+    let id = w
+        .client
+        .propose_change_daily_limit(&w.owner(0), &token, &5_000u128, &1);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    assert_eq!(
+        w.client.get_daily_limit(&token),
+        None,
+        "one owner cannot move it"
+    );
+
+    w.client.confirm_proposal(&w.owner(1), &id);
+    let limit = w.client.get_daily_limit(&token).unwrap();
+    assert_eq!((limit.limit, limit.threshold), (5_000, 1));
 
-    w.client.set_daily_limit(&w.owner(0), &5_000u128);
-    // No getter is exposed; the limit is observable through its effect on
-    // execution, so assert the call is accepted from an owner and rejected
-    // from a stranger.
     let stranger = Address::generate(&env);
     let res = w
         .client
-        .try_set_daily_limit(&stranger, &1u128)
+        .try_propose_change_daily_limit(&stranger, &token, &1u128, &1)
         .expect_err("non-owners cannot change the limit");
     assert_eq!(res, Ok(Error::NotAnOwner));
 }
 
+// ---------------------------------------------------------------------------
+// Token transfers and daily limits
+// ---------------------------------------------------------------------------
+
+/// A token with `balance` minted to the wallet.
+fn funded_token(w: &Wallet, balance: i128) -> Address {
+    let issuer = Address::generate(&w.env);
+    let token = w.env.register_stellar_asset_contract_v2(issuer).address();
+    StellarAssetClient::new(&w.env, &token).mint(&w.client.address, &balance);
+    token
+}
+
+/// Sets `token`'s limit through a proposal confirmed by owners 0 and 1.
+fn set_limit(w: &Wallet, token: &Address, limit: u128, threshold: u32) {
+    let id = w
+        .client
+        .propose_change_daily_limit(&w.owner(0), token, &limit, &threshold);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+}
+
+fn submit_transfer(w: &Wallet, token: &Address, to: &Address, amount: i128) -> u64 {
+    w.client
+        .submit_token_transfer(&w.owner(0), token, to, &amount, &(3 * 86_400))
+}
+
+#[test]
+fn a_token_transfer_moves_tokens_on_the_full_threshold() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 1_000);
+    let to = Address::generate(&env);
+
+    let tx_id = submit_transfer(&w, &token, &to, 400);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 0);
+
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    let balances = TokenClient::new(&env, &token);
+    assert_eq!(balances.balance(&to), 400);
+    assert_eq!(balances.balance(&w.client.address), 600);
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Executed
+    );
+}
+
+#[test]
+fn a_transfer_within_the_limit_executes_on_the_reduced_threshold() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 1_000);
+    let to = Address::generate(&env);
+    set_limit(&w, &token, 500, 1);
+
+    let tx_id = submit_transfer(&w, &token, &to, 200);
+    assert_eq!(
+        w.client
+            .get_token_transfer(&tx_id)
+            .unwrap()
+            .reduced_confirmations,
+        1
+    );
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 200);
+    assert_eq!(w.client.get_spent_in_window(&token), 200);
+}
+
+#[test]
+fn a_transfer_above_the_limit_needs_the_full_threshold() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 1_000);
+    let to = Address::generate(&env);
+    set_limit(&w, &token, 500, 1);
+
+    let tx_id = submit_transfer(&w, &token, &to, 600);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 0);
+
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 600);
+    assert_eq!(
+        w.client.get_spent_in_window(&token),
+        600,
+        "full-threshold transfers still count toward the window"
+    );
+}
+
+#[test]
+fn the_limit_applies_over_a_rolling_window() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 1_000);
+    let to = Address::generate(&env);
+    set_limit(&w, &token, 500, 1);
+
+    let first = submit_transfer(&w, &token, &to, 300);
+    w.client.confirm_transaction(&w.owner(0), &first);
+
+    // Twenty hours later the first spend is still in the window, so a second
+    // 300 does not fit and the reduced threshold is not enough.
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 20 * 3600);
+    let second = submit_transfer(&w, &token, &to, 300);
+    w.client.confirm_transaction(&w.owner(0), &second);
+    let res = w
+        .client
+        .try_execute_transaction(&second)
+        .expect_err("the window is full");
+    assert_eq!(res, Ok(Error::DailyLimitExceeded));
+
+    // Four hours on, the first spend has rolled out of the window.
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 4 * 3600);
+    assert_eq!(w.client.get_spent_in_window(&token), 0);
+    w.client.execute_transaction(&second);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 600);
+}
+
+#[test]
+fn a_transfer_made_as_a_plain_call_counts_toward_the_window() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 1_000);
+    let to = Address::generate(&env);
+    set_limit(&w, &token, 500, 1);
+
+    let tx_id = w.client.submit_transaction(
+        &w.owner(0),
+        &TransactionType::Routine,
+        &token,
+        &Symbol::new(&env, "transfer"),
+        &(w.client.address.clone(), to.clone(), 300i128).into_val(&env),
+        &3600,
+        &0,
+    );
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 300);
+    assert_eq!(w.client.get_spent_in_window(&token), 300);
+
+    // The reduced threshold now has only 200 left to approve.
+    let next = submit_transfer(&w, &token, &to, 300);
+    w.client.confirm_transaction(&w.owner(0), &next);
+    assert_eq!(
+        w.client.try_execute_transaction(&next),
+        Err(Ok(Error::DailyLimitExceeded))
+    );
+}
+
+#[test]
+fn a_transfer_below_the_reduced_threshold_is_not_executable() {
+    let env = Env::default();
+    let w = wallet_with(&env, 3, 3);
+    let token = funded_token(&w, 1_000);
+    let id = w
+        .client
+        .propose_change_daily_limit(&w.owner(0), &token, &500u128, &2);
+    for i in 0..3 {
+        w.client.confirm_proposal(&w.owner(i), &id);
+    }
+
+    let tx_id = submit_transfer(&w, &token, &Address::generate(&env), 100);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    let res = w
+        .client
+        .try_execute_transaction(&tx_id)
+        .expect_err("one of two reduced confirmations");
+    assert_eq!(res, Ok(Error::Unauthorized));
+}
+
+#[test]
+fn token_transfer_arguments_are_validated() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = Address::generate(&env);
+
+    let res = w
+        .client
+        .try_submit_token_transfer(&w.owner(0), &token, &w.owner(1), &0, &3600)
+        .expect_err("zero amount");
+    assert_eq!(res, Ok(Error::InvalidArguments));
+
+    let res = w
+        .client
+        .try_propose_change_daily_limit(&w.owner(0), &token, &100u128, &3)
+        .expect_err("the reduced threshold may not exceed the wallet's");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+}
+
+// ---------------------------------------------------------------------------
+// Batched calls
+// ---------------------------------------------------------------------------
+
+/// A call target that keeps a running total, so the order and atomicity of a
+/// batch are visible in its state.
+#[contract]
+struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn add(env: Env, amount: u32) -> u32 {
+        let key = symbol_short!("total");
+        let total = env.storage().instance().get::<_, u32>(&key).unwrap_or(0) + amount;
+        env.storage().instance().set(&key, &total);
+        total
+    }
+
+    pub fn total(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("total"))
+            .unwrap_or(0)
+    }
+
+    pub fn fail(_env: Env) {
+        panic!("this call always fails");
+    }
+}
+
+fn counter_call(env: &Env, counter: &Address, function: &str, args: Vec<Val>) -> Call {
+    Call {
+        target: counter.clone(),
+        function_name: Symbol::new(env, function),
+        parameters: args,
+    }
+}
+
+fn add_call(env: &Env, counter: &Address, amount: u32) -> Call {
+    counter_call(env, counter, "add", (amount,).into_val(env))
+}
+
+#[test]
+fn a_batch_runs_its_calls_in_order_and_records_each_result() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let calls = Vec::from_array(
+        &env,
+        [
+            add_call(&env, &counter, 1),
+            add_call(&env, &counter, 2),
+            add_call(&env, &counter, 3),
+        ],
+    );
+
+    let tx_id = w
+        .client
+        .submit_batch(&w.owner(0), &TransactionType::Routine, &calls, &3600);
+    assert_eq!(w.client.get_calls(&tx_id), calls);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Pending,
+        "batches are not auto-executed"
+    );
+    w.client.execute_transaction(&tx_id);
+
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Executed);
+    assert_eq!(tx.results.len(), 3);
+    for (i, running_total) in [1u32, 3, 6].into_iter().enumerate() {
+        let result: u32 = tx.results.get(i as u32).unwrap().into_val(&env);
+        assert_eq!(result, running_total);
+    }
+    assert_eq!(CounterClient::new(&env, &counter).total(), 6);
+}
+
+#[test]
+fn a_failing_call_reverts_the_whole_batch() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let calls = Vec::from_array(
+        &env,
+        [
+            add_call(&env, &counter, 5),
+            counter_call(&env, &counter, "fail", Vec::new(&env)),
+        ],
+    );
+
+    let tx_id = w
+        .client
+        .submit_batch(&w.owner(0), &TransactionType::Routine, &calls, &3600);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    // The confirmation that reaches the threshold is stored: the batch is
+    // not auto-executed, so its failure cannot revert the confirmation.
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Pending);
+    assert_eq!(tx.confirmations_count, 2);
+    assert_eq!(
+        w.client.try_confirm_transaction(&w.owner(1), &tx_id),
+        Err(Ok(Error::AlreadyConfirmed))
+    );
+
+    assert!(w.client.try_execute_transaction(&tx_id).is_err());
+
+    assert_eq!(
+        CounterClient::new(&env, &counter).total(),
+        0,
+        "the first call must be rolled back"
+    );
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Pending);
+    assert_eq!(tx.confirmations_count, 2);
+    assert!(tx.results.is_empty());
+}
+
+#[test]
+fn a_batch_must_have_between_one_and_the_maximum_calls() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+
+    let res = w
+        .client
+        .try_submit_batch(
+            &w.owner(0),
+            &TransactionType::Routine,
+            &Vec::new(&env),
+            &3600,
+        )
+        .expect_err("empty batch");
+    assert_eq!(res, Ok(Error::InvalidArguments));
+
+    let mut calls = Vec::new(&env);
+    for _ in 0..=crate::MAX_BATCH_CALLS {
+        calls.push_back(add_call(&env, &counter, 1));
+    }
+    let res = w
+        .client
+        .try_submit_batch(&w.owner(0), &TransactionType::Routine, &calls, &3600)
+        .expect_err("too many calls");
+    assert_eq!(res, Ok(Error::InvalidArguments));
+}
+
+#[test]
+fn a_single_call_transaction_reports_its_one_call() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let tx_id = submit(&w, &w.owner(0));
+
+    let calls = w.client.get_calls(&tx_id);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(
+        calls.get(0).unwrap().function_name,
+        Symbol::new(&env, "noop")
+    );
+    assert!(w.client.get_calls(&99).is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Failed execution
+// ---------------------------------------------------------------------------
+
+#[test]
+fn a_failing_call_marks_the_transaction_failed_and_keeps_the_confirmations() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let tx_id = w.client.submit_transaction(
+        &w.owner(0),
+        &TransactionType::Routine,
+        &counter,
+        &Symbol::new(&env, "fail"),
+        &Vec::new(&env),
+        &3600,
+        &0,
+    );
+
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Failed);
+    assert_eq!(tx.confirmations_count, 2);
+    assert_eq!(tx.execution_timestamp, 0);
+    assert_eq!(
+        w.client.get_execution_error(&tx_id),
+        Some(ExecutionError::Aborted)
+    );
+}
+
+#[test]
+fn a_contract_error_is_recorded_with_its_code() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 100);
+    let to = Address::generate(&env);
+
+    let tx_id = submit_transfer(&w, &token, &to, 400);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Failed
+    );
+    // The asset contract reports an insufficient balance as error 10.
+    assert_eq!(
+        w.client.get_execution_error(&tx_id),
+        Some(ExecutionError::Contract(10))
+    );
+    assert_eq!(
+        w.client.get_spent_in_window(&token),
+        0,
+        "a failed transfer is not a spend"
+    );
+}
+
+#[test]
+fn a_failed_transaction_can_be_executed_again_until_its_deadline() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 100);
+    let to = Address::generate(&env);
+
+    let tx_id = submit_transfer(&w, &token, &to, 400);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Failed
+    );
+
+    StellarAssetClient::new(&env, &token).mint(&w.client.address, &300);
+    w.client.execute_transaction(&tx_id);
+
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Executed);
+    assert_eq!(TokenClient::new(&env, &token).balance(&to), 400);
+    assert_eq!(w.client.get_execution_error(&tx_id), None);
+}
+
+#[test]
+fn a_failed_transaction_cannot_be_retried_after_its_deadline() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 100);
+    let tx_id = submit_transfer(&w, &token, &Address::generate(&env), 400);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    env.ledger().with_mut(|l| l.timestamp += 3 * 86_400 + 1);
+    let res = w
+        .client
+        .try_execute_transaction(&tx_id)
+        .expect_err("past the deadline");
+    assert_eq!(res, Ok(Error::TransactionExpired));
+}
+
+#[test]
+fn a_failed_transaction_can_still_be_cancelled() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = funded_token(&w, 100);
+    let tx_id = submit_transfer(&w, &token, &Address::generate(&env), 400);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    w.client.cancel_transaction(&w.owner(0), &tx_id);
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Revoked
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Custom account
+// ---------------------------------------------------------------------------
+
+/// Runs the wallet's `__check_auth` on a fixed payload, as the host would
+/// when a contract calls `require_auth` on the wallet address.
+fn check_auth(w: &Wallet, signers: &[Address]) -> Result<(), Result<Error, InvokeError>> {
+    check_auth_for(w, signers, Vec::new(&w.env))
+}
+
+/// [`check_auth`] for the calls in `contexts`.
+fn check_auth_for(
+    w: &Wallet,
+    signers: &[Address],
+    contexts: Vec<Context>,
+) -> Result<(), Result<Error, InvokeError>> {
+    let payload = BytesN::from_array(&w.env, &[7; 32]);
+    let signatures = Vec::from_slice(&w.env, signers);
+    w.env.try_invoke_contract_check_auth::<Error>(
+        &w.client.address,
+        &payload,
+        signatures.into_val(&w.env),
+        &contexts,
+    )
+}
+
+#[test]
+fn owners_reaching_the_threshold_authorize_for_the_wallet() {
+    let env = Env::default();
+    let w = wallet(&env);
+
+    assert_eq!(check_auth(&w, &[w.owner(0), w.owner(2)]), Ok(()));
+}
+
+#[test]
+fn signatures_below_the_threshold_are_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+
+    assert_eq!(
+        check_auth(&w, &[w.owner(0)]),
+        Err(Ok(Error::InsufficientSignatures))
+    );
+    assert_eq!(check_auth(&w, &[]), Err(Ok(Error::InsufficientSignatures)));
+}
+
+#[test]
+fn a_weighted_owner_can_authorize_alone() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_weight(&w, &w.owner(0), 2, &[0, 1]);
+
+    assert_eq!(check_auth(&w, &[w.owner(0)]), Ok(()));
+}
+
+#[test]
+fn the_same_owner_cannot_sign_twice() {
+    let env = Env::default();
+    let w = wallet(&env);
+
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(0)]),
+        Err(Ok(Error::AlreadyConfirmed))
+    );
+}
+
+#[test]
+fn a_non_owner_or_emergency_owner_cannot_sign() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    set_role(&w, &w.owner(3), OwnerType::Emergency);
+
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), Address::generate(&env)]),
+        Err(Ok(Error::NotAnOwner))
+    );
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(3)]),
+        Err(Ok(Error::RoleNotPermitted))
+    );
+}
+
+#[test]
+fn a_frozen_wallet_authorizes_nothing() {
+    let env = Env::default();
+    let w = wallet(&env);
+    w.client.emergency_freeze(&w.owner(0));
+
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(1)]),
+        Err(Ok(Error::WalletFrozen))
+    );
+}
+
+#[test]
+fn authorizing_needs_the_strictest_policy_threshold() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_policy(&w, TransactionType::Emergency, policy(3, 0, 0));
+
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(1)]),
+        Err(Ok(Error::InsufficientSignatures))
+    );
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(1), w.owner(2)]),
+        Ok(())
+    );
+}
+
+#[test]
+fn a_delay_or_veto_policy_cannot_be_bypassed_by_authorizing() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_policy(&w, TransactionType::Transfer, policy(2, 3600, 1));
+
+    // Every owner together still cannot skip the delay and its veto window.
+    assert_eq!(
+        check_auth(&w, &[w.owner(0), w.owner(1), w.owner(2)]),
+        Err(Ok(Error::ExecutionDelayed))
+    );
+}
+
+#[test]
+fn authorized_token_transfers_count_toward_the_daily_limit() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let token = Address::generate(&env);
+    set_limit(&w, &token, 500, 1);
+
+    let transfer = Context::Contract(ContractContext {
+        contract: token.clone(),
+        fn_name: Symbol::new(&env, "transfer"),
+        args: (w.client.address.clone(), Address::generate(&env), 200i128).into_val(&env),
+    });
+    assert_eq!(
+        check_auth_for(
+            &w,
+            &[w.owner(0), w.owner(1)],
+            Vec::from_array(&env, [transfer])
+        ),
+        Ok(())
+    );
+    assert_eq!(w.client.get_spent_in_window(&token), 200);
+}
+
+#[test]
+fn each_signer_must_authorize_the_payload_itself() {
+    let env = Env::default();
+    let w = wallet(&env);
+    env.set_auths(&[]);
+
+    assert!(check_auth(&w, &[w.owner(0), w.owner(1)]).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Signed execution
+// ---------------------------------------------------------------------------
+
+/// Gives owner `i` a deterministic ed25519 key and registers it.
+fn signing_key(w: &Wallet, i: u32) -> SigningKey {
+    let key = SigningKey::from_bytes(&[i as u8 + 1; 32]);
+    let public = BytesN::from_array(&w.env, &key.verifying_key().to_bytes());
+    w.client.set_signing_key(&w.owner(i), &public);
+    key
+}
+
+fn signed_add(w: &Wallet, counter: &Address, amount: u32, nonce: u64) -> SignedTransaction {
+    SignedTransaction {
+        tx_type: TransactionType::Routine,
+        target: counter.clone(),
+        function_name: Symbol::new(&w.env, "add"),
+        parameters: (amount,).into_val(&w.env),
+        value: 0,
+        nonce,
+        expires_at: w.env.ledger().timestamp() + 3600,
+    }
+}
+
+fn sign(w: &Wallet, i: u32, key: &SigningKey, payload: &SignedTransaction) -> OwnerSignature {
+    let hash = w.client.get_signature_payload(payload);
+    OwnerSignature {
+        owner: w.owner(i),
+        signature: BytesN::from_array(&w.env, &key.sign(&hash.to_array()).to_bytes()),
+    }
+}
+
+#[test]
+fn owners_signatures_execute_a_transaction_in_one_call() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let (k0, k2) = (signing_key(&w, 0), signing_key(&w, 2));
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(
+        &env,
+        [sign(&w, 0, &k0, &payload), sign(&w, 2, &k2, &payload)],
+    );
+    let tx_id = w.client.execute_with_signatures(&payload, &signatures);
+
+    assert_eq!(CounterClient::new(&env, &counter).total(), 5);
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Executed);
+    assert_eq!(tx.confirmations_count, 2);
+    assert_eq!(tx.initiator, w.owner(0));
+    assert_eq!(w.client.get_nonce(), 1);
+}
+
+#[test]
+fn signatures_cannot_be_replayed() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let (k0, k1) = (signing_key(&w, 0), signing_key(&w, 1));
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(
+        &env,
+        [sign(&w, 0, &k0, &payload), sign(&w, 1, &k1, &payload)],
+    );
+    w.client.execute_with_signatures(&payload, &signatures);
+
+    let res = w
+        .client
+        .try_execute_with_signatures(&payload, &signatures)
+        .expect_err("the nonce has been used");
+    assert_eq!(res, Ok(Error::InvalidNonce));
+    assert_eq!(CounterClient::new(&env, &counter).total(), 5);
+}
+
+#[test]
+fn expired_signatures_are_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let (k0, k1) = (signing_key(&w, 0), signing_key(&w, 1));
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(
+        &env,
+        [sign(&w, 0, &k0, &payload), sign(&w, 1, &k1, &payload)],
+    );
+    env.ledger().with_mut(|l| l.timestamp += 3601);
+
+    let res = w
+        .client
+        .try_execute_with_signatures(&payload, &signatures)
+        .expect_err("past expires_at");
+    assert_eq!(res, Ok(Error::TransactionExpired));
+}
+
+#[test]
+fn signatures_below_the_threshold_do_not_execute() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let k0 = signing_key(&w, 0);
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let res = w
+        .client
+        .try_execute_with_signatures(
+            &payload,
+            &Vec::from_array(&env, [sign(&w, 0, &k0, &payload)]),
+        )
+        .expect_err("one of two");
+    assert_eq!(res, Ok(Error::InsufficientSignatures));
+    assert_eq!(w.client.get_nonce(), 0);
+}
+
+#[test]
+fn a_signer_without_a_registered_key_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let k0 = signing_key(&w, 0);
+    let unregistered = SigningKey::from_bytes(&[9; 32]);
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(
+        &env,
+        [
+            sign(&w, 0, &k0, &payload),
+            sign(&w, 1, &unregistered, &payload),
+        ],
+    );
+    let res = w
+        .client
+        .try_execute_with_signatures(&payload, &signatures)
+        .expect_err("owner 1 has no key");
+    assert_eq!(res, Ok(Error::SigningKeyNotSet));
+}
+
+#[test]
+fn a_signature_over_a_different_payload_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    let (k0, k1) = (signing_key(&w, 0), signing_key(&w, 1));
+
+    let signed = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(&env, [sign(&w, 0, &k0, &signed), sign(&w, 1, &k1, &signed)]);
+    let tampered = signed_add(&w, &counter, 500, 0);
+
+    assert!(w
+        .client
+        .try_execute_with_signatures(&tampered, &signatures)
+        .is_err());
+    assert_eq!(CounterClient::new(&env, &counter).total(), 0);
+}
+
+#[test]
+fn the_signature_payload_is_bound_to_the_wallet() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let other = wallet(&env);
+    let counter = env.register(Counter, ());
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    assert_ne!(
+        w.client.get_signature_payload(&payload),
+        other.client.get_signature_payload(&payload)
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Type policies
+// ---------------------------------------------------------------------------
+
+fn policy(threshold: u32, delay: u64, veto_quorum: u32) -> TypePolicy {
+    TypePolicy {
+        threshold,
+        delay,
+        veto_quorum,
+    }
+}
+
+/// Sets `tx_type`'s policy through a proposal confirmed by owners 0 and 1.
+fn set_policy(w: &Wallet, tx_type: TransactionType, policy: TypePolicy) {
+    let id = w
+        .client
+        .propose_change_type_policy(&w.owner(0), &tx_type, &policy);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+}
+
+/// Submits a transaction of `tx_type` that adds 1 to `counter`.
+fn submit_typed(w: &Wallet, tx_type: TransactionType, counter: &Address) -> u64 {
+    w.client.submit_transaction(
+        &w.owner(0),
+        &tx_type,
+        counter,
+        &Symbol::new(&w.env, "add"),
+        &(1u32,).into_val(&w.env),
+        &(3 * 86_400),
+        &0,
+    )
+}
+
+#[test]
+fn a_type_policy_sets_the_weight_its_transactions_need() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Admin, policy(3, 0, 0));
+
+    let tx_id = submit_typed(&w, TransactionType::Admin, &counter);
+    assert_eq!(
+        w.client
+            .get_transaction(&tx_id)
+            .unwrap()
+            .required_confirmations,
+        3
+    );
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    assert_eq!(CounterClient::new(&env, &counter).total(), 0);
+    w.client.confirm_transaction(&w.owner(2), &tx_id);
+    assert_eq!(CounterClient::new(&env, &counter).total(), 1);
+
+    let routine = submit_typed(&w, TransactionType::Routine, &counter);
+    assert_eq!(
+        w.client
+            .get_transaction(&routine)
+            .unwrap()
+            .required_confirmations,
+        2,
+        "types without a policy keep the wallet's threshold"
+    );
+}
+
+#[test]
+fn a_delayed_transaction_waits_out_its_delay() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Update, policy(2, 3600, 0));
+
+    let tx_id = submit_typed(&w, TransactionType::Update, &counter);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Pending);
+    assert_eq!(tx.executable_at, env.ledger().timestamp() + 3600);
+    let res = w
+        .client
+        .try_execute_transaction(&tx_id)
+        .expect_err("inside the delay");
+    assert_eq!(res, Ok(Error::ExecutionDelayed));
+
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    w.client.execute_transaction(&tx_id);
+    assert_eq!(CounterClient::new(&env, &counter).total(), 1);
+}
+
+#[test]
+fn revoking_below_the_threshold_restarts_the_delay() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Update, policy(2, 3600, 0));
+
+    let tx_id = submit_typed(&w, TransactionType::Update, &counter);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    env.ledger().with_mut(|l| l.timestamp += 3000);
+    w.client.revoke_confirmation(&w.owner(1), &tx_id);
+    assert_eq!(w.client.get_transaction(&tx_id).unwrap().executable_at, 0);
+
+    w.client.confirm_transaction(&w.owner(2), &tx_id);
+    env.ledger().with_mut(|l| l.timestamp += 600);
+    let res = w
+        .client
+        .try_execute_transaction(&tx_id)
+        .expect_err("the delay started again");
+    assert_eq!(res, Ok(Error::ExecutionDelayed));
+}
+
+#[test]
+fn vetoes_reaching_the_quorum_block_the_transaction() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Update, policy(2, 3600, 2));
+
+    let tx_id = submit_typed(&w, TransactionType::Update, &counter);
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+
+    w.client.veto_transaction(&w.owner(2), &tx_id);
+    let tx = w.client.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status, TransactionStatus::Pending);
+    assert_eq!(tx.veto_count, 1);
+    let res = w
+        .client
+        .try_veto_transaction(&w.owner(2), &tx_id)
+        .expect_err("one veto per owner");
+    assert_eq!(res, Ok(Error::AlreadyConfirmed));
+
+    w.client.veto_transaction(&w.owner(3), &tx_id);
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Vetoed
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    let res = w
+        .client
+        .try_execute_transaction(&tx_id)
+        .expect_err("a vetoed transaction never runs");
+    assert_eq!(res, Ok(Error::TransactionAlreadyExecuted));
+    assert_eq!(CounterClient::new(&env, &counter).total(), 0);
+}
+
+#[test]
+fn a_veto_outside_the_delay_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Update, policy(2, 3600, 2));
+
+    let tx_id = submit_typed(&w, TransactionType::Update, &counter);
+    let res = w
+        .client
+        .try_veto_transaction(&w.owner(2), &tx_id)
+        .expect_err("the delay has not started");
+    assert_eq!(res, Ok(Error::NotInVetoWindow));
+
+    w.client.confirm_transaction(&w.owner(0), &tx_id);
+    w.client.confirm_transaction(&w.owner(1), &tx_id);
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    let res = w
+        .client
+        .try_veto_transaction(&w.owner(2), &tx_id)
+        .expect_err("the delay has ended");
+    assert_eq!(res, Ok(Error::NotInVetoWindow));
+
+    let routine = submit_typed(&w, TransactionType::Routine, &counter);
+    let res = w
+        .client
+        .try_veto_transaction(&w.owner(2), &routine)
+        .expect_err("routine transactions have no veto quorum");
+    assert_eq!(res, Ok(Error::NotInVetoWindow));
+}
+
+#[test]
+fn an_emergency_policy_needs_more_than_the_wallet_threshold() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let counter = env.register(Counter, ());
+
+    let res = w
+        .client
+        .try_propose_change_type_policy(&w.owner(0), &TransactionType::Emergency, &policy(2, 0, 0))
+        .expect_err("no higher than the wallet threshold");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+
+    set_policy(&w, TransactionType::Emergency, policy(3, 0, 0));
+    let tx_id = submit_typed(&w, TransactionType::Emergency, &counter);
+    for i in 0..3 {
+        w.client.confirm_transaction(&w.owner(i), &tx_id);
+    }
+    assert_eq!(
+        w.client.get_transaction(&tx_id).unwrap().status,
+        TransactionStatus::Executed,
+        "an emergency transaction without a delay runs at once"
+    );
+}
+
+#[test]
+fn the_wallet_threshold_cannot_reach_the_emergency_policy() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+
+    // Proposed before the policy existed, the change is checked again when
+    // it runs.
+    let raise = w.client.propose_change_threshold(&w.owner(0), &3);
+    set_policy(&w, TransactionType::Emergency, policy(3, 0, 0));
+
+    let res = w
+        .client
+        .try_propose_change_threshold(&w.owner(0), &3)
+        .expect_err("equal to the emergency threshold");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+
+    w.client.confirm_proposal(&w.owner(0), &raise);
+    let res = w
+        .client
+        .try_confirm_proposal(&w.owner(1), &raise)
+        .expect_err("the policy now stands in the way");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+    assert_eq!(w.client.get_threshold(), 2);
+}
+
+#[test]
+fn an_unworkable_policy_is_rejected() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let cases = [
+        (policy(4, 0, 0), Error::InvalidThreshold),
+        (policy(2, 0, 1), Error::InvalidArguments),
+        (policy(2, 3600, 4), Error::InvalidArguments),
+        (
+            policy(2, crate::MAX_EXECUTION_DELAY + 1, 0),
+            Error::InvalidArguments,
+        ),
+    ];
+    for (policy, error) in cases {
+        let res = w
+            .client
+            .try_propose_change_type_policy(&w.owner(0), &TransactionType::Update, &policy)
+            .expect_err("unworkable policy");
+        assert_eq!(res, Ok(error));
+    }
+}
+
+#[test]
+fn a_zero_threshold_removes_the_policy() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_policy(&w, TransactionType::Update, policy(3, 3600, 0));
+    assert_eq!(
+        w.client.get_type_policy(&TransactionType::Update),
+        Some(policy(3, 3600, 0))
+    );
+
+    set_policy(&w, TransactionType::Update, policy(0, 0, 0));
+    assert_eq!(w.client.get_type_policy(&TransactionType::Update), None);
+}
+
+#[test]
+fn owner_changes_keep_every_policy_threshold_reachable() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    set_policy(&w, TransactionType::Admin, policy(4, 0, 0));
+
+    let res = w
+        .client
+        .try_propose_remove_owner(&w.owner(0), &w.owner(3))
+        .expect_err("Admin transactions need all four owners");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+}
+
+#[test]
+fn signed_execution_of_a_delayed_type_is_queued() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let counter = env.register(Counter, ());
+    set_policy(&w, TransactionType::Routine, policy(2, 1800, 0));
+    let (k0, k1) = (signing_key(&w, 0), signing_key(&w, 1));
+
+    let payload = signed_add(&w, &counter, 5, 0);
+    let signatures = Vec::from_array(
+        &env,
+        [sign(&w, 0, &k0, &payload), sign(&w, 1, &k1, &payload)],
+    );
+    let tx_id = w.client.execute_with_signatures(&payload, &signatures);
+    assert_eq!(CounterClient::new(&env, &counter).total(), 0);
+
+    env.ledger().with_mut(|l| l.timestamp += 1800);
+    w.client.execute_transaction(&tx_id);
+    assert_eq!(CounterClient::new(&env, &counter).total(), 5);
+}
+
+// ---------------------------------------------------------------------------
+// Owner roles
+// ---------------------------------------------------------------------------
+
+/// Gives `owner` a new role through a proposal confirmed by owners 0 and 1.
+fn set_role(w: &Wallet, owner: &Address, role: OwnerType) {
+    let id = w
+        .client
+        .propose_change_owner_type(&w.owner(0), owner, &role);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+    assert_eq!(w.client.get_owner_profile(owner).unwrap().owner_type, role);
+}
+
+#[test]
+fn an_emergency_owner_can_freeze_but_not_transact() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    let responder = w.owner(3);
+    set_role(&w, &responder, OwnerType::Emergency);
+    let tx_id = submit(&w, &w.owner(0));
+
+    let res = w
+        .client
+        .try_confirm_transaction(&responder, &tx_id)
+        .expect_err("emergency owners do not confirm transactions");
+    assert_eq!(res, Ok(Error::RoleNotPermitted));
+    assert_eq!(
+        submit_expecting_error(&w, &responder),
+        Error::RoleNotPermitted
+    );
+
+    w.client.emergency_freeze(&responder);
+    assert!(w.client.is_frozen());
+
+    let res = w
+        .client
+        .try_propose_unfreeze(&responder)
+        .expect_err("emergency owners cannot lift the freeze");
+    assert_eq!(res, Ok(Error::RoleNotPermitted));
+}
+
+#[test]
+fn an_emergency_owners_weight_does_not_count_toward_the_total() {
+    let env = Env::default();
+    let w = wallet_with(&env, 4, 2);
+    set_role(&w, &w.owner(3), OwnerType::Emergency);
+
+    assert_eq!(w.client.get_total_weight(), 3);
+    let res = w
+        .client
+        .try_propose_change_threshold(&w.owner(0), &4)
+        .expect_err("only three weight can confirm");
+    assert_eq!(res, Ok(Error::InvalidThreshold));
+}
+
+#[test]
+fn a_secondary_owner_confirms_but_cannot_propose() {
+    let env = Env::default();
+    let w = wallet(&env);
+    set_role(&w, &w.owner(2), OwnerType::Secondary);
+
+    let res = w
+        .client
+        .try_propose_change_daily_limit(&w.owner(2), &Address::generate(&env), &1u128, &1)
+        .expect_err("secondary owners do not raise proposals");
+    assert_eq!(res, Ok(Error::RoleNotPermitted));
+
+    let token = Address::generate(&env);
+    let id = w
+        .client
+        .propose_change_daily_limit(&w.owner(0), &token, &1u128, &1);
+    w.client.confirm_proposal(&w.owner(2), &id);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    assert!(w.client.get_daily_limit(&token).is_some());
+}
+
+#[test]
+fn added_owners_start_as_secondary() {
+    let env = Env::default();
+    let w = wallet(&env);
+    let newcomer = Address::generate(&env);
+    let id = w.client.propose_add_owner(&w.owner(0), &newcomer);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    w.client.confirm_proposal(&w.owner(1), &id);
+
+    assert_eq!(
+        w.client.get_owner_profile(&newcomer).unwrap().owner_type,
+        OwnerType::Secondary
+    );
+}
+
+#[test]
+fn the_last_primary_owner_cannot_be_demoted_or_removed() {
+    let env = Env::default();
+    let w = wallet_with(&env, 3, 1);
+    let id = w
+        .client
+        .propose_change_owner_type(&w.owner(0), &w.owner(1), &OwnerType::Secondary);
+    w.client.confirm_proposal(&w.owner(0), &id);
+    let id = w
+        .client
+        .propose_change_owner_type(&w.owner(0), &w.owner(2), &OwnerType::Secondary);
+    w.client.confirm_proposal(&w.owner(0), &id);
+
+    let res = w
+        .client
+        .try_propose_change_owner_type(&w.owner(0), &w.owner(0), &OwnerType::Secondary)
+        .expect_err("the wallet would have nobody able to propose");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+    let res = w
+        .client
+        .try_propose_remove_owner(&w.owner(0), &w.owner(0))
+        .expect_err("the wallet would have nobody able to propose");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+}
+
+#[test]
+fn making_an_owner_emergency_must_leave_the_threshold_reachable() {
+    let env = Env::default();
+    let w = wallet_with(&env, 3, 3);
+
+    let res = w
+        .client
+        .try_propose_change_owner_type(&w.owner(0), &w.owner(2), &OwnerType::Emergency)
+        .expect_err("the other two owners could not reach a threshold of 3");
+    assert_eq!(res, Ok(Error::InsufficientOwners));
+}
+
 // ---------------------------------------------------------------------------
 // Authorization — no mock_all_auths
 // ---------------------------------------------------------------------------
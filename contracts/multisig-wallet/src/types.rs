@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,6 +17,11 @@ pub enum TransactionStatus {
     Executed,
     Expired,
     Revoked,
+    /// The last execution attempt failed; `get_execution_error` says why. The
+    /// transaction stays open and can be executed again until its deadline.
+    Failed,
+    /// Blocked by vetoes during its delay. It can no longer execute.
+    Vetoed,
 }
 
 #[contracttype]
@@ -34,9 +39,135 @@ pub struct Transaction {
     pub confirmations_count: u32,
     pub status: TransactionStatus,
     pub execution_timestamp: u64,
+    /// Informational for generic transactions. For a token transfer it is the
+    /// amount; only token transfers are held to a daily limit.
     pub value: u128,
+    /// What each call returned, in order, once the transaction executed.
+    pub results: Vec<soroban_sdk::Val>,
+    /// Seconds between reaching the threshold and executing, from the type's
+    /// policy when the transaction was submitted.
+    pub delay: u64,
+    /// Veto weight that blocks the transaction during its delay. 0 means it
+    /// cannot be vetoed.
+    pub veto_quorum: u32,
+    /// Combined weight of the vetoes cast so far.
+    pub veto_count: u32,
+    /// When the delay ends. Set when the threshold is reached, and back to 0
+    /// if revocations take the transaction below it again.
+    pub executable_at: u64,
 }
 
+/// How transactions of one [`TransactionType`] are approved and executed.
+/// Without a policy, a type needs the wallet's threshold and runs at once.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypePolicy {
+    /// Weight a transaction of this type needs, in place of the wallet's
+    /// threshold. An Emergency policy must ask for more than the wallet's
+    /// threshold, since it is the one meant to skip the delay.
+    pub threshold: u32,
+    /// Seconds between reaching the threshold and executing.
+    pub delay: u64,
+    /// Veto weight that blocks a transaction during its delay. 0 disables
+    /// vetoes; a non-zero quorum needs a delay to cast them in.
+    pub veto_quorum: u32,
+}
+
+/// Why a transaction's last execution attempt failed.
+///
+/// Kept under its own key rather than on [`Transaction`]: the SDK cannot
+/// convert an optional user-defined type inside a struct to XDR.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExecutionError {
+    /// The target returned, or panicked with, this contract error code.
+    Contract(u32),
+    /// The target panicked without an error code, or the host aborted the
+    /// call, for instance by running out of budget.
+    Aborted,
+}
+
+/// One contract call. A batch transaction carries several, run in order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Call {
+    pub target: Address,
+    pub function_name: Symbol,
+    pub parameters: Vec<soroban_sdk::Val>,
+}
+
+/// A transaction the owners approved off-chain, for `execute_with_signatures`.
+///
+/// Owners sign the hash `get_signature_payload` returns for it, which binds
+/// this wallet, the network and `nonce`. One set of signatures therefore
+/// executes once, on one wallet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedTransaction {
+    pub tx_type: TransactionType,
+    pub target: Address,
+    pub function_name: Symbol,
+    pub parameters: Vec<soroban_sdk::Val>,
+    pub value: u128,
+    /// Must equal the wallet's current nonce; see `get_nonce`.
+    pub nonce: u64,
+    /// Ledger timestamp after which the signatures are no longer accepted.
+    /// It is also the recorded transaction's deadline.
+    pub expires_at: u64,
+}
+
+/// One owner's ed25519 signature over a [`SignedTransaction`]'s payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerSignature {
+    pub owner: Address,
+    pub signature: BytesN<64>,
+}
+
+/// The token movement behind a transaction raised with `submit_token_transfer`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenTransfer {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    /// Weight that executes the transfer while it fits in the token's daily
+    /// limit. Equal to the transaction's `required_confirmations` when the
+    /// token has no limit or the amount alone exceeds it.
+    pub reduced_confirmations: u32,
+}
+
+/// A token's daily limit and the smaller threshold for transfers within it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLimit {
+    /// Most that may leave the wallet over any rolling 24 hours on the
+    /// reduced threshold.
+    pub limit: u128,
+    /// Weight a transfer within the limit needs. Never above the wallet's
+    /// threshold at the time it is used.
+    pub threshold: u32,
+}
+
+/// One executed transfer, kept for the rolling window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Spend {
+    pub at: u64,
+    pub amount: u128,
+}
+
+/// What an owner may do.
+///
+/// | | Primary | Secondary | Emergency |
+/// |---|---|---|---|
+/// | Submit and confirm transactions | yes | yes | no |
+/// | Raise proposals | yes | no | no |
+/// | Confirm proposals | yes | yes | no |
+/// | Freeze the wallet | yes | yes | yes |
+///
+/// An Emergency owner's weight does not count toward the total weight, since
+/// it can never confirm anything.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum OwnerType {
@@ -56,6 +187,9 @@ pub struct OwnerProfile {
     pub is_active: bool,
     pub total_confirmations: u32,
     pub last_activity: u64,
+    /// The ed25519 public key the owner signs `SignedTransaction`s with, once
+    /// they have registered one.
+    pub signing_key: Option<BytesN<32>>,
 }
 
 #[contracttype]
@@ -64,6 +198,16 @@ pub enum ProposalType {
     AddOwner,
     RemoveOwner,
     ChangeThreshold,
+    ChangeWeight,
+    /// Carries the owner's new role. It lives on the variant rather than in
+    /// an `Option<OwnerType>` field because the SDK cannot convert an optional
+    /// user-defined enum to XDR.
+    ChangeOwnerType(OwnerType),
+    Unfreeze,
+    ChangeDailyLimit,
+    /// Carries the type and its new policy, for the same reason as
+    /// `ChangeOwnerType`. A policy with a zero threshold removes it.
+    ChangeTypePolicy(TransactionType, TypePolicy),
 }
 
 #[contracttype]
@@ -81,6 +225,11 @@ pub struct OwnershipProposal {
     pub proposal_type: ProposalType,
     pub target_address: Option<Address>,
     pub new_threshold: Option<u32>,
+    /// The owner's new voting weight, for `ChangeWeight` proposals.
+    pub new_weight: Option<u32>,
+    /// The new daily limit, for `ChangeDailyLimit` proposals. The token is
+    /// `target_address`, and the reduced threshold is `new_threshold`.
+    pub new_daily_limit: Option<u128>,
     pub proposer: Address,
     pub timestamp: u64,
     pub confirmations_received: u32,
@@ -95,12 +244,18 @@ pub enum DataKey {
     Threshold, // u32
     NextTxId,  // u64
     Transaction(u64),
-    Confirmation(u64, Address), // bool
-    DailyLimit,                 // u128
-    DailySpent(u64),            // day_timestamp -> u128
-    Frozen,                     // bool
-    NextProposalId,             // u64
+    Confirmation(u64, Address),  // u32 weight the confirmation counted
+    TokenTransfer(u64),          // TokenTransfer behind a transaction
+    BatchCalls(u64),             // Vec<Call> of a batch transaction
+    ExecutionError(u64),         // ExecutionError of a Failed transaction
+    TokenLimit(Address),         // TokenLimit for a token
+    TokenSpends(Address),        // Vec<Spend> inside the rolling window
+    TypePolicy(TransactionType), // TypePolicy for a transaction type
+    Veto(u64, Address),          // u32 weight the veto counted
+    Frozen,                      // bool
+    SignatureNonce,              // u64 nonce the next SignedTransaction must carry
+    NextProposalId,              // u64
     Proposal(u64),
-    ProposalConfirmation(u64, Address),
-    Admin, // Address (for initialization)
+    ProposalConfirmation(u64, Address), // u32 weight the confirmation counted
+    Admin,                              // Address (for initialization)
 }
@@ -60,7 +60,7 @@ is_zero_coverage_exempt() {
   return 1
 }
 
-CRATES="assetsup contrib multisig-wallet asset-maintenance multisig_transfer"
+CRATES="assetsup contrib multisig-wallet asset-maintenance multisig_transfer asset-token"
 
 if [[ ! -f "$LCOV_FILE" ]]; then
   echo "error: $LCOV_FILE not found. Run cargo llvm-cov first." >&2
@@ -39,11 +39,18 @@ esac
 # underscores even where the crate name uses hyphens.
 budget_for() {
   case "$1" in
-    assetsup)          echo 215000 ;;
+    # Raised from 215000 when token metadata revisions took the contract past
+    # it; the registry has grown offerings, a listing book and compliance
+    # controls since the baseline was recorded.
+    assetsup)          echo 282000 ;;
     contrib)           echo 100000 ;;
-    multisig_wallet)   echo  95000 ;;
+    # Raised from 95000 when per-type transaction policies took the wallet
+    # past it; it has grown weighted owners, roles, token daily limits,
+    # batches, __check_auth and signed approvals since the baseline.
+    multisig_wallet)   echo 110000 ;;
     asset_maintenance) echo  85000 ;;
     multisig_transfer) echo  85000 ;;
+    asset_token)       echo  24000 ;;
     # An unbudgeted contract fails rather than passing silently: a new
     # deployable contract must get a reviewed budget.
     *)                 echo 0 ;;
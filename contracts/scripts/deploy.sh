@@ -42,6 +42,12 @@ CONTRACTS=(
   "multisig-transfer"
 )
 
+# Contracts whose WASM is uploaded but not deployed here. assetsup deploys an
+# asset-token instance itself for each tokenized asset, from the hash set below.
+UPLOADED=(
+  "asset-token"
+)
+
 usage() {
   cat <<'EOF'
 Usage: ./scripts/deploy.sh --network <name> --source <identity> [--skip-build]
@@ -100,8 +106,8 @@ crate_dir() {
 # ---------------------------------------------------------------- build
 
 if [[ "$SKIP_BUILD" -eq 0 ]]; then
-  log "Building ${#CONTRACTS[@]} contracts for wasm32-unknown-unknown (release)"
-  for c in "${CONTRACTS[@]}"; do
+  log "Building $(( ${#CONTRACTS[@]} + ${#UPLOADED[@]} )) contracts for wasm32-unknown-unknown (release)"
+  for c in "${CONTRACTS[@]}" "${UPLOADED[@]}"; do
     log "  building $c"
     cargo build --package "$c" --target wasm32-unknown-unknown --release
   done
@@ -110,7 +116,7 @@ else
 fi
 
 log "Optimizing WASM artifacts"
-for c in "${CONTRACTS[@]}"; do
+for c in "${CONTRACTS[@]}" "${UPLOADED[@]}"; do
   wasm="$WASM_DIR/${c//-/_}.wasm"
   if [[ ! -f "$wasm" ]]; then
     echo "error: expected artifact not found: $wasm" >&2
@@ -142,6 +148,13 @@ for c in "${CONTRACTS[@]}"; do
   printf '    %-20s %s\n' "$c" "$id"
 done
 
+log "Uploading WASM for contracts assetsup deploys itself"
+TOKEN_WASM_HASH=$(stellar contract upload \
+      --wasm "$WASM_DIR/asset_token.optimized.wasm" \
+      --source-account "$SOURCE" \
+      --network "$NETWORK")
+printf '    %-20s %s\n' "asset-token" "$TOKEN_WASM_HASH"
+
 # ---------------------------------------------------------------- initialize
 
 ADMIN=$(stellar keys address "$SOURCE")
@@ -159,6 +172,10 @@ invoke() {
 log "  assetsup.initialize"
 invoke "${DEPLOYED[assetsup]}" initialize --admin "$ADMIN" >/dev/null
 
+log "  assetsup.set_token_wasm_hash (asset-token)"
+invoke "${DEPLOYED[assetsup]}" set_token_wasm_hash \
+  --wasm_hash "$TOKEN_WASM_HASH" >/dev/null
+
 log "  contrib.initialize"
 invoke "${DEPLOYED[contrib]}" initialize --admin "$ADMIN" >/dev/null
 
@@ -212,6 +229,7 @@ log "Writing $OUT_FILE"
   printf '  "network": "%s",\n' "$NETWORK"
   printf '  "admin": "%s",\n' "$ADMIN"
   printf '  "deployedAt": "%s",\n' "$(date -u +%Y-%m-%dT%H:%M:%SZ)"
+  printf '  "tokenWasmHash": "%s",\n' "$TOKEN_WASM_HASH"
   printf '  "contracts": {\n'
   last_index=$(( ${#CONTRACTS[@]} - 1 ))
   for i in "${!CONTRACTS[@]}"; do